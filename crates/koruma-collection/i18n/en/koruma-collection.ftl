@@ -6,10 +6,59 @@ alphanumeric_validation = The value '{ $actual }' must contain only letters and
 
 ascii_validation = The value '{ $actual }' must contain only ASCII characters.
 
+## BalancedDelimitersValidation
+
+balanced_delimiters_validation = { $imbalance ->
+    [unexpected] The value '{ $actual }' has an unexpected closing delimiter.
+    [mismatched] The value '{ $actual }' has a mismatched closing delimiter.
+    [unclosed] The value '{ $actual }' has an unclosed delimiter.
+   *[other] The delimiters in '{ $actual }' are not balanced.
+}
+
+## EntropyValidation
+
+entropy_validation = { $unmet ->
+    [entropy_bits] The value is too predictable: { $entropy_bits } { $entropy_bits ->
+        [one] bit
+       *[other] bits
+    } of entropy, at least { $min_entropy_bits } required.
+    [character_classes] The value must use at least { $require_classes } of lowercase letters, uppercase letters, digits and symbols.
+   *[other] The value is too predictable.
+}
+
+## HexValidation
+
+hex_validation = { $failure ->
+    [empty] The value must not be empty.
+    [invalid_char] The value '{ $actual }' contains a non-hexadecimal character.
+    [wrong_length] The value '{ $actual }' must be exactly { $expected_len } hexadecimal { $expected_len ->
+        [one] digit
+       *[other] digits
+    }.
+   *[other] The value '{ $actual }' must be hexadecimal.
+}
+
+## NoControlCharsValidation
+
+no_control_chars_validation = The value must not contain control characters.
+
+## CidrValidation
+
+cidr_validation = { $failure ->
+    [missing_prefix] The CIDR block '{ $actual }' must end with a /prefix length.
+    [invalid_prefix_length] The CIDR block '{ $actual }' has an invalid prefix length.
+    [host_bits_set] The CIDR block '{ $actual }' has host bits set past its prefix length.
+   *[other] The value '{ $actual }' is not a valid { $kind } CIDR block.
+}
+
 ## ContainsValidation
 
 contains_validation = The value '{ $actual }' must contain the substring '{ $substring }'.
 
+## EndsWithAnyValidation
+
+ends_with_any_validation = The value '{ $actual }' must end with one of: { $suffixes }.
+
 ## IpKind
 
 ip_kind-Any = any IP version
@@ -18,16 +67,76 @@ ip_kind-V6 = IPv6
 
 ## IpValidation
 
-ip_validation = The value '{ $actual }' is not a valid { $kind } IP address.
+ip_validation = { $rejection ->
+    [private] The IP address '{ $actual }' is private, which is not allowed.
+    [link_local] The IP address '{ $actual }' is link-local, which is not allowed.
+    [loopback] The IP address '{ $actual }' is a loopback address, which is not allowed.
+   *[other] The value '{ $actual }' is not a valid { $kind } IP address.
+}
+
+## JsonKind
+
+json_kind-Any = value
+json_kind-Array = array
+json_kind-Object = object
+
+## JsonValidation
+
+json_validation = The value must be a valid JSON { $require }.
 
 ## LenValidation
 
-len_validation = The length must be between { $min } and { $max } (actual: { $actual }).
+len_validation = { $actual_fill_ratio ->
+    [none] The length must be between { $min } and { $max } { $max ->
+        [one] item
+       *[other] items
+    } (actual: { $actual_len }).
+   *[other] The length must be between { $min } and { $max } { $max ->
+        [one] item
+       *[other] items
+    }, filling between { $min_fill_ratio } and { $max_fill_ratio } of the capacity (actual: { $actual_len }, fill ratio { $actual_fill_ratio }).
+}
+
+## MacSeparator
+
+mac_separator-Any = ':' or '-'
+mac_separator-Colon = ':'
+mac_separator-Hyphen = '-'
+
+## MacAddressValidation
+
+mac_address_validation = { $failure ->
+    [invalid_separator] The MAC address '{ $actual }' must separate its octets with { $separator }.
+   *[other] The value '{ $actual }' is not a valid MAC address.
+}
 
 ## MatchesValidation
 
 matches_validation = The value does not match the expected value '{ $other }' (actual: '{ $actual }').
 
+## EqualsValidation
+
+equals_validation = The value { $actual } must be { $expected }.
+
+## NotEqualsValidation
+
+not_equals_validation = The value must not be { $forbidden }.
+
+## NotOneOfValidation
+
+not_one_of_validation = The value { $actual } is not allowed.
+
+## OneOfValidation
+
+one_of_validation = The value { $actual } must be one of: { $allowed }.
+
+## PortValidation
+
+port_validation = { $failure ->
+    [reserved] The port { $actual } is reserved; use a port of 1024 or above.
+   *[other] The port { $actual } must be between { $min } and { $max }.
+}
+
 ## PrefixValidation
 
 prefix_validation = The value '{ $actual }' must start with '{ $prefix }'.
@@ -36,14 +145,61 @@ prefix_validation = The value '{ $actual }' must start with '{ $prefix }'.
 
 range_validation = The value must be between { $min } and { $max } (actual: { $actual }).
 
+## StepRangeValidation
+
+step_range_validation = { $failure ->
+    [off_step] The value { $actual } must be { $min } plus a multiple of { $step }.
+   *[other] The value must be between { $min } and { $max } (actual: { $actual }).
+}
+
 ## RequiredValidation
 
-required_validation = This field is required and must not be empty.
+required_validation = { $reason ->
+    [missing] This field is required.
+    [blank] This field is required and must not be blank.
+   *[other] This field is required and must not be empty.
+}
+
+## SplitCountValidation
+
+split_count_validation = The value must have between { $min } and { $max } { $max ->
+    [one] field
+   *[other] fields
+} separated by '{ $delimiter }' (actual: { $segment_count }).
+
+## StartsWithAnyValidation
+
+starts_with_any_validation = The value '{ $actual }' must start with one of: { $prefixes }.
 
 ## SuffixValidation
 
 suffix_validation = The value '{ $actual }' must end with '{ $suffix }'.
 
+## TrimmedLengthValidation
+
+trimmed_length_validation = The value without surrounding whitespace must be between { $min } and { $max } { $max ->
+    [one] character
+   *[other] characters
+} long (actual: { $actual }).
+
+## WordCountValidation
+
+word_count_validation = The text must have between { $min } and { $max } { $max ->
+    [one] word
+   *[other] words
+} (actual: { $word_count }).
+
+## FiniteValidation
+
+finite_validation = { $kind ->
+    [nan] The value must be a number, not NaN.
+   *[other] The value { $actual } must be finite.
+}
+
+## MultipleOfValidation
+
+multiple_of_validation = The value { $actual } must be a multiple of { $divisor } (remainder: { $remainder }).
+
 ## NegativeValidation
 
 negative_validation = The value '{ $actual }' must be negative.
@@ -64,6 +220,16 @@ non_positive_validation = The value '{ $actual }' must be zero or a negative num
 
 positive_validation = The value '{ $actual }' must be positive.
 
+## PrecisionValidation
+
+precision_validation = The value { $actual } has { $decimals } decimal { $decimals ->
+    [one] place
+   *[other] places
+}; at most { $max_decimals } { $max_decimals ->
+    [one] is
+   *[other] are
+} allowed.
+
 ## Case
 
 case-Camel = camelCase (lower camel case)
@@ -75,6 +241,29 @@ case-Snake = snake_case
 case-Title = Title Case
 case-Train = Train-Case
 
+## AgeValidation
+
+age_validation = { $max_years ->
+    [none] The age { $age } must be at least { $min_years } { $min_years ->
+        [one] year
+       *[other] years
+    }.
+   *[other] The age { $age } must be between { $min_years } and { $max_years } { $max_years ->
+        [one] year
+       *[other] years
+    }.
+}
+
+## Base64Validation
+
+base64_validation = { $failure ->
+    [too_large] The value must decode to at most { $max_decoded_bytes } { $max_decoded_bytes ->
+        [one] byte
+       *[other] bytes
+    }.
+   *[other] The value is not valid base64.
+}
+
 ## CaseValidation
 
 case_validation = The value '{ $actual }' must be in { $case } case.
@@ -83,10 +272,26 @@ case_validation = The value '{ $actual }' must be in { $case } case.
 
 credit_card_validation = The value '{ $actual }' is not a valid credit card number.
 
+## DateRangeValidation
+
+date_range_validation = { $violated ->
+    [min] The date { $actual } must not be before { $min }.
+    [max] The date { $actual } must not be after { $max }.
+   *[other] The date { $actual } is out of range.
+}
+
 ## EmailValidation
 
 email_validation = The value '{ $actual }' is not a valid email address.
 
+## NotInFutureValidation
+
+not_in_future_validation = The date { $actual } must not be in the future.
+
+## NotInPastValidation
+
+not_in_past_validation = The date { $actual } must not be in the past.
+
 ## PatternValidation
 
 pattern_validation = The value '{ $actual }' does not match the required pattern '{ $pattern }'.
@@ -95,6 +300,25 @@ pattern_validation = The value '{ $actual }' does not match the required pattern
 
 phone_number_validation = The value '{ $actual }' is not a valid phone number.
 
+## SemVerValidation
+
+sem_ver_validation = { $failure ->
+    [unsatisfied] The version '{ $actual }' must satisfy { $requirement }.
+    [invalid_requirement] The version requirement '{ $requirement }' is invalid.
+   *[other] The value '{ $actual }' is not a valid semantic version.
+}
+
 ## UrlValidation
 
 url_validation = The value '{ $actual }' is not a valid URL.
+
+## UuidValidation
+
+uuid_validation = { $failure ->
+    [wrong_version] The value '{ $actual }' must be a version { $version } UUID.
+   *[other] The value '{ $actual }' is not a valid UUID.
+}
+
+## PasswordStrengthValidation
+
+password_strength_validation = The password must be at least { $min_length } characters long and include every required character type.
@@ -6,10 +6,59 @@ alphanumeric_validation = La valeur « { $actual } » doit contenir uniquement d
 
 ascii_validation = La valeur « { $actual } » doit contenir uniquement des caractères ASCII.
 
+## BalancedDelimitersValidation
+
+balanced_delimiters_validation = { $imbalance ->
+    [unexpected] La valeur « { $actual } » contient un délimiteur fermant inattendu.
+    [mismatched] La valeur « { $actual } » contient un délimiteur fermant qui ne correspond pas.
+    [unclosed] La valeur « { $actual } » contient un délimiteur non fermé.
+   *[other] Les délimiteurs de « { $actual } » ne sont pas équilibrés.
+}
+
+## EntropyValidation
+
+entropy_validation = { $unmet ->
+    [entropy_bits] La valeur est trop prévisible : { $entropy_bits } { $entropy_bits ->
+        [one] bit
+       *[other] bits
+    } d'entropie, au moins { $min_entropy_bits } requis.
+    [character_classes] La valeur doit utiliser au moins { $require_classes } types de caractères parmi minuscules, majuscules, chiffres et symboles.
+   *[other] La valeur est trop prévisible.
+}
+
+## HexValidation
+
+hex_validation = { $failure ->
+    [empty] La valeur ne doit pas être vide.
+    [invalid_char] La valeur « { $actual } » contient un caractère non hexadécimal.
+    [wrong_length] La valeur « { $actual } » doit comporter exactement { $expected_len } { $expected_len ->
+        [one] chiffre hexadécimal
+       *[other] chiffres hexadécimaux
+    }.
+   *[other] La valeur « { $actual } » doit être hexadécimale.
+}
+
+## NoControlCharsValidation
+
+no_control_chars_validation = La valeur ne doit pas contenir de caractères de contrôle.
+
+## CidrValidation
+
+cidr_validation = { $failure ->
+    [missing_prefix] Le bloc CIDR « { $actual } » doit se terminer par une longueur de préfixe /n.
+    [invalid_prefix_length] Le bloc CIDR « { $actual } » a une longueur de préfixe invalide.
+    [host_bits_set] Le bloc CIDR « { $actual } » a des bits d'hôte définis au-delà de son préfixe.
+   *[other] La valeur « { $actual } » n'est pas un bloc CIDR { $kind } valide.
+}
+
 ## ContainsValidation
 
 contains_validation = La valeur « { $actual } » doit contenir la sous-chaîne « { $substring } ».
 
+## EndsWithAnyValidation
+
+ends_with_any_validation = La valeur « { $actual } » doit se terminer par l’un des suffixes suivants : { $suffixes }.
+
 ## IpKind
 
 ip_kind-Any = toute version d'adresse IP
@@ -18,16 +67,76 @@ ip_kind-V6 = IPv6
 
 ## IpValidation
 
-ip_validation = La valeur « { $actual } » n'est pas une adresse IP { $kind } valide.
+ip_validation = { $rejection ->
+    [private] L'adresse IP « { $actual } » est privée, ce qui n'est pas autorisé.
+    [link_local] L'adresse IP « { $actual } » est une adresse lien-local, ce qui n'est pas autorisé.
+    [loopback] L'adresse IP « { $actual } » est une adresse de bouclage, ce qui n'est pas autorisé.
+   *[other] La valeur « { $actual } » n'est pas une adresse IP { $kind } valide.
+}
+
+## JsonKind
+
+json_kind-Any = valeur
+json_kind-Array = tableau
+json_kind-Object = objet
+
+## JsonValidation
+
+json_validation = La valeur doit être du JSON valide ({ $require }).
 
 ## LenValidation
 
-len_validation = La longueur doit être comprise entre { $min } et { $max } (actuelle : { $actual }).
+len_validation = { $actual_fill_ratio ->
+    [none] La longueur doit être comprise entre { $min } et { $max } { $max ->
+        [one] élément
+       *[other] éléments
+    } (actuelle : { $actual_len }).
+   *[other] La longueur doit être comprise entre { $min } et { $max } { $max ->
+        [one] élément
+       *[other] éléments
+    }, avec un taux de remplissage entre { $min_fill_ratio } et { $max_fill_ratio } (actuelle : { $actual_len }, taux de remplissage { $actual_fill_ratio }).
+}
+
+## MacSeparator
+
+mac_separator-Any = « : » ou « - »
+mac_separator-Colon = « : »
+mac_separator-Hyphen = « - »
+
+## MacAddressValidation
+
+mac_address_validation = { $failure ->
+    [invalid_separator] L'adresse MAC « { $actual } » doit séparer ses octets par { $separator }.
+   *[other] La valeur « { $actual } » n'est pas une adresse MAC valide.
+}
 
 ## MatchesValidation
 
 matches_validation = La valeur ne correspond pas à la valeur attendue « { $other } » (actuelle : « { $actual } »).
 
+## EqualsValidation
+
+equals_validation = La valeur { $actual } doit être { $expected }.
+
+## NotEqualsValidation
+
+not_equals_validation = La valeur ne doit pas être { $forbidden }.
+
+## NotOneOfValidation
+
+not_one_of_validation = La valeur { $actual } n’est pas autorisée.
+
+## OneOfValidation
+
+one_of_validation = La valeur { $actual } doit être l’une des suivantes : { $allowed }.
+
+## PortValidation
+
+port_validation = { $failure ->
+    [reserved] Le port { $actual } est réservé ; utilisez un port supérieur ou égal à 1024.
+   *[other] Le port { $actual } doit être compris entre { $min } et { $max }.
+}
+
 ## PrefixValidation
 
 prefix_validation = La valeur « { $actual } » doit commencer par « { $prefix } ».
@@ -36,14 +145,61 @@ prefix_validation = La valeur « { $actual } » doit commencer par « { $prefix
 
 range_validation = La valeur doit être comprise entre { $min } et { $max } (actuelle : { $actual }).
 
+## StepRangeValidation
+
+step_range_validation = { $failure ->
+    [off_step] La valeur { $actual } doit être égale à { $min } plus un multiple de { $step }.
+   *[other] La valeur doit être comprise entre { $min } et { $max } (actuelle : { $actual }).
+}
+
 ## RequiredValidation
 
-required_validation = Ce champ est obligatoire et ne doit pas être vide.
+required_validation = { $reason ->
+    [missing] Ce champ est obligatoire.
+    [blank] Ce champ est obligatoire et ne doit pas contenir que des espaces.
+   *[other] Ce champ est obligatoire et ne doit pas être vide.
+}
+
+## SplitCountValidation
+
+split_count_validation = La valeur doit comporter entre { $min } et { $max } { $max ->
+    [one] champ
+   *[other] champs
+} séparés par « { $delimiter } » (actuel : { $segment_count }).
+
+## StartsWithAnyValidation
+
+starts_with_any_validation = La valeur « { $actual } » doit commencer par l’un des préfixes suivants : { $prefixes }.
 
 ## SuffixValidation
 
 suffix_validation = La valeur « { $actual } » doit se terminer par « { $suffix } ».
 
+## TrimmedLengthValidation
+
+trimmed_length_validation = La valeur sans les espaces de début et de fin doit faire entre { $min } et { $max } { $max ->
+    [one] caractère
+   *[other] caractères
+} (actuelle : { $actual }).
+
+## WordCountValidation
+
+word_count_validation = Le texte doit contenir entre { $min } et { $max } { $max ->
+    [one] mot
+   *[other] mots
+} (actuel : { $word_count }).
+
+## FiniteValidation
+
+finite_validation = { $kind ->
+    [nan] La valeur doit être un nombre, pas NaN.
+   *[other] La valeur { $actual } doit être finie.
+}
+
+## MultipleOfValidation
+
+multiple_of_validation = La valeur { $actual } doit être un multiple de { $divisor } (reste : { $remainder }).
+
 ## NegativeValidation
 
 negative_validation = La valeur « { $actual } » doit être négative.
@@ -64,6 +220,16 @@ non_positive_validation = La valeur « { $actual } » doit être nulle ou négat
 
 positive_validation = La valeur « { $actual } » doit être positive.
 
+## PrecisionValidation
+
+precision_validation = La valeur { $actual } a { $decimals } { $decimals ->
+    [one] décimale
+   *[other] décimales
+} ; { $max_decimals } au maximum { $max_decimals ->
+    [one] est autorisée
+   *[other] sont autorisées
+}.
+
 ## Case
 
 case-Camel = camelCase (lower camel case)
@@ -75,6 +241,29 @@ case-Snake = snake_case
 case-Title = Title Case
 case-Train = Train-Case
 
+## AgeValidation
+
+age_validation = { $max_years ->
+    [none] L'âge { $age } doit être d'au moins { $min_years } { $min_years ->
+        [one] an
+       *[other] ans
+    }.
+   *[other] L'âge { $age } doit être compris entre { $min_years } et { $max_years } { $max_years ->
+        [one] an
+       *[other] ans
+    }.
+}
+
+## Base64Validation
+
+base64_validation = { $failure ->
+    [too_large] La valeur décodée ne doit pas dépasser { $max_decoded_bytes } { $max_decoded_bytes ->
+        [one] octet
+       *[other] octets
+    }.
+   *[other] La valeur n'est pas un base64 valide.
+}
+
 ## CaseValidation
 
 case_validation = La valeur « { $actual } » doit être en casse { $case }.
@@ -83,10 +272,26 @@ case_validation = La valeur « { $actual } » doit être en casse { $case }.
 
 credit_card_validation = La valeur « { $actual } » n'est pas un numéro de carte de crédit valide.
 
+## DateRangeValidation
+
+date_range_validation = { $violated ->
+    [min] La date { $actual } ne doit pas être antérieure au { $min }.
+    [max] La date { $actual } ne doit pas être postérieure au { $max }.
+   *[other] La date { $actual } est hors limites.
+}
+
 ## EmailValidation
 
 email_validation = La valeur « { $actual } » n'est pas une adresse e-mail valide.
 
+## NotInFutureValidation
+
+not_in_future_validation = La date { $actual } ne doit pas être dans le futur.
+
+## NotInPastValidation
+
+not_in_past_validation = La date { $actual } ne doit pas être dans le passé.
+
 ## PatternValidation
 
 pattern_validation = La valeur « { $actual } » ne correspond pas au motif requis « { $pattern } ».
@@ -95,6 +300,25 @@ pattern_validation = La valeur « { $actual } » ne correspond pas au motif requ
 
 phone_number_validation = La valeur « { $actual } » n'est pas un numéro de téléphone valide.
 
+## SemVerValidation
+
+sem_ver_validation = { $failure ->
+    [unsatisfied] La version « { $actual } » doit satisfaire { $requirement }.
+    [invalid_requirement] La contrainte de version « { $requirement } » est invalide.
+   *[other] La valeur « { $actual } » n'est pas une version sémantique valide.
+}
+
 ## UrlValidation
 
 url_validation = La valeur « { $actual } » n'est pas une URL valide.
+
+## UuidValidation
+
+uuid_validation = { $failure ->
+    [wrong_version] La valeur « { $actual } » doit être un UUID de version { $version }.
+   *[other] La valeur « { $actual } » n'est pas un UUID valide.
+}
+
+## PasswordStrengthValidation
+
+password_strength_validation = Le mot de passe doit contenir au moins { $min_length } caractères et inclure chaque type de caractère requis.
@@ -6,10 +6,53 @@ alphanumeric_validation = 值 '{ $actual }' 必须只包含字母和数字。
 
 ascii_validation = 值 '{ $actual }' 必须只包含 ASCII 字符。
 
+## BalancedDelimitersValidation
+
+balanced_delimiters_validation = { $imbalance ->
+    [unexpected] 值 '{ $actual }' 含有多余的闭合分隔符。
+    [mismatched] 值 '{ $actual }' 含有不匹配的闭合分隔符。
+    [unclosed] 值 '{ $actual }' 含有未闭合的分隔符。
+   *[other] 值 '{ $actual }' 中的分隔符不平衡。
+}
+
+## EntropyValidation
+
+entropy_validation = { $unmet ->
+    [entropy_bits] 该值过于容易猜测：熵为 { $entropy_bits } 位，至少需要 { $min_entropy_bits } 位。
+    [character_classes] 该值必须至少包含小写字母、大写字母、数字和符号中的 { $require_classes } 类。
+   *[other] 该值过于容易猜测。
+}
+
+## HexValidation
+
+hex_validation = { $failure ->
+    [empty] 值不能为空。
+    [invalid_char] 值 '{ $actual }' 含有非十六进制字符。
+    [wrong_length] 值 '{ $actual }' 必须恰好为 { $expected_len } 位十六进制数字。
+   *[other] 值 '{ $actual }' 必须是十六进制。
+}
+
+## NoControlCharsValidation
+
+no_control_chars_validation = 值不得包含控制字符。
+
+## CidrValidation
+
+cidr_validation = { $failure ->
+    [missing_prefix] CIDR 块 '{ $actual }' 必须以 /前缀长度 结尾。
+    [invalid_prefix_length] CIDR 块 '{ $actual }' 的前缀长度无效。
+    [host_bits_set] CIDR 块 '{ $actual }' 在前缀长度之后设置了主机位。
+   *[other] 值 '{ $actual }' 不是有效的 { $kind } CIDR 块。
+}
+
 ## ContainsValidation
 
 contains_validation = 值 '{ $actual }' 必须包含子串 '{ $substring }'。
 
+## EndsWithAnyValidation
+
+ends_with_any_validation = 值 '{ $actual }' 必须以以下之一结尾：{ $suffixes }。
+
 ## IpKind
 
 ip_kind-Any = 任意 IP 版本
@@ -18,16 +61,70 @@ ip_kind-V6 = IPv6
 
 ## IpValidation
 
-ip_validation = 值 '{ $actual }' 不是有效的 { $kind } IP 地址。
+ip_validation = { $rejection ->
+    [private] IP 地址 '{ $actual }' 是私有地址，不允许使用。
+    [link_local] IP 地址 '{ $actual }' 是链路本地地址，不允许使用。
+    [loopback] IP 地址 '{ $actual }' 是环回地址，不允许使用。
+   *[other] 值 '{ $actual }' 不是有效的 { $kind } IP 地址。
+}
+
+## JsonKind
+
+json_kind-Any = 值
+json_kind-Array = 数组
+json_kind-Object = 对象
+
+## JsonValidation
+
+json_validation = 该值必须是有效的 JSON { $require }。
 
 ## LenValidation
 
-len_validation = 长度必须在 { $min } 到 { $max } 之间（实际：{ $actual }）。
+len_validation = { $actual_fill_ratio ->
+    [none] 长度必须在 { $min } 到 { $max } 之间（实际：{ $actual_len }）。
+   *[other] 长度必须在 { $min } 到 { $max } 之间，填充率必须在 { $min_fill_ratio } 到 { $max_fill_ratio } 之间（实际：{ $actual_len }，填充率 { $actual_fill_ratio }）。
+}
+
+## MacSeparator
+
+mac_separator-Any = ':' 或 '-'
+mac_separator-Colon = ':'
+mac_separator-Hyphen = '-'
+
+## MacAddressValidation
+
+mac_address_validation = { $failure ->
+    [invalid_separator] MAC 地址 '{ $actual }' 必须使用 { $separator } 分隔各字节。
+   *[other] 值 '{ $actual }' 不是有效的 MAC 地址。
+}
 
 ## MatchesValidation
 
 matches_validation = 值与期望值 '{ $other }' 不匹配（实际：'{ $actual }'）。
 
+## EqualsValidation
+
+equals_validation = 值 { $actual } 必须为 { $expected }。
+
+## NotEqualsValidation
+
+not_equals_validation = 值不能为 { $forbidden }。
+
+## NotOneOfValidation
+
+not_one_of_validation = 值 { $actual } 不被允许。
+
+## OneOfValidation
+
+one_of_validation = 值 { $actual } 必须是以下之一：{ $allowed }。
+
+## PortValidation
+
+port_validation = { $failure ->
+    [reserved] 端口 { $actual } 是保留端口；请使用 1024 及以上的端口。
+   *[other] 端口 { $actual } 必须介于 { $min } 和 { $max } 之间。
+}
+
 ## PrefixValidation
 
 prefix_validation = 值 '{ $actual }' 必须以 '{ $prefix }' 开头。
@@ -36,14 +133,52 @@ prefix_validation = 值 '{ $actual }' 必须以 '{ $prefix }' 开头。
 
 range_validation = 值必须在 { $min } 到 { $max } 之间（实际：{ $actual }）。
 
+## StepRangeValidation
+
+step_range_validation = { $failure ->
+    [off_step] 值 { $actual } 必须等于 { $min } 加上 { $step } 的倍数。
+   *[other] 值必须在 { $min } 到 { $max } 之间（实际：{ $actual }）。
+}
+
 ## RequiredValidation
 
-required_validation = 此字段为必填，不能为空。
+required_validation = { $reason ->
+    [missing] 此字段为必填。
+    [blank] 此字段为必填，不能只包含空白字符。
+   *[other] 此字段为必填，不能为空。
+}
+
+## SplitCountValidation
+
+split_count_validation = 以 '{ $delimiter }' 分隔后，值必须包含 { $min } 到 { $max } 个字段（实际：{ $segment_count }）。
+
+## StartsWithAnyValidation
+
+starts_with_any_validation = 值 '{ $actual }' 必须以以下之一开头：{ $prefixes }。
 
 ## SuffixValidation
 
 suffix_validation = 值 '{ $actual }' 必须以 '{ $suffix }' 结尾。
 
+## TrimmedLengthValidation
+
+trimmed_length_validation = 去除首尾空白后的长度必须在 { $min } 到 { $max } 之间（实际：{ $actual }）。
+
+## WordCountValidation
+
+word_count_validation = 文本的词数必须在 { $min } 到 { $max } 之间（实际：{ $word_count }）。
+
+## FiniteValidation
+
+finite_validation = { $kind ->
+    [nan] 该值必须是数字，而不是 NaN。
+   *[other] 值 { $actual } 必须是有限数。
+}
+
+## MultipleOfValidation
+
+multiple_of_validation = 值 { $actual } 必须是 { $divisor } 的倍数（余数：{ $remainder }）。
+
 ## NegativeValidation
 
 negative_validation = 值 '{ $actual }' 必须为负数。
@@ -64,6 +199,10 @@ non_positive_validation = 值 '{ $actual }' 必须为零或负数。
 
 positive_validation = 值 '{ $actual }' 必须为正数。
 
+## PrecisionValidation
+
+precision_validation = 值 { $actual } 有 { $decimals } 位小数；最多允许 { $max_decimals } 位。
+
 ## Case
 
 case-Camel = camelCase (lower camel case)
@@ -75,6 +214,20 @@ case-Snake = snake_case
 case-Title = Title Case
 case-Train = Train-Case
 
+## AgeValidation
+
+age_validation = { $max_years ->
+    [none] 年龄 { $age } 必须至少为 { $min_years } 岁。
+   *[other] 年龄 { $age } 必须介于 { $min_years } 到 { $max_years } 岁之间。
+}
+
+## Base64Validation
+
+base64_validation = { $failure ->
+    [too_large] 解码后的值不得超过 { $max_decoded_bytes } 字节。
+   *[other] 该值不是有效的 base64。
+}
+
 ## CaseValidation
 
 case_validation = 值 '{ $actual }' 必须使用 { $case } 格式。
@@ -83,10 +236,26 @@ case_validation = 值 '{ $actual }' 必须使用 { $case } 格式。
 
 credit_card_validation = 值 '{ $actual }' 不是有效的信用卡号。
 
+## DateRangeValidation
+
+date_range_validation = { $violated ->
+    [min] 日期 { $actual } 不得早于 { $min }。
+    [max] 日期 { $actual } 不得晚于 { $max }。
+   *[other] 日期 { $actual } 超出范围。
+}
+
 ## EmailValidation
 
 email_validation = 值 '{ $actual }' 不是有效的电子邮件地址。
 
+## NotInFutureValidation
+
+not_in_future_validation = 日期 { $actual } 不得晚于当前时间。
+
+## NotInPastValidation
+
+not_in_past_validation = 日期 { $actual } 不得早于当前时间。
+
 ## PatternValidation
 
 pattern_validation = 值 '{ $actual }' 不匹配所需的模式 '{ $pattern }'。
@@ -95,6 +264,25 @@ pattern_validation = 值 '{ $actual }' 不匹配所需的模式 '{ $pattern }'
 
 phone_number_validation = 值 '{ $actual }' 不是有效的电话号码。
 
+## SemVerValidation
+
+sem_ver_validation = { $failure ->
+    [unsatisfied] 版本 '{ $actual }' 必须满足 { $requirement }。
+    [invalid_requirement] 版本要求 '{ $requirement }' 无效。
+   *[other] 值 '{ $actual }' 不是有效的语义化版本。
+}
+
 ## UrlValidation
 
 url_validation = 值 '{ $actual }' 不是有效的 URL。
+
+## UuidValidation
+
+uuid_validation = { $failure ->
+    [wrong_version] 值 '{ $actual }' 必须是版本 { $version } 的 UUID。
+   *[other] 值 '{ $actual }' 不是有效的 UUID。
+}
+
+## PasswordStrengthValidation
+
+password_strength_validation = 密码长度至少为 { $min_length } 个字符，并且必须包含所有要求的字符类型。
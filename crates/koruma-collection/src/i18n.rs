@@ -1 +1,281 @@
+//! Embedded translations for the collection's validator messages.
+//!
+//! Initialize the es-fluent manager once, then render failures in any of the
+//! bundled languages (`en`, `fr`, `zh-CN`):
+//!
+//! ```rust
+//! use koruma::{Koruma, KorumaAllFluent};
+//! use koruma_collection::{collection::NonEmptyValidation, general::RequiredValidation};
+//! use unic_langid::langid;
+//!
+//! #[derive(Koruma, KorumaAllFluent)]
+//! struct Signup {
+//!     #[koruma(NonEmptyValidation::<_>)]
+//!     name: String,
+//!     #[koruma(RequiredValidation::<Option<_>>(priority = 10))]
+//!     email: Option<String>,
+//!     #[koruma(NonEmptyValidation::<_>)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! es_fluent_manager_embedded::init();
+//!
+//! let signup = Signup { name: String::new(), email: None, tags: Vec::new() };
+//! let err = signup.validate().unwrap_err();
+//!
+//! // `email` has the highest priority; `name` and `tags` keep declaration order
+//! assert_eq!(
+//!     err.localized_summary(&langid!("fr")).unwrap(),
+//!     vec![
+//!         "Ce champ est obligatoire.",
+//!         "La valeur ne doit pas être vide.",
+//!         "La valeur ne doit pas être vide.",
+//!     ]
+//! );
+//! assert_eq!(
+//!     err.localized_summary(&langid!("en")).unwrap()[0],
+//!     "This field is required."
+//! );
+//!
+//! // Or every message with its field path, in declaration order, rendered
+//! // in the global language
+//! use koruma::LocalizedMessages as _;
+//! es_fluent_manager_embedded::select_language(langid!("en"));
+//! assert_eq!(
+//!     err.all_localized_messages()[1],
+//!     ("email".to_string(), "This field is required.".to_string())
+//! );
+//! ```
+//!
+//! Counts reach fluent as numbers, so each language's plural rules pick the
+//! wording:
+//!
+//! ```rust
+//! use koruma::{Koruma, KorumaAllFluent};
+//! use koruma_collection::{collection::LenValidation, numeric::PrecisionValidation};
+//! use unic_langid::langid;
+//!
+//! #[derive(Koruma, KorumaAllFluent)]
+//! struct Price {
+//!     #[koruma(PrecisionValidation::<_>(max_decimals = 0))]
+//!     amount: f64,
+//! }
+//!
+//! #[derive(Koruma, KorumaAllFluent)]
+//! struct Tags {
+//!     #[koruma(LenValidation::<_>(min = 1, max = 1))]
+//!     one: Vec<u8>,
+//!     #[koruma(LenValidation::<_>(min = 1, max = 5))]
+//!     five: Vec<u8>,
+//! }
+//!
+//! es_fluent_manager_embedded::init();
+//!
+//! // Fluent wraps arguments in Unicode isolation marks
+//! let plain = |messages: Vec<String>| messages.concat().replace(['\u{2068}', '\u{2069}'], "");
+//!
+//! let one = Price { amount: 1.5 }.validate().unwrap_err();
+//! assert_eq!(
+//!     plain(one.localized_summary(&langid!("en")).unwrap()),
+//!     "The value 1.5 has 1 decimal place; at most 0 are allowed."
+//! );
+//! let five = Price { amount: 1.23456 }.validate().unwrap_err();
+//! assert_eq!(
+//!     plain(five.localized_summary(&langid!("en")).unwrap()),
+//!     "The value 1.23456 has 5 decimal places; at most 0 are allowed."
+//! );
+//! assert_eq!(
+//!     plain(five.localized_summary(&langid!("fr")).unwrap()),
+//!     "La valeur 1.23456 a 5 décimales ; 0 au maximum est autorisée."
+//! );
+//!
+//! let tags = Tags { one: Vec::new(), five: Vec::new() }.validate().unwrap_err();
+//! assert_eq!(
+//!     tags.localized_summary(&langid!("en")).unwrap().into_iter().map(|m| plain(vec![m])).collect::<Vec<_>>(),
+//!     vec![
+//!         "The length must be between 1 and 1 item (actual: 0).",
+//!         "The length must be between 1 and 5 items (actual: 0).",
+//!     ]
+//! );
+//! ```
+//!
+//! [`koruma::ToFluentStringIn`] renders in a given locale on the calling
+//! thread only, leaving the global language to other threads and requests:
+//!
+//! ```rust
+//! use es_fluent::ToFluentString as _;
+//! use koruma::{Koruma, KorumaAllFluent, ToFluentStringIn as _};
+//! use koruma_collection::collection::NonEmptyValidation;
+//! use unic_langid::langid;
+//!
+//! #[derive(Koruma, KorumaAllFluent)]
+//! struct Signup {
+//!     #[koruma(NonEmptyValidation::<_>)]
+//!     name: String,
+//! }
+//!
+//! es_fluent_manager_embedded::init();
+//! es_fluent_manager_embedded::select_language(langid!("en"));
+//!
+//! let err = Signup { name: String::new() }.validate().unwrap_err();
+//! let failure = &err.name().all()[0];
+//! assert_eq!(
+//!     failure.to_fluent_string_in(&langid!("fr")).unwrap(),
+//!     "La valeur ne doit pas être vide."
+//! );
+//! assert_eq!(failure.to_fluent_string(), "The value must not be empty.");
+//!
+//! // So does `localized_summary`
+//! assert_eq!(
+//!     err.localized_summary(&langid!("fr")).unwrap(),
+//!     vec!["La valeur ne doit pas être vide."]
+//! );
+//! assert_eq!(failure.to_fluent_string(), "The value must not be empty.");
+//! ```
+//!
+//! A fallback chain fills in messages missing from the selected language.
+//! `de` isn't bundled, so without a chain its messages render as their ids:
+//!
+//! ```rust
+//! use koruma::{Koruma, KorumaAllFluent, ToFluentStringIn as _};
+//! use koruma_collection::collection::NonEmptyValidation;
+//! use unic_langid::langid;
+//!
+//! #[derive(Koruma, KorumaAllFluent)]
+//! struct Signup {
+//!     #[koruma(NonEmptyValidation::<_>)]
+//!     name: String,
+//! }
+//!
+//! es_fluent_manager_embedded::init();
+//! koruma_collection::i18n::set_fallback_chain(&[langid!("fr"), langid!("en")]);
+//!
+//! let err = Signup { name: String::new() }.validate().unwrap_err();
+//! assert_eq!(
+//!     err.localized_summary(&langid!("de")).unwrap(),
+//!     vec!["La valeur ne doit pas être vide."]
+//! );
+//!
+//! // Rendering in a locale on one thread uses the chain too
+//! assert_eq!(
+//!     err.name().all()[0].to_fluent_string_in(&langid!("de")).unwrap(),
+//!     "La valeur ne doit pas être vide."
+//! );
+//! ```
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use es_fluent::__manager_core::{FluentManager, I18nModule, LocalizationError, Localizer};
+use es_fluent::FluentValue;
+use unic_langid::LanguageIdentifier;
+
 es_fluent_manager_embedded::define_i18n_module!();
+
+/// Resolves messages in each fallback language, in order.
+static FALLBACK_CHAIN: RwLock<Vec<FluentManager>> = RwLock::new(Vec::new());
+
+thread_local! {
+    /// Set while the fallback managers resolve or select, whose own copy
+    /// of [`FallbackLocalizer`] must stay out of the way.
+    static RESOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn resolving<R>(f: impl FnOnce() -> R) -> R {
+    RESOLVING.set(true);
+    let result = f();
+    RESOLVING.set(false);
+    result
+}
+
+/// Sets the languages to try, in order, when a message is missing from the
+/// selected language, e.g. `&[langid!("en")]` so missing French messages
+/// render in English.
+///
+/// The chain applies to every message rendered through es-fluent, not only
+/// this crate's, and replaces any previous chain; an empty chain turns
+/// fallback off. It takes effect once `es_fluent_manager_embedded::init()`
+/// has run. A message missing from every fallback language too is left to
+/// es-fluent, which logs a warning and renders the message id.
+pub fn set_fallback_chain(chain: &[LanguageIdentifier]) {
+    let managers = resolving(|| {
+        chain
+            .iter()
+            .map(|lang| {
+                let manager = FluentManager::new_with_discovered_modules();
+                manager.select_language(lang);
+                manager
+            })
+            .collect()
+    });
+    *FALLBACK_CHAIN.write().unwrap_or_else(|e| e.into_inner()) = managers;
+}
+
+/// Registered alongside the embedded modules so it sees every language
+/// selection and can answer lookups the selected language misses.
+struct FallbackModule;
+
+impl I18nModule for FallbackModule {
+    fn name(&self) -> &'static str {
+        "koruma-collection-fallback"
+    }
+
+    fn create_localizer(&self) -> Box<dyn Localizer> {
+        Box::new(FallbackLocalizer {
+            selected: OnceLock::new(),
+        })
+    }
+}
+
+es_fluent::__inventory::submit!(&FallbackModule as &dyn I18nModule);
+
+struct FallbackLocalizer {
+    /// Resolves messages in the language last selected on this localizer's
+    /// manager, which needn't be the global one
+    selected: OnceLock<FluentManager>,
+}
+
+impl Localizer for FallbackLocalizer {
+    fn select_language(&self, lang: &LanguageIdentifier) -> Result<(), LocalizationError> {
+        if !RESOLVING.get() {
+            resolving(|| {
+                self.selected
+                    .get_or_init(FluentManager::new_with_discovered_modules)
+                    .select_language(lang)
+            });
+        }
+        Ok(())
+    }
+
+    fn localize<'a>(
+        &self,
+        id: &str,
+        args: Option<&HashMap<&str, FluentValue<'a>>>,
+    ) -> Option<String> {
+        if RESOLVING.get() {
+            return None;
+        }
+        let chain = FALLBACK_CHAIN.read().unwrap_or_else(|e| e.into_inner());
+        if chain.is_empty() {
+            return None;
+        }
+        // Managers can list this localizer before the module owning `id`,
+        // so resolve the selected language first rather than assume it missed
+        resolving(|| {
+            self.selected
+                .get()
+                .and_then(|manager| manager.localize(id, args))
+                .or_else(|| chain.iter().find_map(|manager| manager.localize(id, args)))
+        })
+    }
+}
+
+/// Passes an optional count to fluent as a number, so plural selectors apply
+/// to it, or as `"none"` when it isn't set.
+pub(crate) fn count_or_none<N>(count: &Option<N>) -> es_fluent::FluentValue<'static>
+where
+    N: Copy + Into<es_fluent::FluentValue<'static>>,
+{
+    count.map_or_else(|| "none".into(), Into::into)
+}
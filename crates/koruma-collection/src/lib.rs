@@ -1,4 +1,6 @@
+mod registry;
 mod validators;
+pub use registry::{RegisteredValidator, all_validators};
 pub use validators::*;
 
 #[cfg(feature = "fluent")]
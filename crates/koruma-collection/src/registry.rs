@@ -0,0 +1,130 @@
+//! Registry of the validators exported under the enabled features.
+//!
+//! Every entry names a concrete instantiation of its validator, so a feature
+//! combination that exports a validator without the code it depends on fails
+//! to compile here rather than in a downstream crate.
+
+/// A validator exported by koruma-collection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RegisteredValidator {
+    /// Name of the validator type (e.g. `"EmailValidation"`).
+    pub name: &'static str,
+    /// Path of the module exporting the validator (e.g. `"format"`).
+    pub module: &'static str,
+    /// Cargo feature the validator is gated behind, if any.
+    pub feature: Option<&'static str>,
+}
+
+/// Registers a validator, referencing a concrete instantiation of its type.
+macro_rules! register {
+    ($validators:ident, $($module:ident)::+ => $name:ident < $($ty:ty),* > $(, $feature:literal)?) => {{
+        let _ = ::std::marker::PhantomData::<crate::$($module)::+::$name<$($ty),*>>;
+        $validators.push(RegisteredValidator {
+            name: stringify!($name),
+            module: stringify!($($module)::+),
+            feature: register!(@feature $($feature)?),
+        });
+    }};
+    (@feature) => { None };
+    (@feature $feature:literal) => { Some($feature) };
+}
+
+/// Returns every validator exported under the enabled features.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_collection::all_validators;
+///
+/// let validators = all_validators();
+/// assert!(validators.iter().any(|v| v.name == "RangeValidation"));
+///
+/// // Feature-gated validators are only listed when their feature is enabled
+/// assert_eq!(
+///     validators.iter().any(|v| v.name == "EmailValidation"),
+///     cfg!(feature = "email"),
+/// );
+/// ```
+pub fn all_validators() -> Vec<RegisteredValidator> {
+    let mut validators = Vec::new();
+
+    // collection
+    register!(validators, collection => LenValidation<Vec<u8>>);
+    register!(validators, collection => NonEmptyValidation<Vec<u8>>);
+
+    // format
+    #[cfg(feature = "base64")]
+    register!(validators, format => Base64Validation<String>, "base64");
+    register!(validators, format => CidrValidation<String>);
+    #[cfg(feature = "credit-card")]
+    register!(validators, format => CreditCardValidation<String>, "credit-card");
+    #[cfg(feature = "email")]
+    register!(validators, format => EmailValidation<String>, "email");
+    register!(validators, format => IpValidation<String>);
+    #[cfg(feature = "serde_json")]
+    register!(validators, format => JsonValidation<String>, "serde_json");
+    register!(validators, format => MacAddressValidation<String>);
+    #[cfg(feature = "phone-number")]
+    register!(validators, format => PhoneNumberValidation<String>, "phone-number");
+    register!(validators, format => PortValidation<u16>);
+    #[cfg(feature = "semver")]
+    register!(validators, format => SemVerValidation<String>, "semver");
+    #[cfg(feature = "url")]
+    register!(validators, format => UrlValidation<String>, "url");
+    #[cfg(feature = "uuid")]
+    register!(validators, format => UuidValidation<String>, "uuid");
+
+    // general
+    register!(validators, general => EqualsValidation<String>);
+    register!(validators, general => NotEqualsValidation<String>);
+    register!(validators, general => NotOneOfValidation<String>);
+    register!(validators, general => OneOfValidation<String>);
+    register!(validators, general => RequiredValidation<Option<String>>);
+
+    // numeric
+    register!(validators, numeric => FiniteValidation<f64>);
+    register!(validators, numeric => MultipleOfValidation<i32>);
+    register!(validators, numeric => NegativeValidation<i32>);
+    register!(validators, numeric => NonNegativeValidation<i32>);
+    register!(validators, numeric => NonPositiveValidation<i32>);
+    register!(validators, numeric => PositiveValidation<i32>);
+    register!(validators, numeric => PrecisionValidation<f64>);
+    register!(validators, numeric => RangeValidation<i32>);
+    register!(validators, numeric => StepRangeValidation<i32>);
+
+    // security
+    register!(validators, security => PasswordStrengthValidation<String>);
+
+    // string
+    register!(validators, string => AlphanumericValidation<String>);
+    register!(validators, string => AsciiValidation<String>);
+    register!(validators, string => BalancedDelimitersValidation<String>);
+    register!(validators, string => ContainsValidation<String>);
+    register!(validators, string => EndsWithAnyValidation<String>);
+    register!(validators, string => EntropyValidation<String>);
+    register!(validators, string => HexValidation<String>);
+    register!(validators, string => MatchesValidation<String>);
+    register!(validators, string => NoControlCharsValidation<String>);
+    #[cfg(feature = "regex")]
+    register!(validators, string => PatternValidation<String>, "regex");
+    register!(validators, string => PrefixValidation<String>);
+    register!(validators, string => SplitCountValidation<String>);
+    register!(validators, string => StartsWithAnyValidation<String>);
+    register!(validators, string => SuffixValidation<String>);
+    register!(validators, string => TrimmedLengthValidation<String>);
+    register!(validators, string => WordCountValidation<String>);
+    #[cfg(feature = "heck")]
+    register!(validators, string::en => CaseValidation<String>, "heck");
+
+    // time
+    #[cfg(feature = "chrono")]
+    register!(validators, time => AgeValidation<chrono::NaiveDate>, "chrono");
+    #[cfg(feature = "chrono")]
+    register!(validators, time => DateRangeValidation<chrono::NaiveDate>, "chrono");
+    #[cfg(feature = "chrono")]
+    register!(validators, time => NotInFutureValidation<chrono::NaiveDate>, "chrono");
+    #[cfg(feature = "chrono")]
+    register!(validators, time => NotInPastValidation<chrono::NaiveDate>, "chrono");
+
+    validators
+}
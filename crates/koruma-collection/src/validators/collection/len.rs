@@ -1,6 +1,103 @@
 use koruma::{Validate, validator};
 
-use super::HasLen;
+use super::{HasCapacity, HasLen};
+
+/// How [`LenValidation`] measures the length of strings.
+///
+/// Collections other than strings always count their elements.
+///
+/// Non-exhaustive because `Graphemes` only exists with the `unicode` feature,
+/// which another crate in the build may turn on.
+///
+/// ```rust
+/// use koruma_collection::collection::{HasLen, LenMode};
+///
+/// let name = "café".to_string();
+/// assert_eq!(name.len_in(LenMode::Bytes), 5);
+/// assert_eq!(name.len_in(LenMode::Chars), 4);
+///
+/// // "e" followed by a combining acute accent
+/// # #[cfg(feature = "unicode")]
+/// assert_eq!("cafe\u{301}".to_string().len_in(LenMode::Graphemes), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum LenMode {
+    /// UTF-8 bytes (`str::len`)
+    #[default]
+    Bytes,
+    /// Unicode scalar values (`str::chars`)
+    Chars,
+    /// Extended grapheme clusters, so combined characters and most emoji
+    /// count as one (requires the `unicode` feature)
+    #[cfg(feature = "unicode")]
+    Graphemes,
+}
+
+impl std::fmt::Display for LenMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LenMode::Bytes => write!(f, "bytes"),
+            LenMode::Chars => write!(f, "characters"),
+            #[cfg(feature = "unicode")]
+            LenMode::Graphemes => write!(f, "graphemes"),
+        }
+    }
+}
+
+/// A fill ratio bound of [`LenValidation`].
+///
+/// Only collections implementing [`HasCapacity`] can be given one, so a bound
+/// on a `Vec` is rejected at compile time.
+pub struct FillRatio<T> {
+    /// The bound, as a fraction of the capacity
+    pub ratio: f64,
+    measure: fn(&T) -> f64,
+}
+
+impl<T: HasCapacity> FillRatio<T> {
+    /// Creates a bound measured with [`HasCapacity::fill_ratio`].
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            ratio,
+            measure: T::fill_ratio,
+        }
+    }
+}
+
+impl<T> FillRatio<T> {
+    /// Returns the fill ratio of `value`.
+    pub fn measure(&self, value: &T) -> f64 {
+        (self.measure)(value)
+    }
+}
+
+impl<T> Clone for FillRatio<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FillRatio<T> {}
+
+impl<T> std::fmt::Debug for FillRatio<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FillRatio").field(&self.ratio).finish()
+    }
+}
+
+/// Serializes as the bound itself.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for FillRatio<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.ratio.serialize(serializer)
+    }
+}
 
 /// Length validation for collections.
 ///
@@ -19,8 +116,124 @@ use super::HasLen;
 ///
 /// Validates that a collection's length is within the specified bounds.
 ///
-/// Works with any type that implements `HasLen + Clone`.
-#[validator]
+/// Works with any type that implements `HasLen + Clone`. Strings are measured
+/// in bytes unless another [`LenMode`] is given:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::collection::{LenMode, LenValidation};
+///
+/// #[derive(Koruma)]
+/// struct Profile {
+///     #[koruma(LenValidation::<_>(min = 1, max = 4, mode = LenMode::Chars))]
+///     name: String,
+/// }
+///
+/// // 5 bytes, but 4 characters
+/// assert!(Profile { name: "café".to_string() }.validate().is_ok());
+/// ```
+///
+/// Custom collections only need to implement [`HasLen`]; the derive passes
+/// the field through as-is, without unwrapping it like a `Vec`:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::collection::{HasLen, LenValidation};
+///
+/// #[derive(Clone, Debug)]
+/// struct Playlist {
+///     tracks: Vec<String>,
+/// }
+///
+/// impl HasLen for Playlist {
+///     fn len(&self) -> usize {
+///         self.tracks.len()
+///     }
+/// }
+///
+/// #[derive(Koruma)]
+/// struct Party {
+///     #[koruma(LenValidation::<_>(min = 1, max = 3))]
+///     playlist: Playlist,
+///     #[koruma(LenValidation::<_>(min = 0, max = 1))]
+///     backup: Option<Playlist>,
+/// }
+///
+/// let party = Party {
+///     playlist: Playlist { tracks: Vec::new() },
+///     backup: Some(Playlist { tracks: vec!["a".into(), "b".into()] }),
+/// };
+/// let err = party.validate().unwrap_err();
+/// assert_eq!(err.playlist().len_validation().unwrap().actual_len, 0);
+/// assert_eq!(err.backup().len_validation().unwrap().actual_len, 2);
+/// ```
+///
+/// `min_fill_ratio` and `max_fill_ratio` bound `len() / capacity()` too, for
+/// collections with a fixed capacity, i.e. a [`HasCapacity`] impl:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::collection::{HasCapacity, HasLen, LenValidation};
+///
+/// /// A fixed-capacity buffer, like `ArrayVec`.
+/// #[derive(Clone, Debug)]
+/// struct RingBuffer {
+///     items: Vec<u8>,
+///     capacity: usize,
+/// }
+///
+/// impl HasLen for RingBuffer {
+///     fn len(&self) -> usize {
+///         self.items.len()
+///     }
+/// }
+///
+/// impl HasCapacity for RingBuffer {
+///     fn capacity(&self) -> usize {
+///         self.capacity
+///     }
+/// }
+///
+/// #[derive(Koruma)]
+/// struct Sampler {
+///     #[koruma(LenValidation::<_>(min = 0, max = 64, min_fill_ratio = 0.5))]
+///     buffer: RingBuffer,
+/// }
+///
+/// let half_full = Sampler {
+///     buffer: RingBuffer { items: vec![1, 2], capacity: 4 },
+/// };
+/// assert!(half_full.validate().is_ok());
+///
+/// let mostly_empty = Sampler {
+///     buffer: RingBuffer { items: vec![1], capacity: 4 },
+/// };
+/// let err = mostly_empty.validate().unwrap_err();
+/// let len_err = err.buffer().len_validation().unwrap();
+/// assert_eq!(len_err.actual_fill_ratio, Some(0.25));
+/// assert_eq!(len_err.to_string(), "fill ratio 0.25 is not within bounds [0.5, 1]");
+///
+/// // A zero-capacity buffer holds nothing, so its fill ratio is 0
+/// let zero_capacity = Sampler {
+///     buffer: RingBuffer { items: vec![], capacity: 0 },
+/// };
+/// assert!(zero_capacity.validate().is_err());
+/// ```
+///
+/// A `Vec`'s capacity depends on its allocation history, so it can't be given
+/// a fill ratio bound:
+///
+/// ```compile_fail
+/// use koruma::Koruma;
+/// use koruma_collection::collection::LenValidation;
+///
+/// #[derive(Koruma)]
+/// struct Queue {
+///     #[koruma(LenValidation::<_>(min = 0, max = 64, max_fill_ratio = 0.9))]
+///     jobs: Vec<u32>,
+/// }
+/// ```
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Length",
     description = "Validates string length is between 1 and 10",
@@ -33,34 +246,113 @@ use super::HasLen;
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct LenValidation<T: HasLen> {
     /// Minimum allowed length (inclusive)
     pub min: usize,
     /// Maximum allowed length (inclusive)
     pub max: usize,
+    /// How string lengths are measured
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub mode: LenMode,
+    /// Minimum allowed fill ratio (inclusive), if any
+    #[builder(setters(vis = "", name = min_fill_ratio_bound))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<FillRatio<T>>| {
+        x.map_or(0.0, |bound| bound.ratio)
+    })))]
+    pub min_fill_ratio: Option<FillRatio<T>>,
+    /// Maximum allowed fill ratio (inclusive), if any
+    #[builder(setters(vis = "", name = max_fill_ratio_bound))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<FillRatio<T>>| {
+        x.map_or(1.0, |bound| bound.ratio)
+    })))]
+    pub max_fill_ratio: Option<FillRatio<T>>,
     /// The collection being validated (stored for error context)
     #[koruma(value)]
-    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.len())))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
     pub actual: T,
+    /// Length of `actual` measured in `mode`
+    #[builder(skip = actual.len_in(mode))]
+    pub actual_len: usize,
+    /// Fill ratio of `actual`, if a fill ratio bound is set
+    #[builder(skip = min_fill_ratio
+        .or(max_fill_ratio)
+        .map(|bound| bound.measure(&actual)))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<f64>| {
+        x.map_or("none".to_string(), |ratio| ratio.to_string())
+    })))]
+    pub actual_fill_ratio: Option<f64>,
+}
+
+impl<T: HasCapacity, S: len_validation_builder::State> LenValidationBuilder<T, S> {
+    /// Sets the minimum allowed fill ratio (inclusive).
+    pub fn min_fill_ratio(
+        self,
+        ratio: f64,
+    ) -> LenValidationBuilder<T, len_validation_builder::SetMinFillRatio<S>>
+    where
+        S::MinFillRatio: koruma::bon::IsUnset,
+    {
+        self.min_fill_ratio_bound(FillRatio::new(ratio))
+    }
+
+    /// Sets the maximum allowed fill ratio (inclusive).
+    pub fn max_fill_ratio(
+        self,
+        ratio: f64,
+    ) -> LenValidationBuilder<T, len_validation_builder::SetMaxFillRatio<S>>
+    where
+        S::MaxFillRatio: koruma::bon::IsUnset,
+    {
+        self.max_fill_ratio_bound(FillRatio::new(ratio))
+    }
+}
+
+impl<T: HasLen> LenValidation<T> {
+    fn len_within_bounds(&self, len: usize) -> bool {
+        !(len < self.min || len > self.max)
+    }
+
+    fn fill_ratio_within_bounds(&self, value: &T) -> bool {
+        self.min_fill_ratio
+            .is_none_or(|bound| bound.measure(value) >= bound.ratio)
+            && self
+                .max_fill_ratio
+                .is_none_or(|bound| bound.measure(value) <= bound.ratio)
+    }
 }
 
 impl<T: HasLen + Clone> Validate<T> for LenValidation<T> {
     fn validate(&self, value: &T) -> bool {
-        let len = value.len();
-        !(len < self.min || len > self.max)
+        self.len_within_bounds(value.len_in(self.mode)) && self.fill_ratio_within_bounds(value)
     }
 }
 
 #[cfg(feature = "fmt")]
 impl<T: HasLen + Clone> std::fmt::Display for LenValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (true, Some(ratio)) = (
+            self.len_within_bounds(self.actual_len),
+            self.actual_fill_ratio,
+        ) {
+            return write!(
+                f,
+                "fill ratio {} is not within bounds [{}, {}]",
+                ratio,
+                self.min_fill_ratio.map_or(0.0, |bound| bound.ratio),
+                self.max_fill_ratio.map_or(1.0, |bound| bound.ratio)
+            );
+        }
         write!(
             f,
             "length {} is not within bounds [{}, {}]",
-            self.actual.len(),
-            self.min,
-            self.max
-        )
+            self.actual_len, self.min, self.max
+        )?;
+        if self.mode != LenMode::Bytes {
+            write!(f, " ({})", self.mode)?;
+        }
+        Ok(())
     }
 }
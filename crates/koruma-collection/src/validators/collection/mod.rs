@@ -9,6 +9,23 @@ pub trait HasLen {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns the length measured in `mode`.
+    ///
+    /// Only strings distinguish between modes; the default returns [`len`](Self::len).
+    fn len_in(&self, mode: LenMode) -> usize {
+        let _ = mode;
+        self.len()
+    }
+}
+
+fn str_len_in(s: &str, mode: LenMode) -> usize {
+    match mode {
+        LenMode::Bytes => s.len(),
+        LenMode::Chars => s.chars().count(),
+        #[cfg(feature = "unicode")]
+        LenMode::Graphemes => unicode_segmentation::UnicodeSegmentation::graphemes(s, true).count(),
+    }
 }
 
 // Implementations for std collections
@@ -52,12 +69,20 @@ impl HasLen for String {
     fn len(&self) -> usize {
         self.len()
     }
+
+    fn len_in(&self, mode: LenMode) -> usize {
+        str_len_in(self, mode)
+    }
 }
 
 impl HasLen for str {
     fn len(&self) -> usize {
         self.len()
     }
+
+    fn len_in(&self, mode: LenMode) -> usize {
+        str_len_in(self, mode)
+    }
 }
 
 impl<T> HasLen for [T] {
@@ -75,12 +100,40 @@ impl<T, const N: usize> HasLen for [T; N] {
 #[cfg(feature = "smallvec")]
 impl<T, const N: usize> HasLen for smallvec::SmallVec<[T; N]> {
     fn len(&self) -> usize {
-        N
+        self.len()
+    }
+}
+
+/// Trait for collections with a fixed capacity, whose fill ratio
+/// [`LenValidation`] can bound.
+///
+/// Growable collections like `Vec` don't implement it: their `capacity()`
+/// depends on the allocator and how the value was built, so a fill ratio
+/// over it wouldn't be deterministic. Arrays don't either, as they are always
+/// full.
+pub trait HasCapacity: HasLen {
+    fn capacity(&self) -> usize;
+
+    /// Returns `len() / capacity()`, or `0.0` when the capacity is zero.
+    fn fill_ratio(&self) -> f64 {
+        match self.capacity() {
+            0 => 0.0,
+            capacity => self.len() as f64 / capacity as f64,
+        }
+    }
+}
+
+/// The inline capacity `N`. A vector that has spilled to the heap holds
+/// more than `N` items and counts as full.
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> HasCapacity for smallvec::SmallVec<[T; N]> {
+    fn capacity(&self) -> usize {
+        N.max(self.len())
     }
 }
 
 mod len;
 mod non_empty;
 
-pub use len::LenValidation;
+pub use len::{FillRatio, LenMode, LenValidation};
 pub use non_empty::NonEmptyValidation;
@@ -22,7 +22,7 @@ use super::HasLen;
 /// Validates that a string or collection is not empty.
 ///
 /// Works with any type that implements `HasLen + Clone`.
-#[validator]
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "NonEmpty",
     description = "Validates that the input is not empty",
@@ -33,6 +33,7 @@ use super::HasLen;
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct NonEmptyValidation<T: HasLen> {
     /// The value being validated (stored for error context)
@@ -0,0 +1,140 @@
+use base64::Engine;
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// Why a string failed [`Base64Validation`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Base64Failure {
+    /// The string doesn't decode; `reason` is the decoder's error message.
+    Invalid { reason: String },
+    /// The string decodes to more than `max_decoded_bytes` bytes.
+    TooLarge,
+}
+
+impl Base64Failure {
+    /// Returns the Fluent selector key for this failure
+    /// (`invalid` or `too_large`).
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Invalid { .. } => "invalid",
+            Self::TooLarge => "too_large",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it decodes within the size cap.
+fn base64_failure(actual: &str, url_safe: bool, max: Option<usize>) -> Option<Base64Failure> {
+    // The estimate exceeds the decoded length by at most 2 bytes, so inputs
+    // well past the cap are rejected without decoding them
+    if max.is_some_and(|max| base64::decoded_len_estimate(actual.len()) > max.saturating_add(2)) {
+        return Some(Base64Failure::TooLarge);
+    }
+
+    let decoded = if url_safe {
+        base64::engine::general_purpose::URL_SAFE.decode(actual)
+    } else {
+        base64::engine::general_purpose::STANDARD.decode(actual)
+    };
+    match decoded {
+        Err(error) => Some(Base64Failure::Invalid {
+            reason: error.to_string(),
+        }),
+        Ok(bytes) if max.is_some_and(|max| bytes.len() > max) => Some(Base64Failure::TooLarge),
+        Ok(_) => None,
+    }
+}
+
+/// Base64 validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{Base64Failure, Base64Validation};
+///
+/// #[derive(Koruma)]
+/// struct Upload {
+///     #[koruma(Base64Validation::<_>(max_decoded_bytes = 8))]
+///     payload: String,
+/// }
+///
+/// let upload = Upload { payload: "aGVsbG8=".to_string() };
+/// assert!(upload.validate().is_ok());
+///
+/// let upload = Upload { payload: "aGVsbG8".to_string() };
+/// let err = upload.validate().unwrap_err();
+/// let base64_err = err.payload().base64_validation().unwrap();
+/// assert!(matches!(base64_err.failure, Some(Base64Failure::Invalid { .. })));
+///
+/// // "hello world" is 11 bytes once decoded
+/// let upload = Upload { payload: "aGVsbG8gd29ybGQ=".to_string() };
+/// let err = upload.validate().unwrap_err();
+/// let base64_err = err.payload().base64_validation().unwrap();
+/// assert_eq!(base64_err.failure, Some(Base64Failure::TooLarge));
+/// assert_eq!(base64_err.to_string(), "base64 payload must decode to at most 8 bytes");
+/// ```
+///
+/// Validates that a string is padded base64, using the standard alphabet or,
+/// with `url_safe = true`, the URL-safe one (`-` and `_` instead of `+` and
+/// `/`).
+///
+/// Set `max_decoded_bytes` to cap the decoded payload size; inputs far over
+/// the cap are rejected from their length alone, without decoding. Why
+/// validation failed is kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Base64",
+    description = "Validates that the input is standard padded base64",
+    create = |input: &str| {
+        Base64Validation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct Base64Validation<T: StringLike> {
+    /// Whether to use the URL-safe alphabet (default `false`)
+    #[builder(default = false)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub url_safe: bool,
+    /// Maximum decoded size in bytes, if any
+    #[cfg_attr(feature = "fluent", fluent(value(crate::i18n::count_or_none)))]
+    pub max_decoded_bytes: Option<usize>,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = base64_failure(actual.as_ref(), url_safe, max_decoded_bytes))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<Base64Failure>| {
+        x.as_ref().map_or("none", Base64Failure::key).to_string()
+    })))]
+    pub failure: Option<Base64Failure>,
+}
+
+impl<T: StringLike> Validate<T> for Base64Validation<T> {
+    fn validate(&self, value: &T) -> bool {
+        base64_failure(value.as_ref(), self.url_safe, self.max_decoded_bytes).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for Base64Validation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.failure, self.max_decoded_bytes) {
+            (Some(Base64Failure::TooLarge), Some(max)) => {
+                write!(f, "base64 payload must decode to at most {} bytes", max)
+            },
+            (Some(Base64Failure::Invalid { reason }), _) => write!(f, "invalid base64: {}", reason),
+            _ => write!(f, "invalid base64"),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::net::IpAddr;
+
+use koruma::{Validate, validator};
+
+use super::IpKind;
+use crate::string::StringLike;
+
+/// Why a string failed [`CidrValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CidrFailure {
+    /// There is no `/` separating the address from the prefix length.
+    MissingPrefix,
+    /// The part before the `/` isn't an address of the required kind.
+    InvalidAddress,
+    /// The prefix length isn't a number up to 32 (IPv4) or 128 (IPv6).
+    InvalidPrefixLength,
+    /// With `strict`, the address has bits set past the prefix length
+    /// (e.g. `192.168.0.1/24`).
+    HostBitsSet,
+}
+
+impl CidrFailure {
+    /// Returns the Fluent selector key for this failure (`missing_prefix`,
+    /// `invalid_address`, `invalid_prefix_length` or `host_bits_set`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::MissingPrefix => "missing_prefix",
+            Self::InvalidAddress => "invalid_address",
+            Self::InvalidPrefixLength => "invalid_prefix_length",
+            Self::HostBitsSet => "host_bits_set",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it is a CIDR block of `kind`.
+fn cidr_failure(actual: &str, kind: &IpKind, strict: bool) -> Option<CidrFailure> {
+    let Some((address, prefix)) = actual.split_once('/') else {
+        return Some(CidrFailure::MissingPrefix);
+    };
+    let Some(address) = kind.parse(address) else {
+        return Some(CidrFailure::InvalidAddress);
+    };
+
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+    // Digits only: `u32::from_str` would also accept a leading `+`
+    let prefix = Some(prefix)
+        .filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|p| p.parse::<u32>().ok())
+        .filter(|&p| p <= max_prefix);
+    let Some(prefix) = prefix else {
+        return Some(CidrFailure::InvalidPrefixLength);
+    };
+
+    // Left-align both families in 128 bits so one mask covers them
+    let bits = match address {
+        IpAddr::V4(v4) => u128::from(u32::from(v4)) << 96,
+        IpAddr::V6(v6) => u128::from(v6),
+    };
+    let host_bits = bits & u128::MAX.checked_shr(prefix).unwrap_or(0);
+    (strict && host_bits != 0).then_some(CidrFailure::HostBitsSet)
+}
+
+/// CIDR block validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{CidrFailure, CidrValidation, IpKind};
+///
+/// #[derive(Koruma)]
+/// struct Firewall {
+///     #[koruma(CidrValidation::<_>(kind = IpKind::Any))]
+///     allow: String,
+///     #[koruma(CidrValidation::<_>(kind = IpKind::V4, strict = true))]
+///     subnet: String,
+/// }
+///
+/// let firewall = Firewall {
+///     allow: "2001:db8::/32".to_string(),
+///     subnet: "192.168.0.0/24".to_string(),
+/// };
+/// assert!(firewall.validate().is_ok());
+///
+/// let firewall = Firewall {
+///     allow: "10.0.0.0/33".to_string(),
+///     subnet: "192.168.0.1/24".to_string(),
+/// };
+/// let err = firewall.validate().unwrap_err();
+/// let allow_err = err.allow().cidr_validation().unwrap();
+/// assert_eq!(allow_err.failure, Some(CidrFailure::InvalidPrefixLength));
+///
+/// let subnet_err = err.subnet().cidr_validation().unwrap();
+/// assert_eq!(subnet_err.failure, Some(CidrFailure::HostBitsSet));
+/// assert_eq!(subnet_err.to_string(), "CIDR block has host bits set past its prefix length");
+/// ```
+///
+/// Validates that a string is a CIDR block, an address of the given `kind`
+/// followed by `/` and a prefix length of at most 32 (IPv4) or 128 (IPv6).
+///
+/// Set `strict = true` to also require the address to be the network
+/// address, with every bit past the prefix length cleared. Why validation
+/// failed is kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "CIDR Block",
+    description = "Validates that the input is a CIDR block like 192.168.0.0/24",
+    create = |input: &str| {
+        CidrValidation::builder()
+            .kind(IpKind::Any)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct CidrValidation<T: StringLike> {
+    /// The type of IP address the block must use
+    pub kind: IpKind,
+    /// Whether bits past the prefix length must be zero (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub strict: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = cidr_failure(actual.as_ref(), &kind, strict))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<CidrFailure>| {
+        x.map_or("none", CidrFailure::key).to_string()
+    })))]
+    pub failure: Option<CidrFailure>,
+}
+
+impl<T: StringLike> Validate<T> for CidrValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        cidr_failure(value.as_ref(), &self.kind, self.strict).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for CidrValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.failure {
+            Some(CidrFailure::MissingPrefix) => write!(f, "CIDR block is missing a /prefix length"),
+            Some(CidrFailure::InvalidPrefixLength) => write!(f, "invalid CIDR prefix length"),
+            Some(CidrFailure::HostBitsSet) => {
+                write!(f, "CIDR block has host bits set past its prefix length")
+            },
+            _ => write!(f, "not a valid {} CIDR block", self.kind),
+        }
+    }
+}
@@ -1,7 +1,14 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 use koruma::{Validate, validator};
 
 /// The type of IP address to validate
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub enum IpKind {
     Any,
@@ -19,23 +26,115 @@ impl std::fmt::Display for IpKind {
     }
 }
 
+impl IpKind {
+    /// Parses `s` as an address of this kind.
+    pub(super) fn parse(&self, s: &str) -> Option<IpAddr> {
+        match self {
+            IpKind::Any => s.parse().ok(),
+            IpKind::V4 => s.parse::<Ipv4Addr>().ok().map(IpAddr::V4),
+            IpKind::V6 => s.parse::<Ipv6Addr>().ok().map(IpAddr::V6),
+        }
+    }
+}
+
+/// A disqualifying property of an address rejected by [`IpValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum IpRejection {
+    /// A private address: IPv4 `10.0.0.0/8`, `172.16.0.0/12` or
+    /// `192.168.0.0/16`, or IPv6 unique local `fc00::/7`.
+    Private,
+    /// A link-local address: IPv4 `169.254.0.0/16` or IPv6 `fe80::/10`.
+    LinkLocal,
+    /// A loopback address: IPv4 `127.0.0.0/8` or IPv6 `::1`.
+    Loopback,
+}
+
+impl IpRejection {
+    /// Returns the Fluent selector key for this rejection
+    /// (`private`, `link_local` or `loopback`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Private => "private",
+            Self::LinkLocal => "link_local",
+            Self::Loopback => "loopback",
+        }
+    }
+}
+
+impl std::fmt::Display for IpRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IpRejection::Private => write!(f, "private address"),
+            IpRejection::LinkLocal => write!(f, "link-local address"),
+            IpRejection::Loopback => write!(f, "loopback"),
+        }
+    }
+}
+
+/// Returns the first disallowed property of `addr`, if any.
+///
+/// IPv4-mapped IPv6 addresses (`::ffff:10.0.0.1`) are classified as the
+/// IPv4 address they map.
+fn ip_rejection(addr: IpAddr, reject_private: bool, reject_loopback: bool) -> Option<IpRejection> {
+    let (private, link_local) = match addr.to_canonical() {
+        IpAddr::V4(v4) => (v4.is_private(), v4.is_link_local()),
+        IpAddr::V6(v6) => (v6.is_unique_local(), v6.is_unicast_link_local()),
+    };
+    if reject_loopback && addr.to_canonical().is_loopback() {
+        Some(IpRejection::Loopback)
+    } else if reject_private && private {
+        Some(IpRejection::Private)
+    } else if reject_private && link_local {
+        Some(IpRejection::LinkLocal)
+    } else {
+        None
+    }
+}
+
 /// IP address validation for koruma.
 ///
 ///
 /// # Example
 /// ```rust
 /// use koruma::Koruma;
-/// use koruma_collection::format::{IpValidation, IpKind};
+/// use koruma_collection::format::{IpKind, IpRejection, IpValidation};
 ///
 /// #[derive(Koruma)]
 /// struct NetworkConfig {
 ///     #[koruma(IpValidation::<_>(kind = IpKind::V4))]
 ///     ip_address: String,
+///     #[koruma(IpValidation::<_>(kind = IpKind::Any, reject_private = true, reject_loopback = true))]
+///     webhook_host: String,
 /// }
+///
+/// let config = NetworkConfig {
+///     ip_address: "192.168.1.10".to_string(),
+///     webhook_host: "2001:db8::1".to_string(),
+/// };
+/// assert!(config.validate().is_ok());
+///
+/// let config = NetworkConfig {
+///     ip_address: "192.168.1.10".to_string(),
+///     webhook_host: "127.0.0.1".to_string(),
+/// };
+/// let err = config.validate().unwrap_err();
+/// let host_err = err.webhook_host().ip_validation().unwrap();
+/// assert_eq!(host_err.rejection, Some(IpRejection::Loopback));
+/// assert_eq!(host_err.to_string(), "loopback not allowed");
 /// ```
 ///
 /// Validates that a string is a valid IP address.
-#[validator]
+///
+/// Set `reject_private = true` to also refuse private and link-local
+/// addresses, and `reject_loopback = true` to refuse loopback ones, e.g. for
+/// user-supplied hosts the server will connect to. The disqualifying
+/// property is kept in `rejection`.
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "IP Address",
     description = "Validates that the input is a valid IP address (v4 or v6)",
@@ -47,30 +146,47 @@ impl std::fmt::Display for IpKind {
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct IpValidation<T: AsRef<str>> {
     /// The type of IP address to validate
     pub kind: IpKind,
+    /// Whether private and link-local addresses are refused (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub reject_private: bool,
+    /// Whether loopback addresses are refused (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub reject_loopback: bool,
     /// The string being validated (stored for error context)
     #[koruma(value)]
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
     pub actual: T,
+    /// Why a well-formed `actual` was refused, if it was
+    #[builder(skip = kind
+        .parse(actual.as_ref())
+        .and_then(|addr| ip_rejection(addr, reject_private, reject_loopback)))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<IpRejection>| {
+        x.map_or("none", IpRejection::key).to_string()
+    })))]
+    pub rejection: Option<IpRejection>,
 }
 
 impl<T: AsRef<str>> Validate<T> for IpValidation<T> {
     fn validate(&self, value: &T) -> bool {
-        let s = value.as_ref();
-        match self.kind {
-            IpKind::Any => s.parse::<std::net::IpAddr>().is_ok(),
-            IpKind::V4 => s.parse::<std::net::Ipv4Addr>().is_ok(),
-            IpKind::V6 => s.parse::<std::net::Ipv6Addr>().is_ok(),
-        }
+        self.kind.parse(value.as_ref()).is_some_and(|addr| {
+            ip_rejection(addr, self.reject_private, self.reject_loopback).is_none()
+        })
     }
 }
 
 #[cfg(feature = "fmt")]
 impl<T: AsRef<str>> std::fmt::Display for IpValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "not a valid {} address", self.kind)
+        match self.rejection {
+            Some(rejection) => write!(f, "{} not allowed", rejection),
+            None => write!(f, "not a valid {} address", self.kind),
+        }
     }
 }
@@ -0,0 +1,266 @@
+use std::marker::PhantomData;
+
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// The top-level JSON type required by [`JsonValidation`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub enum JsonKind {
+    #[default]
+    Any,
+    Object,
+    Array,
+}
+
+impl JsonKind {
+    /// The kind of a parsed value, `Any` for anything but objects and arrays.
+    fn of(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Object(_) => JsonKind::Object,
+            serde_json::Value::Array(_) => JsonKind::Array,
+            _ => JsonKind::Any,
+        }
+    }
+
+    fn accepts(&self, found: &JsonKind) -> bool {
+        matches!(
+            (self, found),
+            (JsonKind::Any, _)
+                | (JsonKind::Object, JsonKind::Object)
+                | (JsonKind::Array, JsonKind::Array)
+        )
+    }
+}
+
+impl std::fmt::Display for JsonKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonKind::Any => write!(f, "value"),
+            JsonKind::Object => write!(f, "object"),
+            JsonKind::Array => write!(f, "array"),
+        }
+    }
+}
+
+/// Where and why a string failed to parse in [`JsonValidation`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct JsonSyntaxError {
+    /// The parser's error message, without the position suffix
+    pub message: String,
+    /// 1-based line of the error
+    pub line: usize,
+    /// 1-based column of the error, `0` at the end of the input
+    pub column: usize,
+    /// Byte offset of the error into the input
+    pub offset: usize,
+}
+
+impl JsonSyntaxError {
+    /// Locates `error`, returned while parsing `input`.
+    fn of(input: &str, error: &serde_json::Error) -> Self {
+        let (line, column) = (error.line(), error.column());
+        let line_start: usize = input
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let message = error.to_string();
+        let message = match message.rfind(" at line ") {
+            Some(suffix) => message[..suffix].to_string(),
+            None => message,
+        };
+        Self {
+            message,
+            line,
+            column,
+            offset: (line_start + column.saturating_sub(1)).min(input.len()),
+        }
+    }
+}
+
+/// Longest excerpt of the input, in bytes, that [`JsonValidation`] keeps.
+pub const JSON_EXCERPT_LEN: usize = 64;
+
+/// What [`JsonValidation`] keeps of the string it checked.
+///
+/// Only the first [`JSON_EXCERPT_LEN`] bytes are stored, cut back to a
+/// character boundary, so a large document isn't copied into the error.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct JsonInput {
+    /// The start of the input
+    pub excerpt: String,
+    /// Byte length of the whole input
+    pub len: usize,
+    /// The top-level type of the input (`Any` for scalars), or `None` if it
+    /// isn't valid JSON
+    pub kind: Option<JsonKind>,
+    /// The parse error, or `None` if the input is valid JSON
+    pub syntax_error: Option<JsonSyntaxError>,
+}
+
+impl JsonInput {
+    fn of(input: &str) -> Self {
+        let mut end = input.len().min(JSON_EXCERPT_LEN);
+        while !input.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (kind, syntax_error) = match serde_json::from_str::<serde_json::Value>(input) {
+            Ok(value) => (Some(JsonKind::of(&value)), None),
+            Err(error) => (None, Some(JsonSyntaxError::of(input, &error))),
+        };
+        Self {
+            excerpt: input[..end].to_string(),
+            len: input.len(),
+            kind,
+            syntax_error,
+        }
+    }
+
+    /// Returns `true` if the excerpt is shorter than the input.
+    pub fn is_truncated(&self) -> bool {
+        self.excerpt.len() < self.len
+    }
+}
+
+/// Shows the excerpt, followed by `…` if the input was longer.
+impl std::fmt::Display for JsonInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.excerpt)?;
+        if self.is_truncated() {
+            f.write_str("…")?;
+        }
+        Ok(())
+    }
+}
+
+/// JSON validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{JSON_EXCERPT_LEN, JsonKind, JsonValidation};
+///
+/// #[derive(Koruma)]
+/// struct Plugin {
+///     #[koruma(JsonValidation::<_>(require = JsonKind::Object))]
+///     config: String,
+/// }
+///
+/// let plugin = Plugin { config: r#"{"enabled": true}"#.to_string() };
+/// assert!(plugin.validate().is_ok());
+///
+/// let plugin = Plugin { config: "{\n  \"enabled\": ,\n}".to_string() };
+/// let err = plugin.validate().unwrap_err();
+/// let json_err = err.config().json_validation().unwrap();
+/// assert_eq!(json_err.error_position(), Some((2, 14)));
+/// let syntax = json_err.syntax_error().unwrap();
+/// assert_eq!(syntax.offset, 15);
+/// assert_eq!(syntax.message, "expected value");
+/// assert_eq!(
+///     json_err.to_string(),
+///     "invalid JSON at line 2, column 14 (byte 15): expected value"
+/// );
+///
+/// // Valid JSON with the wrong top-level type also fails
+/// let plugin = Plugin { config: "[1, 2]".to_string() };
+/// assert!(plugin.validate().is_err());
+///
+/// // Only the start of a large input is kept
+/// let plugin = Plugin { config: format!("[{}", "1, ".repeat(10_000)) };
+/// let err = plugin.validate().unwrap_err();
+/// let actual = &err.config().json_validation().unwrap().actual;
+/// assert_eq!(actual.len, 30_001);
+/// assert_eq!(actual.excerpt.len(), JSON_EXCERPT_LEN);
+/// ```
+///
+/// Validates that a string parses as JSON, optionally requiring a specific
+/// top-level type. Empty strings are not valid JSON.
+///
+/// The parser's message and the error's byte offset are kept in
+/// [`syntax_error`](Self::syntax_error). Error messages report them rather
+/// than echoing the (possibly very large) input, and `actual` keeps only a
+/// bounded [`JsonInput`] excerpt.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "JSON",
+    description = "Validates that the input is valid JSON",
+    create = |input: &str| {
+        JsonValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct JsonValidation<T: StringLike> {
+    /// The required top-level JSON type
+    #[builder(default)]
+    pub require: JsonKind,
+    /// An excerpt of the string being validated (stored for error context)
+    #[koruma(value)]
+    #[builder(with = |value: T| JsonInput::of(value.as_ref()))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub actual: JsonInput,
+    #[builder(skip)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _value: PhantomData<fn(T)>,
+}
+
+impl<T: StringLike> JsonValidation<T> {
+    /// Returns the `(line, column)` of the parse error, if the input is not
+    /// valid JSON.
+    ///
+    /// Returns `None` when the input parses, even if it has the wrong
+    /// top-level type.
+    pub fn error_position(&self) -> Option<(usize, usize)> {
+        self.syntax_error().map(|error| (error.line, error.column))
+    }
+
+    /// Returns the parse error, or `None` if the input is valid JSON.
+    pub fn syntax_error(&self) -> Option<&JsonSyntaxError> {
+        self.actual.syntax_error.as_ref()
+    }
+}
+
+impl<T: StringLike> Validate<T> for JsonValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        serde_json::from_str::<serde_json::Value>(value.as_ref())
+            .is_ok_and(|json| self.require.accepts(&JsonKind::of(&json)))
+    }
+}
+
+/// Checks the outcome recorded when the [`JsonInput`] was taken.
+impl<T: StringLike> Validate<JsonInput> for JsonValidation<T> {
+    fn validate(&self, value: &JsonInput) -> bool {
+        value
+            .kind
+            .as_ref()
+            .is_some_and(|kind| self.require.accepts(kind))
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for JsonValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.syntax_error() {
+            Some(error) => write!(
+                f,
+                "invalid JSON at line {}, column {} (byte {}): {}",
+                error.line, error.column, error.offset, error.message
+            ),
+            None => write!(f, "JSON must be a top-level {}", self.require),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// The octet separator accepted by [`MacAddressValidation`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub enum MacSeparator {
+    /// Either `:` or `-`, used consistently
+    Any,
+    /// `:` only, e.g. `00:1a:2b:3c:4d:5e`
+    Colon,
+    /// `-` only, e.g. `00-1A-2B-3C-4D-5E`
+    Hyphen,
+}
+
+impl MacSeparator {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            MacSeparator::Any => matches!(c, ':' | '-'),
+            MacSeparator::Colon => c == ':',
+            MacSeparator::Hyphen => c == '-',
+        }
+    }
+}
+
+impl std::fmt::Display for MacSeparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MacSeparator::Any => write!(f, "':' or '-'"),
+            MacSeparator::Colon => write!(f, "':'"),
+            MacSeparator::Hyphen => write!(f, "'-'"),
+        }
+    }
+}
+
+/// Why a string failed [`MacAddressValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum MacAddressFailure {
+    /// The string isn't 17 characters long (six two-digit octets and five
+    /// separators); `len` is its length in characters.
+    WrongLength { len: usize },
+    /// An octet contains a non-hex character; `index` is its byte index.
+    InvalidHexDigit { index: usize, found: char },
+    /// A separator isn't allowed, or differs from the first one; `index` is
+    /// its byte index.
+    InvalidSeparator { index: usize, found: char },
+}
+
+impl MacAddressFailure {
+    /// Returns the Fluent selector key for this failure
+    /// (`wrong_length`, `invalid_hex_digit` or `invalid_separator`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::WrongLength { .. } => "wrong_length",
+            Self::InvalidHexDigit { .. } => "invalid_hex_digit",
+            Self::InvalidSeparator { .. } => "invalid_separator",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it is a MAC address using
+/// `separator`.
+fn mac_address_failure(actual: &str, separator: MacSeparator) -> Option<MacAddressFailure> {
+    let len = actual.chars().count();
+    if len != 17 {
+        return Some(MacAddressFailure::WrongLength { len });
+    }
+
+    let mut first_separator = None;
+    for (position, (index, c)) in actual.char_indices().enumerate() {
+        if position % 3 != 2 {
+            if !c.is_ascii_hexdigit() {
+                return Some(MacAddressFailure::InvalidHexDigit { index, found: c });
+            }
+            continue;
+        }
+        let consistent = first_separator.is_none_or(|first| first == c);
+        if !separator.accepts(c) || !consistent {
+            return Some(MacAddressFailure::InvalidSeparator { index, found: c });
+        }
+        first_separator = Some(c);
+    }
+    None
+}
+
+/// MAC address validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{MacAddressFailure, MacAddressValidation, MacSeparator};
+///
+/// #[derive(Koruma)]
+/// struct Device {
+///     #[koruma(MacAddressValidation::<_>)]
+///     mac: String,
+///     #[koruma(MacAddressValidation::<_>(separator = MacSeparator::Colon))]
+///     gateway_mac: String,
+/// }
+///
+/// let device = Device {
+///     mac: "00-1A-2B-3C-4D-5E".to_string(),
+///     gateway_mac: "00:1a:2b:3c:4d:5e".to_string(),
+/// };
+/// assert!(device.validate().is_ok());
+///
+/// let device = Device {
+///     mac: "00:1a:2b-3c:4d:5e".to_string(),
+///     gateway_mac: "00-1a-2b-3c-4d-5e".to_string(),
+/// };
+/// let err = device.validate().unwrap_err();
+/// let mac_err = err.mac().mac_address_validation().unwrap();
+/// assert_eq!(
+///     mac_err.failure,
+///     Some(MacAddressFailure::InvalidSeparator { index: 8, found: '-' })
+/// );
+/// let gateway_err = err.gateway_mac().mac_address_validation().unwrap();
+/// assert_eq!(gateway_err.to_string(), "invalid MAC address separator '-' at byte 2, expected ':'");
+/// ```
+///
+/// Validates that a string is a six-octet MAC address written as two hex
+/// digits per octet, separated by `:` or `-`.
+///
+/// `separator` restricts which separator is accepted; with the default,
+/// [`MacSeparator::Any`], either works as long as all five match. Why
+/// validation failed is kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "MAC Address",
+    description = "Validates that the input is a colon- or hyphen-separated MAC address",
+    create = |input: &str| {
+        MacAddressValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct MacAddressValidation<T: StringLike> {
+    /// The accepted octet separator (default [`MacSeparator::Any`])
+    #[builder(default = MacSeparator::Any)]
+    pub separator: MacSeparator,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = mac_address_failure(actual.as_ref(), separator))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<MacAddressFailure>| {
+        x.map_or("none", MacAddressFailure::key).to_string()
+    })))]
+    pub failure: Option<MacAddressFailure>,
+}
+
+impl<T: StringLike> Validate<T> for MacAddressValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        mac_address_failure(value.as_ref(), self.separator).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for MacAddressValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.failure {
+            Some(MacAddressFailure::InvalidHexDigit { index, found }) => write!(
+                f,
+                "invalid hex digit '{}' in MAC address at byte {}",
+                found, index
+            ),
+            Some(MacAddressFailure::InvalidSeparator { index, found }) => write!(
+                f,
+                "invalid MAC address separator '{}' at byte {}, expected {}",
+                found, index, self.separator
+            ),
+            _ => write!(f, "not a valid MAC address"),
+        }
+    }
+}
@@ -1,24 +1,47 @@
 //! Format validation validators.
 //!
 //! This module contains validators for specific format validation scenarios
-//! such as emails, URLs, phone numbers, credit cards, and IP addresses.
+//! such as emails, URLs, phone numbers, credit cards, IP and MAC addresses,
+//! CIDR blocks, ports, JSON, UUIDs, base64 and semantic versions.
 
+#[cfg(feature = "base64")]
+mod base64;
+mod cidr;
 #[cfg(feature = "credit-card")]
 mod credit_card;
 #[cfg(feature = "email")]
 mod email;
 mod ip;
+#[cfg(feature = "serde_json")]
+mod json;
+mod mac_address;
 #[cfg(feature = "phone-number")]
 mod phone_number;
+mod port;
+#[cfg(feature = "semver")]
+mod semver;
 #[cfg(feature = "url")]
 mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
 
+#[cfg(feature = "base64")]
+pub use base64::{Base64Failure, Base64Validation};
+pub use cidr::{CidrFailure, CidrValidation};
 #[cfg(feature = "credit-card")]
 pub use credit_card::CreditCardValidation;
 #[cfg(feature = "email")]
 pub use email::EmailValidation;
-pub use ip::{IpKind, IpValidation};
+pub use ip::{IpKind, IpRejection, IpValidation};
+#[cfg(feature = "serde_json")]
+pub use json::{JSON_EXCERPT_LEN, JsonInput, JsonKind, JsonSyntaxError, JsonValidation};
+pub use mac_address::{MacAddressFailure, MacAddressValidation, MacSeparator};
 #[cfg(feature = "phone-number")]
 pub use phone_number::PhoneNumberValidation;
+pub use port::{PortClass, PortFailure, PortValidation};
+#[cfg(feature = "semver")]
+pub use semver::{SemVerFailure, SemVerValidation};
 #[cfg(feature = "url")]
 pub use url::UrlValidation;
+#[cfg(feature = "uuid")]
+pub use uuid::{UuidFailure, UuidValidation};
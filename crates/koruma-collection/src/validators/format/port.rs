@@ -0,0 +1,164 @@
+use koruma::{Validate, validator};
+
+/// The IANA range a port number falls in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PortClass {
+    /// System or well-known ports, `0..=1023`.
+    WellKnown,
+    /// Registered ports, `1024..=49151`.
+    Registered,
+    /// Dynamic or private ports, `49152..=65535`.
+    Dynamic,
+}
+
+impl PortClass {
+    /// Returns the range `port` falls in.
+    pub fn of(port: u16) -> Self {
+        match port {
+            0..=1023 => Self::WellKnown,
+            1024..=49151 => Self::Registered,
+            _ => Self::Dynamic,
+        }
+    }
+}
+
+/// Why a port failed [`PortValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PortFailure {
+    /// The port is outside `min..=max` (or isn't a port number at all).
+    OutOfRange,
+    /// The port is `0` or well-known while `allow_reserved` is `false`.
+    Reserved,
+}
+
+impl PortFailure {
+    /// Returns the Fluent selector key for this failure
+    /// (`out_of_range` or `reserved`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::OutOfRange => "out_of_range",
+            Self::Reserved => "reserved",
+        }
+    }
+}
+
+/// Returns why `port` fails, or `None` if it is allowed.
+fn port_failure(port: u32, min: u16, max: u16, allow_reserved: bool) -> Option<PortFailure> {
+    if !(u32::from(min)..=u32::from(max)).contains(&port) {
+        Some(PortFailure::OutOfRange)
+    } else if !allow_reserved && port < 1024 {
+        Some(PortFailure::Reserved)
+    } else {
+        None
+    }
+}
+
+/// Port number validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{PortClass, PortFailure, PortValidation};
+///
+/// #[derive(Koruma)]
+/// struct ServerConfig {
+///     #[koruma(PortValidation::<_>(allow_reserved = false))]
+///     port: u16,
+///     #[koruma(PortValidation::<_>(min = 49152))]
+///     callback_port: u32,
+/// }
+///
+/// let config = ServerConfig { port: 8080, callback_port: 50000 };
+/// assert!(config.validate().is_ok());
+///
+/// let config = ServerConfig { port: 443, callback_port: 70000 };
+/// let err = config.validate().unwrap_err();
+/// let port_err = err.port().port_validation().unwrap();
+/// assert_eq!(port_err.failure, Some(PortFailure::Reserved));
+/// assert_eq!(port_err.class, Some(PortClass::WellKnown));
+/// assert_eq!(port_err.to_string(), "port 443 is reserved (below 1024)");
+///
+/// let callback_err = err.callback_port().port_validation().unwrap();
+/// assert_eq!(callback_err.failure, Some(PortFailure::OutOfRange));
+/// assert_eq!(callback_err.class, None);
+/// ```
+///
+/// Validates that a port number is within `min..=max` (`1..=65535` by
+/// default). Works on `u16` and, for ports parsed into wider integers, on
+/// `u32` (anything above `65535` is out of range).
+///
+/// Set `allow_reserved = false` to also refuse `0` and the well-known ports
+/// below 1024. Why validation failed is kept in `failure`, and the
+/// [`PortClass`] of the port in `class`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Port",
+    description = "Validates that the input is a non-reserved port number (1024-65535)",
+    create = |input: &str| {
+        PortValidation::builder()
+            .allow_reserved(false)
+            .with_value(input.parse::<u32>().unwrap_or(u32::MAX))
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct PortValidation<T: Copy + Into<u32>> {
+    /// Smallest allowed port (default `1`)
+    #[builder(default = 1)]
+    pub min: u16,
+    /// Largest allowed port (default `65535`)
+    #[builder(default = u16::MAX)]
+    pub max: u16,
+    /// Whether `0` and ports below 1024 are allowed (default `true`)
+    #[builder(default = true)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub allow_reserved: bool,
+    /// The port being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| Into::<u32>::into(*x).to_string())))]
+    pub actual: T,
+    /// The range `actual` falls in, or `None` if it isn't a port number
+    #[builder(skip = u16::try_from(actual.into()).ok().map(PortClass::of))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub class: Option<PortClass>,
+    /// Why `actual` fails, or `None` if it is allowed
+    #[builder(skip = port_failure(actual.into(), min, max, allow_reserved))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<PortFailure>| {
+        x.map_or("none", PortFailure::key).to_string()
+    })))]
+    pub failure: Option<PortFailure>,
+}
+
+impl<T: Copy + Into<u32>> Validate<T> for PortValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        port_failure((*value).into(), self.min, self.max, self.allow_reserved).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: Copy + Into<u32>> std::fmt::Display for PortValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let port: u32 = self.actual.into();
+        match self.failure {
+            Some(PortFailure::Reserved) => write!(f, "port {} is reserved (below 1024)", port),
+            _ => write!(
+                f,
+                "port {} must be between {} and {}",
+                port, self.min, self.max
+            ),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// Why a string failed [`SemVerValidation`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SemVerFailure {
+    /// The string isn't a semantic version; `reason` is the parser's message.
+    Invalid { reason: String },
+    /// The configured `requirement` doesn't parse; `reason` is the parser's
+    /// message.
+    InvalidRequirement { reason: String },
+    /// The version parses but doesn't satisfy the `requirement`.
+    Unsatisfied,
+}
+
+impl SemVerFailure {
+    /// Returns the Fluent selector key for this failure
+    /// (`invalid`, `invalid_requirement` or `unsatisfied`).
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Invalid { .. } => "invalid",
+            Self::InvalidRequirement { .. } => "invalid_requirement",
+            Self::Unsatisfied => "unsatisfied",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it is a version matching
+/// `requirement`.
+fn semver_failure(actual: &str, requirement: Option<&str>) -> Option<SemVerFailure> {
+    let version = match semver::Version::parse(actual) {
+        Ok(version) => version,
+        Err(error) => {
+            return Some(SemVerFailure::Invalid {
+                reason: error.to_string(),
+            });
+        },
+    };
+    let requirement = match requirement.map(semver::VersionReq::parse)? {
+        Ok(requirement) => requirement,
+        Err(error) => {
+            return Some(SemVerFailure::InvalidRequirement {
+                reason: error.to_string(),
+            });
+        },
+    };
+    (!requirement.matches(&version)).then_some(SemVerFailure::Unsatisfied)
+}
+
+/// Semantic version validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{SemVerFailure, SemVerValidation};
+///
+/// #[derive(Koruma)]
+/// struct Plugin {
+///     #[koruma(SemVerValidation::<_>)]
+///     version: String,
+///     #[koruma(SemVerValidation::<_>(requirement = ">=1.2, <2"))]
+///     host_version: String,
+/// }
+///
+/// let plugin = Plugin {
+///     version: "0.3.0-beta.1".to_string(),
+///     host_version: "1.4.2".to_string(),
+/// };
+/// assert!(plugin.validate().is_ok());
+///
+/// let plugin = Plugin {
+///     version: "v1.0".to_string(),
+///     host_version: "2.0.0".to_string(),
+/// };
+/// let err = plugin.validate().unwrap_err();
+/// let version_err = err.version().sem_ver_validation().unwrap();
+/// assert!(matches!(version_err.failure, Some(SemVerFailure::Invalid { .. })));
+///
+/// let host_err = err.host_version().sem_ver_validation().unwrap();
+/// assert_eq!(host_err.failure, Some(SemVerFailure::Unsatisfied));
+/// assert_eq!(host_err.to_string(), "version 2.0.0 does not satisfy >=1.2, <2");
+/// ```
+///
+/// Validates that a string is a [semantic version](https://semver.org)
+/// such as `1.2.3` or `1.0.0-rc.1+build.5`, parsed with the `semver` crate.
+///
+/// Set `requirement` to a Cargo-style range such as `>=1.2, <2` or `^1.4` to
+/// also require the version to match it; a requirement that doesn't parse
+/// fails every value. Why validation failed is kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Semantic Version",
+    description = "Validates that the input is a semantic version like 1.2.3",
+    create = |input: &str| {
+        SemVerValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct SemVerValidation<T: StringLike> {
+    /// Version requirement the value must match, if any
+    #[builder(into)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<String>| {
+        x.clone().unwrap_or_else(|| "none".to_string())
+    })))]
+    pub requirement: Option<String>,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = semver_failure(actual.as_ref(), requirement.as_deref()))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<SemVerFailure>| {
+        x.as_ref().map_or("none", SemVerFailure::key).to_string()
+    })))]
+    pub failure: Option<SemVerFailure>,
+}
+
+impl<T: StringLike> Validate<T> for SemVerValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        semver_failure(value.as_ref(), self.requirement.as_deref()).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for SemVerValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.failure, &self.requirement) {
+            (Some(SemVerFailure::Unsatisfied), Some(requirement)) => write!(
+                f,
+                "version {} does not satisfy {}",
+                self.actual.as_ref(),
+                requirement
+            ),
+            (Some(SemVerFailure::InvalidRequirement { reason }), _) => {
+                write!(f, "invalid version requirement: {}", reason)
+            },
+            (Some(SemVerFailure::Invalid { reason }), _) => {
+                write!(f, "invalid semantic version: {}", reason)
+            },
+            _ => write!(f, "not a valid semantic version"),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// Why a string failed [`UuidValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UuidFailure {
+    /// The string doesn't parse as a UUID.
+    Invalid,
+    /// The UUID parses but has a different version than required.
+    WrongVersion,
+}
+
+impl UuidFailure {
+    /// Returns the Fluent selector key for this failure
+    /// (`invalid` or `wrong_version`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Invalid => "invalid",
+            Self::WrongVersion => "wrong_version",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it is a UUID of the required version.
+fn uuid_failure(actual: &str, version: Option<u8>) -> Option<UuidFailure> {
+    match uuid::Uuid::parse_str(actual) {
+        Err(_) => Some(UuidFailure::Invalid),
+        Ok(uuid) if version.is_some_and(|v| usize::from(v) != uuid.get_version_num()) => {
+            Some(UuidFailure::WrongVersion)
+        },
+        Ok(_) => None,
+    }
+}
+
+/// UUID validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::format::{UuidFailure, UuidValidation};
+///
+/// #[derive(Koruma)]
+/// struct Order {
+///     #[koruma(UuidValidation::<_>(version = 4))]
+///     id: String,
+/// }
+///
+/// let order = Order { id: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string() };
+/// assert!(order.validate().is_ok());
+///
+/// let order = Order { id: "not-a-uuid".to_string() };
+/// let err = order.validate().unwrap_err();
+/// let uuid_err = err.id().uuid_validation().unwrap();
+/// assert_eq!(uuid_err.failure, Some(UuidFailure::Invalid));
+///
+/// // A version 1 UUID parses, but isn't version 4
+/// let order = Order { id: "a8098c1a-f86e-11da-bd1a-00112444be1e".to_string() };
+/// let err = order.validate().unwrap_err();
+/// let uuid_err = err.id().uuid_validation().unwrap();
+/// assert_eq!(uuid_err.failure, Some(UuidFailure::WrongVersion));
+/// assert_eq!(uuid_err.to_string(), "UUID must be version 4");
+/// ```
+///
+/// Validates that a string parses as a UUID (hyphenated, simple, braced or
+/// URN form), optionally of a specific `version`. Why validation failed is
+/// kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "UUID",
+    description = "Validates that the input is a valid UUID",
+    create = |input: &str| {
+        UuidValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct UuidValidation<T: StringLike> {
+    /// The required UUID version, if any
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<u8>| {
+        x.map_or_else(|| "none".to_string(), |v| v.to_string())
+    })))]
+    pub version: Option<u8>,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = uuid_failure(actual.as_ref(), version))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<UuidFailure>| {
+        x.map_or("none", UuidFailure::key).to_string()
+    })))]
+    pub failure: Option<UuidFailure>,
+}
+
+impl<T: StringLike> Validate<T> for UuidValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        uuid_failure(value.as_ref(), self.version).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for UuidValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.failure, self.version) {
+            (Some(UuidFailure::WrongVersion), Some(version)) => {
+                write!(f, "UUID must be version {}", version)
+            },
+            _ => write!(f, "not a valid UUID"),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use koruma::{Validate, validator};
+
+/// Equals validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::general::EqualsValidation;
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(EqualsValidation::<_>(expected = true))]
+///     accept_terms: bool,
+///     #[koruma(EqualsValidation::<_>(expected = "DELETE"))]
+///     confirmation: String,
+/// }
+///
+/// let signup = Signup { accept_terms: false, confirmation: "delete".to_string() };
+/// let err = signup.validate().unwrap_err();
+/// let terms = err.accept_terms().equals_validation().unwrap();
+/// assert_eq!(terms.to_string(), "expected true, got false");
+/// let confirmation = err.confirmation().equals_validation().unwrap();
+/// assert_eq!(confirmation.to_string(), r#"expected "DELETE", got "delete""#);
+/// ```
+///
+/// Validates that a value equals a fixed expected value, e.g. a confirmation
+/// checkbox that must be ticked.
+///
+/// `expected` accepts anything convertible into the field type, so string
+/// literals work for `String` fields. To compare against another field, use
+/// [`MatchesValidation`](crate::string::MatchesValidation).
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Equals",
+    description = "Validates that the input is exactly 'I agree'",
+    create = |input: &str| {
+        EqualsValidation::builder()
+            .expected("I agree")
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct EqualsValidation<T: PartialEq + Clone + std::fmt::Debug> {
+    /// The value the field must equal
+    #[builder(into)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub expected: T,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub actual: T,
+}
+
+impl<T: PartialEq + Clone + std::fmt::Debug> Validate<T> for EqualsValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value == &self.expected
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: PartialEq + Clone + std::fmt::Debug> std::fmt::Display for EqualsValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {:?}, got {:?}", self.expected, self.actual)
+    }
+}
@@ -3,6 +3,14 @@
 //! This module contains validators for general validation scenarios
 //! that don't fit into other categories.
 
+mod equals;
+mod not_equals;
+mod not_one_of;
+mod one_of;
 mod required;
 
-pub use required::RequiredValidation;
+pub use equals::EqualsValidation;
+pub use not_equals::NotEqualsValidation;
+pub use not_one_of::NotOneOfValidation;
+pub use one_of::OneOfValidation;
+pub use required::{Emptiness, MaybeEmpty, RequiredValidation};
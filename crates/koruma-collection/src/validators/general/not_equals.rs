@@ -0,0 +1,72 @@
+use koruma::{Validate, validator};
+
+/// Not-equals validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::general::NotEqualsValidation;
+///
+/// #[derive(Koruma)]
+/// struct Settings {
+///     #[koruma(NotEqualsValidation::<_>(forbidden = "changeme"))]
+///     admin_password: String,
+///     #[koruma(NotEqualsValidation::<_>(forbidden = 0u16))]
+///     port: u16,
+/// }
+///
+/// let settings = Settings { admin_password: "changeme".to_string(), port: 8080 };
+/// let err = settings.validate().unwrap_err();
+/// let failure = err.admin_password().not_equals_validation().unwrap();
+/// assert_eq!(failure.to_string(), r#"expected a value other than "changeme", got "changeme""#);
+/// assert!(err.port().not_equals_validation().is_none());
+/// ```
+///
+/// Validates that a value differs from a fixed forbidden value, e.g. a
+/// placeholder that must be replaced.
+///
+/// `forbidden` accepts anything convertible into the field type, so string
+/// literals work for `String` fields. To forbid several values, use
+/// [`NotOneOfValidation`](super::NotOneOfValidation).
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Not Equals",
+    description = "Validates that the input is not 'changeme'",
+    create = |input: &str| {
+        NotEqualsValidation::builder()
+            .forbidden("changeme")
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct NotEqualsValidation<T: PartialEq + Clone + std::fmt::Debug> {
+    /// The value the field must differ from
+    #[builder(into)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub forbidden: T,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub actual: T,
+}
+
+impl<T: PartialEq + Clone + std::fmt::Debug> Validate<T> for NotEqualsValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value != &self.forbidden
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: PartialEq + Clone + std::fmt::Debug> std::fmt::Display for NotEqualsValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected a value other than {:?}, got {:?}",
+            self.forbidden, self.actual
+        )
+    }
+}
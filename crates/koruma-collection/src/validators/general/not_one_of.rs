@@ -0,0 +1,66 @@
+use koruma::{Validate, validator};
+
+/// Not-one-of validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::general::NotOneOfValidation;
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(NotOneOfValidation::<_>(forbidden = ["admin", "root"]))]
+///     username: String,
+/// }
+///
+/// let signup = Signup { username: "root".to_string() };
+/// let err = signup.validate().unwrap_err();
+/// let failure = err.username().not_one_of_validation().unwrap();
+/// assert_eq!(failure.to_string(), r#""root" is not allowed"#);
+/// ```
+///
+/// Validates that a value is not one of a set of forbidden values.
+///
+/// `forbidden` accepts any iterable of items convertible into the field type,
+/// so string literals work for `String` fields. The rejected value is kept in
+/// `actual`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Not One Of",
+    description = "Validates that the input is not 'admin' or 'root'",
+    create = |input: &str| {
+        NotOneOfValidation::builder()
+            .forbidden(["admin", "root"])
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct NotOneOfValidation<T: PartialEq + Clone + std::fmt::Debug> {
+    /// The forbidden values
+    #[builder(with = |forbidden: impl IntoIterator<Item = impl Into<T>>| {
+        forbidden.into_iter().map(Into::into).collect()
+    })]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub forbidden: Vec<T>,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub actual: T,
+}
+
+impl<T: PartialEq + Clone + std::fmt::Debug> Validate<T> for NotOneOfValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        !self.forbidden.contains(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: PartialEq + Clone + std::fmt::Debug> std::fmt::Display for NotOneOfValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not allowed", self.actual)
+    }
+}
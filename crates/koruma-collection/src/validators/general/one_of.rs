@@ -0,0 +1,72 @@
+use koruma::{Validate, validator};
+
+/// One-of validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::general::OneOfValidation;
+///
+/// #[derive(Koruma)]
+/// struct Account {
+///     #[koruma(OneOfValidation::<_>(allowed = ["active", "inactive"]))]
+///     status: String,
+/// }
+///
+/// let account = Account { status: "deleted".to_string() };
+/// let err = account.validate().unwrap_err();
+/// let failure = err.status().one_of_validation().unwrap();
+/// assert_eq!(failure.to_string(), r#"expected one of ["active", "inactive"], got "deleted""#);
+/// ```
+///
+/// Validates that a value is one of a fixed set of allowed values.
+///
+/// `allowed` accepts any iterable of items convertible into the field type,
+/// so string literals work for `String` fields. Other literals should match
+/// the field type (e.g. `[1u8, 2]` for a `u8` field).
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "One Of",
+    description = "Validates that the input is one of 'red', 'green' or 'blue'",
+    create = |input: &str| {
+        OneOfValidation::builder()
+            .allowed(["red", "green", "blue"])
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct OneOfValidation<T: PartialEq + Clone + std::fmt::Debug> {
+    /// The allowed values
+    #[builder(with = |allowed: impl IntoIterator<Item = impl Into<T>>| {
+        allowed.into_iter().map(Into::into).collect()
+    })]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Vec<T>| {
+        x.iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>().join(", ")
+    })))]
+    pub allowed: Vec<T>,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| format!("{:?}", x))))]
+    pub actual: T,
+}
+
+impl<T: PartialEq + Clone + std::fmt::Debug> Validate<T> for OneOfValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        self.allowed.contains(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: PartialEq + Clone + std::fmt::Debug> std::fmt::Display for OneOfValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected one of {:?}, got {:?}",
+            self.allowed, self.actual
+        )
+    }
+}
@@ -1,5 +1,97 @@
 use koruma::{Validate, validator};
 
+/// Why a value counts as empty for [`RequiredValidation`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Emptiness {
+    /// An `Option` is `None`.
+    Missing,
+    /// A string or collection has no elements.
+    Empty,
+    /// A string contains only whitespace.
+    Blank,
+}
+
+impl Emptiness {
+    /// Returns the Fluent selector key for this reason
+    /// (`missing`, `empty` or `blank`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Empty => "empty",
+            Self::Blank => "blank",
+        }
+    }
+}
+
+/// Trait for values that [`RequiredValidation`] can find empty.
+///
+/// - `Option<T>` is empty when it is `None`. A `Some` is present whatever it
+///   holds, so `Some(String::new())` passes.
+/// - Strings are empty when they have no characters, and blank when they
+///   contain only whitespace.
+/// - Collections are empty when they have no elements.
+pub trait MaybeEmpty {
+    /// Returns why the value is empty, or `None` if it has content.
+    fn emptiness(&self) -> Option<Emptiness>;
+}
+
+impl<T> MaybeEmpty for Option<T> {
+    fn emptiness(&self) -> Option<Emptiness> {
+        self.is_none().then_some(Emptiness::Missing)
+    }
+}
+
+impl MaybeEmpty for str {
+    fn emptiness(&self) -> Option<Emptiness> {
+        if self.is_empty() {
+            Some(Emptiness::Empty)
+        } else if self.trim().is_empty() {
+            Some(Emptiness::Blank)
+        } else {
+            None
+        }
+    }
+}
+
+impl MaybeEmpty for String {
+    fn emptiness(&self) -> Option<Emptiness> {
+        self.as_str().emptiness()
+    }
+}
+
+impl<T: MaybeEmpty + ?Sized> MaybeEmpty for &T {
+    fn emptiness(&self) -> Option<Emptiness> {
+        (**self).emptiness()
+    }
+}
+
+macro_rules! impl_maybe_empty_for_collections {
+    ($(impl<$($param:ident),*> for $ty:ty;)*) => {
+        $(
+            impl<$($param),*> MaybeEmpty for $ty {
+                fn emptiness(&self) -> Option<Emptiness> {
+                    self.is_empty().then_some(Emptiness::Empty)
+                }
+            }
+        )*
+    };
+}
+
+impl_maybe_empty_for_collections! {
+    impl<T> for [T];
+    impl<T> for Vec<T>;
+    impl<T> for std::collections::VecDeque<T>;
+    impl<K, V> for std::collections::HashMap<K, V>;
+    impl<K, V> for std::collections::BTreeMap<K, V>;
+    impl<T> for std::collections::HashSet<T>;
+    impl<T> for std::collections::BTreeSet<T>;
+}
+
 /// Required validation for koruma.
 ///
 ///
@@ -16,26 +108,95 @@ use koruma::{Validate, validator};
 /// }
 /// ```
 ///
-/// Validates that a value is present (not None for Option types).
-#[validator]
+/// The `required` field flag adds it for you, ahead of validators that check
+/// the inner value:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::collection::LenValidation;
+/// use koruma_collection::general::RequiredValidation;
+///
+/// #[derive(Koruma)]
+/// struct Profile {
+///     #[koruma(required, LenValidation::<_>(min = 1, max = 50))]
+///     name: Option<String>,
+/// }
+///
+/// let err = Profile { name: None }.validate().unwrap_err();
+/// assert!(err.name().required_validation().is_some());
+/// assert!(err.name().len_validation().is_none());
+///
+/// let err = Profile { name: Some(String::new()) }.validate().unwrap_err();
+/// assert!(err.name().required_validation().is_none());
+/// assert!(err.name().len_validation().is_some());
+/// ```
+///
+/// On strings and collections it checks the value itself, and the failure
+/// says why the value counts as empty:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::general::{Emptiness, RequiredValidation};
+///
+/// #[derive(Koruma)]
+/// struct Comment {
+///     #[koruma(RequiredValidation::<_>)]
+///     body: String,
+///     #[koruma(RequiredValidation::<_>)]
+///     tags: Vec<String>,
+/// }
+///
+/// let err = Comment { body: "  ".to_string(), tags: Vec::new() }
+///     .validate()
+///     .unwrap_err();
+/// let body = err.body().required_validation().unwrap();
+/// assert_eq!(body.reason, Some(Emptiness::Blank));
+/// assert_eq!(body.to_string(), "value is required but contains only whitespace");
+/// assert_eq!(
+///     err.tags().required_validation().unwrap().reason,
+///     Some(Emptiness::Empty)
+/// );
+/// ```
+///
+/// Validates that a value is present and not empty, as defined by
+/// [`MaybeEmpty`].
+///
+/// Every validator on a field runs, so how it combines with others depends on
+/// the field's type. On an `Option` field, validators of the inner type skip
+/// `None`, which then fails only `RequiredValidation`. On a string or
+/// collection field, an empty value may fail other validators too, e.g. a
+/// minimum length; give `RequiredValidation` a `priority` to list its failure
+/// first in a summary.
+#[validator(deferred_value)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
-pub struct RequiredValidation<T> {
+pub struct RequiredValidation<T: MaybeEmpty> {
     /// The value being validated (stored for error context)
     #[koruma(value)]
     #[cfg_attr(feature = "fluent", fluent(skip))]
     pub actual: Option<T>,
+    /// Why `actual` is empty, or `None` if it has content
+    #[builder(skip = actual.as_ref().and_then(MaybeEmpty::emptiness))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<Emptiness>| {
+        x.map_or("none", Emptiness::key).to_string()
+    })))]
+    pub reason: Option<Emptiness>,
 }
 
-impl<T> Validate<Option<T>> for RequiredValidation<Option<T>> {
-    fn validate(&self, value: &Option<T>) -> bool {
-        value.is_some()
+impl<T: MaybeEmpty> Validate<T> for RequiredValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value.emptiness().is_none()
     }
 }
 
 #[cfg(feature = "fmt")]
-impl<T> std::fmt::Display for RequiredValidation<Option<T>> {
+impl<T: MaybeEmpty> std::fmt::Display for RequiredValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "value is required but not present")
+        match self.reason {
+            Some(Emptiness::Empty) => write!(f, "value is required but empty"),
+            Some(Emptiness::Blank) => write!(f, "value is required but contains only whitespace"),
+            _ => write!(f, "value is required but not present"),
+        }
     }
 }
@@ -8,9 +8,14 @@
 //! - [`numeric`]: Numeric validators (positive, negative, range, etc.)
 //! - [`collection`]: Collection validators (length, non-empty)
 //! - [`general`]: General-purpose validators (required)
+//! - [`security`]: Security validators (password strength)
+//! - [`time`]: Date and time validators (date ranges, past/future, age), behind the `chrono` feature
 
 pub mod collection;
 pub mod format;
 pub mod general;
 pub mod numeric;
+pub mod security;
 pub mod string;
+#[cfg(feature = "chrono")]
+pub mod time;
@@ -0,0 +1,140 @@
+use koruma::{Validate, validator};
+
+use super::Numeric;
+
+/// Floating-point types that [`FiniteValidation`] can check.
+///
+/// Implemented for `f32` and `f64`.
+pub trait FloatingPoint: Numeric {
+    /// Returns why the value isn't finite, or `None` if it is.
+    fn non_finite(self) -> Option<NonFinite>;
+}
+
+macro_rules! impl_floating_point {
+    ($($ty:ty),*) => {$(
+        impl FloatingPoint for $ty {
+            fn non_finite(self) -> Option<NonFinite> {
+                if self.is_nan() {
+                    Some(NonFinite::NaN)
+                } else if self.is_infinite() {
+                    Some(NonFinite::Infinite)
+                } else {
+                    None
+                }
+            }
+        }
+    )*};
+}
+
+impl_floating_point!(f32, f64);
+
+/// Why a value failed [`FiniteValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NonFinite {
+    /// The value is `NaN`.
+    #[cfg_attr(feature = "serde", serde(rename = "nan"))]
+    NaN,
+    /// The value is positive or negative infinity.
+    Infinite,
+}
+
+impl NonFinite {
+    /// Returns the Fluent selector key for this condition (`nan` or `infinite`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::NaN => "nan",
+            Self::Infinite => "infinite",
+        }
+    }
+}
+
+impl std::fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NaN => write!(f, "not a number"),
+            Self::Infinite => write!(f, "infinite"),
+        }
+    }
+}
+
+/// Finite float validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::numeric::{FiniteValidation, NonFinite};
+///
+/// #[derive(Koruma)]
+/// struct Reading {
+///     #[koruma(FiniteValidation::<_>)]
+///     celsius: f64,
+///     #[koruma(FiniteValidation::<_>)]
+///     humidity: f32,
+/// }
+///
+/// let reading = Reading { celsius: f64::NAN, humidity: f32::INFINITY };
+/// let err = reading.validate().unwrap_err();
+/// assert_eq!(
+///     err.celsius().finite_validation().unwrap().kind,
+///     Some(NonFinite::NaN)
+/// );
+/// assert_eq!(
+///     err.humidity().finite_validation().unwrap().kind,
+///     Some(NonFinite::Infinite)
+/// );
+/// ```
+///
+/// Validates that a float is neither `NaN` nor infinite.
+///
+/// Parsing user input with `str::parse` happily accepts `"NaN"` and `"inf"`,
+/// so put this before other numeric checks on untrusted floats. Which
+/// condition failed is kept in `kind` for precise error messages.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Finite Number",
+    description = "Validates that the input is neither NaN nor infinite (try 'NaN' or 'inf')",
+    // Text input, since the `Float` input kind rejects non-finite numbers
+    try_create = |input: &str| {
+        let num = input
+            .parse::<f64>()
+            .map_err(|_| "expected a number, 'NaN' or 'inf'".to_string())?;
+        Ok(FiniteValidation::builder().with_value(num).build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct FiniteValidation<T: FloatingPoint> {
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// Why `actual` isn't finite, or `None` if it is
+    #[builder(skip = actual.non_finite())]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<NonFinite>| {
+        x.map_or("finite", NonFinite::key).to_string()
+    })))]
+    pub kind: Option<NonFinite>,
+}
+
+impl<T: FloatingPoint> Validate<T> for FiniteValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value.non_finite().is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: FloatingPoint> std::fmt::Display for FiniteValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            Some(NonFinite::NaN) => write!(f, "value must be a number, not NaN"),
+            _ => write!(f, "value {} must be finite", self.actual),
+        }
+    }
+}
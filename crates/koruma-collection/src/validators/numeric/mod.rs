@@ -13,14 +13,22 @@ pub trait Numeric: PartialOrd + Default + Copy + Display {}
 
 impl<T: PartialOrd + Default + Copy + Display> Numeric for T {}
 
+mod finite;
+mod multiple_of;
 mod negative;
 mod non_negative;
 mod non_positive;
 mod positive;
+mod precision;
 mod range;
+mod step_range;
 
+pub use finite::{FiniteValidation, FloatingPoint, NonFinite};
+pub use multiple_of::{Divisible, MultipleOfValidation};
 pub use negative::NegativeValidation;
 pub use non_negative::NonNegativeValidation;
 pub use non_positive::NonPositiveValidation;
 pub use positive::PositiveValidation;
+pub use precision::{DecimalPlaces, PrecisionValidation};
 pub use range::RangeValidation;
+pub use step_range::{StepRangeFailure, StepRangeValidation};
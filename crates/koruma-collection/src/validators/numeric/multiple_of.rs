@@ -0,0 +1,152 @@
+use koruma::{Validate, validator};
+
+use super::Numeric;
+
+/// Numeric types that [`MultipleOfValidation`] can check.
+///
+/// Implemented for the primitive integer and float types.
+pub trait Divisible: Numeric {
+    /// Returns the remainder of `self / divisor`, or `self` if the divisor is zero.
+    fn remainder(self, divisor: Self) -> Self;
+
+    /// Returns whether `self` is a multiple of `divisor`.
+    ///
+    /// Floats accept a remainder within `epsilon` of zero or of the divisor;
+    /// integers ignore `epsilon`. A zero divisor has no multiples.
+    fn is_multiple_within(self, divisor: Self, epsilon: f64) -> bool;
+
+    /// Returns whether `self - origin` is a multiple of `divisor`, like
+    /// [`is_multiple_within`](Self::is_multiple_within) but without
+    /// overflowing when the distance doesn't fit in `Self`.
+    fn is_multiple_from_within(self, origin: Self, divisor: Self, epsilon: f64) -> bool;
+}
+
+macro_rules! impl_divisible_int {
+    ($($ty:ty),*) => {$(
+        impl Divisible for $ty {
+            fn remainder(self, divisor: Self) -> Self {
+                if divisor == 0 { self } else { self.wrapping_rem(divisor) }
+            }
+
+            fn is_multiple_within(self, divisor: Self, _epsilon: f64) -> bool {
+                divisor != 0 && self.wrapping_rem(divisor) == 0
+            }
+
+            fn is_multiple_from_within(self, origin: Self, divisor: Self, _epsilon: f64) -> bool {
+                // `abs_diff` returns the unsigned type of the same width,
+                // which always holds the distance
+                let divisor = divisor.abs_diff(0);
+                divisor != 0 && self.abs_diff(origin) % divisor == 0
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_divisible_float {
+    ($($ty:ty),*) => {$(
+        impl Divisible for $ty {
+            fn remainder(self, divisor: Self) -> Self {
+                if divisor == 0.0 { self } else { self % divisor }
+            }
+
+            fn is_multiple_within(self, divisor: Self, epsilon: f64) -> bool {
+                if divisor == 0.0 || !self.is_finite() {
+                    return false;
+                }
+                let remainder = (self % divisor).abs();
+                let epsilon = epsilon as $ty;
+                remainder <= epsilon || divisor.abs() - remainder <= epsilon
+            }
+
+            fn is_multiple_from_within(self, origin: Self, divisor: Self, epsilon: f64) -> bool {
+                (self - origin).is_multiple_within(divisor, epsilon)
+            }
+        }
+    )*};
+}
+
+impl_divisible_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_divisible_float!(f32, f64);
+
+/// Multiple-of validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::numeric::MultipleOfValidation;
+///
+/// #[derive(Koruma)]
+/// struct Order {
+///     // Sold in packs of 6
+///     #[koruma(MultipleOfValidation::<_>(divisor = 6))]
+///     quantity: u32,
+///     // Quarter-hour steps, tolerating float rounding
+///     #[koruma(MultipleOfValidation::<_>(divisor = 0.25, epsilon = 1e-9))]
+///     hours: f64,
+/// }
+///
+/// let order = Order { quantity: 14, hours: 1.75 };
+/// let err = order.validate().unwrap_err();
+/// let failure = err.quantity().multiple_of_validation().unwrap();
+/// assert_eq!(failure.remainder, 2);
+/// assert!(err.hours().multiple_of_validation().is_none());
+/// ```
+///
+/// Validates that a numeric value is a whole multiple of `divisor`.
+///
+/// For floats, set `epsilon` to accept remainders that are only off by
+/// rounding error (default `0.0`, i.e. exact). A zero divisor rejects every
+/// value. The remainder is kept in `remainder` for error messages.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Multiple of 6",
+    description = "Validates that the input is a multiple of 6",
+    input = Integer,
+    try_create = |input: &str| {
+        let num = input.parse::<i64>().map_err(|e| e.to_string())?;
+        Ok(MultipleOfValidation::builder()
+            .divisor(6_i64)
+            .with_value(num)
+            .build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct MultipleOfValidation<T: Divisible> {
+    /// The step the value must be a multiple of
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub divisor: T,
+    /// Tolerance for float remainders (default `0.0`, ignored for integers)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub epsilon: f64,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// Remainder of `actual / divisor`
+    #[builder(skip = actual.remainder(divisor))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub remainder: T,
+}
+
+impl<T: Divisible> Validate<T> for MultipleOfValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value.is_multiple_within(self.divisor, self.epsilon)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: Divisible> std::fmt::Display for MultipleOfValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "value {} is not a multiple of {} (remainder {})",
+            self.actual, self.divisor, self.remainder
+        )
+    }
+}
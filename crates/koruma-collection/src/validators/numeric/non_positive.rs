@@ -18,11 +18,11 @@ use super::Numeric;
 /// ```
 ///
 /// Validates that a numeric value is non-positive (<= 0).
-#[validator]
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Non-Positive Number",
     description = "Validates that the input is a non-positive number (<= 0)",
-    input_type = Numeric,
+    input = Float,
     create = |input: &str| {
         let num = input.parse::<f64>().unwrap_or(0.0);
         NonPositiveValidation::builder()
@@ -31,6 +31,7 @@ use super::Numeric;
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct NonPositiveValidation<T: Numeric> {
     /// The value being validated (stored for error context)
@@ -0,0 +1,121 @@
+use koruma::{Validate, validator};
+
+use super::Numeric;
+
+/// Float types that [`PrecisionValidation`] can check.
+///
+/// Implemented for `f32` and `f64`.
+pub trait DecimalPlaces: Numeric {
+    /// Returns the number of decimal places in the shortest representation
+    /// of the value, e.g. `3` for `9.999`. Non-finite values have none.
+    fn decimal_places(self) -> u32;
+
+    /// Returns whether `self * 10^max_decimals` is within `epsilon` of a
+    /// whole number. Scaling happens in the value's own type, so an `f32`
+    /// isn't judged by the noise of widening it to `f64`.
+    fn fits_decimals(self, max_decimals: u32, epsilon: f64) -> bool;
+}
+
+macro_rules! impl_decimal_places {
+    ($($ty:ty),*) => {$(
+        impl DecimalPlaces for $ty {
+            fn decimal_places(self) -> u32 {
+                if !self.is_finite() {
+                    return 0;
+                }
+                // `Display` never uses exponent notation and prints the
+                // shortest digits that round-trip
+                self.to_string()
+                    .split_once('.')
+                    .map_or(0, |(_, fraction)| fraction.len() as u32)
+            }
+
+            fn fits_decimals(self, max_decimals: u32, epsilon: f64) -> bool {
+                let scaled = self * (10.0 as $ty).powi(max_decimals as i32);
+                let fraction = scaled.fract().abs() as f64;
+                fraction <= epsilon || 1.0 - fraction <= epsilon
+            }
+        }
+    )*};
+}
+
+impl_decimal_places!(f32, f64);
+
+/// Decimal precision validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::numeric::PrecisionValidation;
+///
+/// #[derive(Koruma)]
+/// struct Product {
+///     #[koruma(PrecisionValidation::<_>(max_decimals = 2))]
+///     price: f64,
+/// }
+///
+/// assert!(Product { price: 9.99 }.validate().is_ok());
+/// // Float noise from arithmetic is tolerated
+/// assert!(Product { price: 0.1 + 0.2 }.validate().is_ok());
+///
+/// let err = Product { price: 9.999 }.validate().unwrap_err();
+/// let failure = err.price().precision_validation().unwrap();
+/// assert_eq!(failure.decimals, 3);
+/// ```
+///
+/// Validates that a float has at most `max_decimals` decimal places.
+///
+/// The value is scaled by `10^max_decimals` and must then be within
+/// `epsilon` (default `1e-6`) of a whole number, so binary rounding noise
+/// like `0.30000000000000004` doesn't count as extra places. `NaN` and
+/// infinities always fail. The number of decimal places found is kept in
+/// `decimals` for error messages.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Precision (2 decimals)",
+    description = "Validates that the input has at most 2 decimal places",
+    input = Float,
+    create = |input: &str| {
+        let num = input.parse::<f64>().unwrap_or(0.0);
+        PrecisionValidation::builder()
+            .max_decimals(2)
+            .with_value(num)
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct PrecisionValidation<T: DecimalPlaces> {
+    /// Maximum number of decimal places allowed
+    pub max_decimals: u32,
+    /// Tolerance on the scaled value's fractional part (default `1e-6`)
+    #[builder(default = 1e-6)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub epsilon: f64,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// Number of decimal places in `actual`
+    #[builder(skip = actual.decimal_places())]
+    pub decimals: u32,
+}
+
+impl<T: DecimalPlaces> Validate<T> for PrecisionValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        value.fits_decimals(self.max_decimals, self.epsilon)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: DecimalPlaces> std::fmt::Display for PrecisionValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "value {} has {} decimal places, at most {} allowed",
+            self.actual, self.decimals, self.max_decimals
+        )
+    }
+}
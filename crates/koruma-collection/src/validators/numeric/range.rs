@@ -13,34 +13,56 @@ use koruma::{Validate, validator};
 ///     #[koruma(RangeValidation::<_>(min = 0, max = 100))]
 ///     value: u32,
 /// }
+///
+/// // Half-open interval (0, 1]
+/// #[derive(Koruma)]
+/// struct Ratio {
+///     #[koruma(RangeValidation::<_>(min = 0.0, max = 1.0, exclusive_min = true))]
+///     value: f64,
+/// }
+///
+/// let err = Ratio { value: 0.0 }.validate().unwrap_err();
+/// let failure = err.value().range_validation().unwrap();
+/// assert_eq!(failure.to_string(), "value 0 is not within bounds (0, 1]");
+/// assert!(Ratio { value: 1.0 }.validate().is_ok());
+///
+/// // NaN and infinities are never within finite bounds
+/// for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+///     assert!(Ratio { value }.validate().is_err());
+/// }
 /// ```
 ///
 /// Validates that a numeric value is within specified bounds.
-#[validator]
+///
+/// Both bounds are inclusive by default; set `exclusive_min` or
+/// `exclusive_max` to make the corresponding comparison strict. For floats,
+/// `NaN` always fails, whatever the bounds.
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Range [0, 100]",
-    description = "Validates that the input is a number between 0 and 100",
-    input_type = Numeric,
-    create = |input: &str| {
-        let num = input.parse::<f64>().unwrap_or(0.0);
-        RangeValidation::builder()
-            .min(0_f64)
-            .max(100_f64)
+    description = "Validates that the input is an integer between 0 and 100",
+    input = Integer,
+    try_create = |input: &str| {
+        let num = input.parse::<i64>().map_err(|e| e.to_string())?;
+        Ok(RangeValidation::builder()
+            .min(0_i64)
+            .max(100_i64)
             .with_value(num)
-            .build()
+            .build())
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct RangeValidation<T: PartialOrd + Copy + std::fmt::Display + Clone> {
-    /// Minimum allowed value (inclusive)
+    /// Minimum allowed value (inclusive unless `exclusive_min`)
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
     pub min: T,
     /// Whether the minimum value is exclusive
     #[cfg_attr(feature = "fluent", fluent(skip))]
     #[builder(default = false)]
     pub exclusive_min: bool,
-    /// Maximum allowed value (inclusive)
+    /// Maximum allowed value (inclusive unless `exclusive_max`)
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
     pub max: T,
     /// Whether the maximum value is exclusive
@@ -55,6 +77,8 @@ pub struct RangeValidation<T: PartialOrd + Copy + std::fmt::Display + Clone> {
 
 impl<T: PartialOrd + Copy + std::fmt::Display> Validate<T> for RangeValidation<T> {
     fn validate(&self, value: &T) -> bool {
+        // Each bound is checked as "value is inside", not "value is outside",
+        // so an unordered value (`NaN`) fails both checks instead of passing.
         let lower_ok = if self.exclusive_min {
             *value > self.min
         } else {
@@ -74,10 +98,12 @@ impl<T: PartialOrd + Copy + std::fmt::Display> Validate<T> for RangeValidation<T
 #[cfg(feature = "fmt")]
 impl<T: PartialOrd + Copy + std::fmt::Display + Clone> std::fmt::Display for RangeValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let open = if self.exclusive_min { '(' } else { '[' };
+        let close = if self.exclusive_max { ')' } else { ']' };
         write!(
             f,
-            "value {} is not within bounds [{}, {}]",
-            self.actual, self.min, self.max
+            "value {} is not within bounds {}{}, {}{}",
+            self.actual, open, self.min, self.max, close
         )
     }
 }
@@ -0,0 +1,178 @@
+use koruma::{Validate, validator};
+
+use super::Divisible;
+
+/// Which condition of a [`StepRangeValidation`] failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum StepRangeFailure {
+    /// The value is outside `[min, max]`.
+    OutOfRange,
+    /// The value is in range but not on a `step` boundary counted from `min`.
+    OffStep,
+}
+
+impl StepRangeFailure {
+    /// Returns the Fluent selector key for this condition (`out_of_range` or `off_step`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::OutOfRange => "out_of_range",
+            Self::OffStep => "off_step",
+        }
+    }
+}
+
+impl std::fmt::Display for StepRangeFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "out of range"),
+            Self::OffStep => write!(f, "off step"),
+        }
+    }
+}
+
+/// Returns which condition `actual` fails, or `None` if it is valid.
+///
+/// The distance from `min` may not fit in `T` (e.g. `-100..=100` as `i8`),
+/// so the step is checked with [`Divisible::is_multiple_from_within`].
+fn step_range_failure<T: Divisible>(
+    actual: T,
+    min: T,
+    max: T,
+    step: T,
+    epsilon: f64,
+) -> Option<StepRangeFailure> {
+    if !(min <= actual && actual <= max) {
+        Some(StepRangeFailure::OutOfRange)
+    } else if !actual.is_multiple_from_within(min, step, epsilon) {
+        Some(StepRangeFailure::OffStep)
+    } else {
+        None
+    }
+}
+
+/// Stepped range validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::numeric::{StepRangeFailure, StepRangeValidation};
+///
+/// #[derive(Koruma)]
+/// struct Slider {
+///     // 5, 15, 25, ... 95
+///     #[koruma(StepRangeValidation::<_>(min = 5, max = 95, step = 10))]
+///     volume: u8,
+/// }
+///
+/// assert!(Slider { volume: 35 }.validate().is_ok());
+///
+/// let err = Slider { volume: 40 }.validate().unwrap_err();
+/// let failure = err.volume().step_range_validation().unwrap();
+/// assert_eq!(failure.failure, Some(StepRangeFailure::OffStep));
+///
+/// let err = Slider { volume: 0 }.validate().unwrap_err();
+/// let failure = err.volume().step_range_validation().unwrap();
+/// assert_eq!(failure.failure, Some(StepRangeFailure::OutOfRange));
+/// ```
+///
+/// The range may be wider than the type's maximum:
+///
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::numeric::{StepRangeFailure, StepRangeValidation};
+///
+/// #[derive(Koruma)]
+/// struct Balance {
+///     // 200 apart, more than `i8::MAX`
+///     #[koruma(StepRangeValidation::<_>(min = -100, max = 100, step = 5))]
+///     trim: i8,
+/// }
+///
+/// assert!(Balance { trim: 100 }.validate().is_ok());
+/// assert!(Balance { trim: 95 }.validate().is_ok());
+///
+/// let err = Balance { trim: 99 }.validate().unwrap_err();
+/// let failure = err.trim().step_range_validation().unwrap();
+/// assert_eq!(failure.failure, Some(StepRangeFailure::OffStep));
+/// ```
+///
+/// Validates that a value is within `[min, max]` (inclusive) and lands on the
+/// grid `min, min + step, min + 2 * step, ...`.
+///
+/// Unlike chaining `RangeValidation` with `MultipleOfValidation`, the grid
+/// starts at `min` rather than zero. For floats, set `epsilon` to tolerate
+/// rounding error (default `0.0`). Which condition failed is kept in
+/// `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Step Range [0, 100] step 5",
+    description = "Validates that the input is between 0 and 100 in steps of 5",
+    input = Integer,
+    try_create = |input: &str| {
+        let num = input.parse::<i64>().map_err(|e| e.to_string())?;
+        Ok(StepRangeValidation::builder()
+            .min(0_i64)
+            .max(100_i64)
+            .step(5_i64)
+            .with_value(num)
+            .build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct StepRangeValidation<T: Divisible> {
+    /// Minimum allowed value (inclusive), also the grid origin
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub min: T,
+    /// Maximum allowed value (inclusive)
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub max: T,
+    /// Distance between allowed values
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub step: T,
+    /// Tolerance for float steps (default `0.0`, ignored for integers)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub epsilon: f64,
+    /// The value being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// Which condition `actual` fails, or `None` if it is valid
+    #[builder(skip = step_range_failure(actual, min, max, step, epsilon))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<StepRangeFailure>| {
+        x.map_or("valid", StepRangeFailure::key).to_string()
+    })))]
+    pub failure: Option<StepRangeFailure>,
+}
+
+impl<T: Divisible> Validate<T> for StepRangeValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        step_range_failure(*value, self.min, self.max, self.step, self.epsilon).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: Divisible> std::fmt::Display for StepRangeValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.failure {
+            Some(StepRangeFailure::OffStep) => write!(
+                f,
+                "value {} is not on a step of {} from {}",
+                self.actual, self.step, self.min
+            ),
+            _ => write!(
+                f,
+                "value {} is not within bounds [{}, {}]",
+                self.actual, self.min, self.max
+            ),
+        }
+    }
+}
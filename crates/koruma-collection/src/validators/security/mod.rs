@@ -0,0 +1,8 @@
+//! Security validation validators.
+//!
+//! This module contains validators for credentials and other
+//! security-sensitive input.
+
+mod password_strength;
+
+pub use password_strength::{PasswordRequirement, PasswordStrengthValidation};
@@ -0,0 +1,201 @@
+use koruma::{Validate, validator};
+
+use crate::string::StringLike;
+
+/// A single rule checked by [`PasswordStrengthValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PasswordRequirement {
+    /// At least `min_length` characters.
+    MinLength,
+    /// At least one uppercase letter.
+    Uppercase,
+    /// At least one lowercase letter.
+    Lowercase,
+    /// At least one digit.
+    Digit,
+    /// At least one symbol (any character that isn't alphanumeric or whitespace).
+    Symbol,
+}
+
+impl PasswordRequirement {
+    /// Returns whether `c` belongs to this character class. Always `false`
+    /// for [`MinLength`](Self::MinLength).
+    pub fn matches(self, c: char) -> bool {
+        match self {
+            Self::MinLength => false,
+            Self::Uppercase => c.is_uppercase(),
+            Self::Lowercase => c.is_lowercase(),
+            Self::Digit => c.is_ascii_digit(),
+            Self::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+}
+
+impl std::fmt::Display for PasswordRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinLength => write!(f, "minimum length"),
+            Self::Uppercase => write!(f, "an uppercase letter"),
+            Self::Lowercase => write!(f, "a lowercase letter"),
+            Self::Digit => write!(f, "a digit"),
+            Self::Symbol => write!(f, "a symbol"),
+        }
+    }
+}
+
+/// Password strength validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::security::{PasswordRequirement, PasswordStrengthValidation};
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(PasswordStrengthValidation::<_>(
+///         min_length = 8,
+///         require_upper = true,
+///         require_digit = true,
+///         require_symbol = true,
+///     ))]
+///     password: String,
+/// }
+///
+/// let signup = Signup { password: "hunter2".to_string() };
+/// let err = signup.validate().unwrap_err();
+/// let failure = err.password().password_strength_validation().unwrap();
+/// assert_eq!(
+///     failure.failed,
+///     vec![
+///         PasswordRequirement::MinLength,
+///         PasswordRequirement::Uppercase,
+///         PasswordRequirement::Symbol,
+///     ]
+/// );
+/// ```
+///
+/// Validates that a password is at least `min_length` characters long and
+/// contains every required character class.
+///
+/// Each class is opt-in through `require_upper`, `require_lower`,
+/// `require_digit` and `require_symbol` (all `false` by default). Length is
+/// counted in characters. The requirements that weren't met are listed in
+/// `failed`, in the order above, so a UI can show targeted hints.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Password Strength",
+    description = "Validates 8+ characters with upper, lower, digit and symbol",
+    create = |input: &str| {
+        PasswordStrengthValidation::builder()
+            .min_length(8)
+            .require_upper(true)
+            .require_lower(true)
+            .require_digit(true)
+            .require_symbol(true)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct PasswordStrengthValidation<T: StringLike> {
+    /// Minimum number of characters (inclusive)
+    pub min_length: usize,
+    /// Whether an uppercase letter is required (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub require_upper: bool,
+    /// Whether a lowercase letter is required (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub require_lower: bool,
+    /// Whether a digit is required (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub require_digit: bool,
+    /// Whether a symbol is required (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub require_symbol: bool,
+    /// The password being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub actual: T,
+    /// Requirements `actual` doesn't meet
+    #[builder(skip = unmet_requirements(
+        actual.as_ref(),
+        min_length,
+        [require_upper, require_lower, require_digit, require_symbol],
+    ))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub failed: Vec<PasswordRequirement>,
+}
+
+/// Returns the requirements `password` doesn't meet. `required` flags the
+/// uppercase, lowercase, digit and symbol classes, in that order.
+fn unmet_requirements(
+    password: &str,
+    min_length: usize,
+    required: [bool; 4],
+) -> Vec<PasswordRequirement> {
+    const CLASSES: [PasswordRequirement; 4] = [
+        PasswordRequirement::Uppercase,
+        PasswordRequirement::Lowercase,
+        PasswordRequirement::Digit,
+        PasswordRequirement::Symbol,
+    ];
+
+    let mut failed = Vec::new();
+    if password.chars().count() < min_length {
+        failed.push(PasswordRequirement::MinLength);
+    }
+    for (requirement, required) in CLASSES.into_iter().zip(required) {
+        if required && !password.chars().any(|c| requirement.matches(c)) {
+            failed.push(requirement);
+        }
+    }
+    failed
+}
+
+impl<T: StringLike> Validate<T> for PasswordStrengthValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        unmet_requirements(
+            value.as_ref(),
+            self.min_length,
+            [
+                self.require_upper,
+                self.require_lower,
+                self.require_digit,
+                self.require_symbol,
+            ],
+        )
+        .is_empty()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for PasswordStrengthValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "password is missing ")?;
+        for (i, requirement) in self.failed.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match requirement {
+                PasswordRequirement::MinLength => {
+                    write!(f, "at least {} characters", self.min_length)?
+                },
+                requirement => write!(f, "{requirement}")?,
+            }
+        }
+        Ok(())
+    }
+}
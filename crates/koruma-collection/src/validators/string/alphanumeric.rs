@@ -16,7 +16,7 @@ use koruma::{Validate, validator};
 /// ```
 ///
 /// Validates that a string contains only alphanumeric characters.
-#[validator]
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Alphanumeric",
     description = "Validates that the input contains only alphanumeric characters",
@@ -27,6 +27,7 @@ use koruma::{Validate, validator};
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct AlphanumericValidation<T: AsRef<str>> {
     /// The string being validated (stored for error context)
@@ -0,0 +1,209 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// The kind of imbalance found by [`BalancedDelimitersValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ImbalanceKind {
+    /// A closing delimiter with no open delimiter left to close.
+    UnexpectedClose(char),
+    /// A closing delimiter that doesn't match the innermost open one.
+    Mismatched { expected: char, found: char },
+    /// An opening delimiter that is never closed.
+    Unclosed(char),
+}
+
+impl ImbalanceKind {
+    /// Returns the Fluent selector key for this kind
+    /// (`unexpected`, `mismatched` or `unclosed`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::UnexpectedClose(_) => "unexpected",
+            Self::Mismatched { .. } => "mismatched",
+            Self::Unclosed(_) => "unclosed",
+        }
+    }
+}
+
+/// The first imbalance in a string, as found by [`BalancedDelimitersValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Imbalance {
+    /// Byte index of the offending delimiter; for [`ImbalanceKind::Unclosed`],
+    /// the index of the innermost unclosed opening delimiter.
+    pub index: usize,
+    /// What is wrong with the delimiter
+    pub kind: ImbalanceKind,
+}
+
+/// Parses `"()"`-style pairs into `(open, close)` characters.
+///
+/// # Panics
+///
+/// Panics if a pair isn't exactly two characters.
+fn parse_pairs(pairs: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<(char, char)> {
+    pairs
+        .into_iter()
+        .map(|pair| {
+            let pair = pair.as_ref();
+            let mut chars = pair.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(open), Some(close), None) => (open, close),
+                _ => panic!("delimiter pair {pair:?} must be exactly two characters, like \"()\""),
+            }
+        })
+        .collect()
+}
+
+/// Returns the first imbalance in `s`, or `None` if every pair is balanced
+/// and properly nested.
+fn first_imbalance(s: &str, pairs: &[(char, char)], respect_quotes: bool) -> Option<Imbalance> {
+    let mut open: Vec<(usize, char)> = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (index, c) in s.char_indices() {
+        if let Some(q) = quote {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if c == q => quote = None,
+                _ => {},
+            }
+            continue;
+        }
+        if respect_quotes && matches!(c, '"' | '\'') {
+            quote = Some(c);
+        } else if let Some(&(_, close)) = pairs.iter().find(|(o, _)| *o == c) {
+            open.push((index, close));
+        } else if pairs.iter().any(|&(_, close)| close == c) {
+            let kind = match open.pop() {
+                None => ImbalanceKind::UnexpectedClose(c),
+                Some((_, expected)) if expected != c => {
+                    ImbalanceKind::Mismatched { expected, found: c }
+                },
+                Some(_) => continue,
+            };
+            return Some(Imbalance { index, kind });
+        }
+    }
+
+    open.pop().map(|(index, close)| {
+        let opener = pairs
+            .iter()
+            .find(|&&(_, c)| c == close)
+            .map_or(close, |&(o, _)| o);
+        Imbalance {
+            index,
+            kind: ImbalanceKind::Unclosed(opener),
+        }
+    })
+}
+
+/// Balanced delimiters validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::{BalancedDelimitersValidation, ImbalanceKind};
+///
+/// #[derive(Koruma)]
+/// struct Formula {
+///     #[koruma(BalancedDelimitersValidation(pairs = ["()", "[]"]))]
+///     expression: String,
+///     #[koruma(BalancedDelimitersValidation(respect_quotes = true))]
+///     template: String,
+/// }
+///
+/// let formula = Formula {
+///     expression: "(a[b])".to_string(),
+///     template: r#"{ "name": "}" }"#.to_string(),
+/// };
+/// assert!(formula.validate().is_ok());
+///
+/// let formula = Formula {
+///     expression: "(a]".to_string(),
+///     template: "{}".to_string(),
+/// };
+/// let err = formula.validate().unwrap_err();
+/// let imbalance = err.expression().balanced_delimiters_validation().unwrap().imbalance.unwrap();
+/// assert_eq!(imbalance.index, 2);
+/// assert_eq!(imbalance.kind, ImbalanceKind::Mismatched { expected: ')', found: ']' });
+/// ```
+///
+/// Validates that delimiter pairs in a string are balanced and properly
+/// nested. `pairs` lists each pair as a two-character string such as `"()"`,
+/// and defaults to `()`, `[]` and `{}`; building the validator panics if a
+/// pair isn't exactly two characters.
+///
+/// Set `respect_quotes = true` to ignore delimiters inside `"..."` or
+/// `'...'` (with `\` escaping a quote). The first imbalance is kept in
+/// `imbalance`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Balanced Delimiters",
+    description = "Validates that (), [] and {} in the input are balanced",
+    create = |input: &str| {
+        BalancedDelimitersValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct BalancedDelimitersValidation<T: StringLike = String> {
+    /// The `(open, close)` delimiter pairs to check
+    #[builder(
+        with = |pairs: impl IntoIterator<Item = impl AsRef<str>>| parse_pairs(pairs),
+        default = vec![('(', ')'), ('[', ']'), ('{', '}')]
+    )]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub pairs: Vec<(char, char)>,
+    /// Whether delimiters inside quotes are ignored (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub respect_quotes: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// The first imbalance in `actual`, or `None` if it is balanced
+    #[builder(skip = first_imbalance(actual.as_ref(), &pairs, respect_quotes))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<Imbalance>| {
+        x.map_or("none", |imbalance| imbalance.kind.key()).to_string()
+    })))]
+    pub imbalance: Option<Imbalance>,
+}
+
+impl<T: StringLike> Validate<T> for BalancedDelimitersValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        first_imbalance(value.as_ref(), &self.pairs, self.respect_quotes).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for BalancedDelimitersValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(Imbalance { index, kind }) = self.imbalance else {
+            return write!(f, "delimiters are not balanced");
+        };
+        match kind {
+            ImbalanceKind::UnexpectedClose(c) => {
+                write!(f, "unexpected closing '{}' at byte {}", c, index)
+            },
+            ImbalanceKind::Mismatched { expected, found } => write!(
+                f,
+                "expected '{}' but found '{}' at byte {}",
+                expected, found, index
+            ),
+            ImbalanceKind::Unclosed(c) => write!(f, "unclosed '{}' at byte {}", c, index),
+        }
+    }
+}
@@ -12,11 +12,20 @@ use koruma::{Validate, validator};
 /// struct User {
 ///     #[koruma(ContainsValidation::<_>(substring = "test"))]
 ///     email: String,
+///     #[koruma(ContainsValidation::<_>(substring = "hello", case_insensitive = true))]
+///     greeting: String,
 /// }
+///
+/// let user = User {
+///     email: "test@example.com".to_string(),
+///     greeting: "HELLO world".to_string(),
+/// };
+/// assert!(user.validate().is_ok());
 /// ```
 ///
-/// Validates that a string contains a specified substring.
-#[validator]
+/// Validates that a string contains a specified substring. Set
+/// `case_insensitive = true` to lowercase both sides before comparing.
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Contains 'test'",
     description = "Validates that the input contains the substring 'test'",
@@ -28,11 +37,16 @@ use koruma::{Validate, validator};
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct ContainsValidation<T: AsRef<str>> {
     /// The substring to search for
     #[builder(into)]
     pub substring: String,
+    /// Whether both sides are lowercased before comparing (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub case_insensitive: bool,
     /// The string being validated (stored for error context)
     #[koruma(value)]
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
@@ -42,13 +56,21 @@ pub struct ContainsValidation<T: AsRef<str>> {
 impl<T: AsRef<str>> Validate<T> for ContainsValidation<T> {
     fn validate(&self, value: &T) -> bool {
         let s = value.as_ref();
-        s.contains(&self.substring)
+        if self.case_insensitive {
+            s.to_lowercase().contains(&self.substring.to_lowercase())
+        } else {
+            s.contains(&self.substring)
+        }
     }
 }
 
 #[cfg(feature = "fmt")]
 impl<T: AsRef<str>> std::fmt::Display for ContainsValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "value does not contain \"{}\"", self.substring)
+        write!(f, "value does not contain \"{}\"", self.substring)?;
+        if self.case_insensitive {
+            write!(f, " (case-insensitive)")?;
+        }
+        Ok(())
     }
 }
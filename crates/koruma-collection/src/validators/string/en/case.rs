@@ -6,6 +6,11 @@ use koruma::{Validate, validator};
 
 /// Represents different string case formats.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub enum Case {
     /// snake_case
@@ -77,7 +82,7 @@ impl Case {
 /// ```
 ///
 /// Validates that a string matches a specific case format.
-#[validator]
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Case Format",
     description = "Validates that the input is in snake_case format",
@@ -89,6 +94,7 @@ impl Case {
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct CaseValidation<T: AsRef<str>> {
     /// The string being validated (stored for error context)
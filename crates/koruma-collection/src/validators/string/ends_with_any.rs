@@ -0,0 +1,88 @@
+use koruma::{Validate, validator};
+
+/// Ends-with-any validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::EndsWithAnyValidation;
+///
+/// #[derive(Koruma)]
+/// struct Upload {
+///     #[koruma(EndsWithAnyValidation::<_>(suffixes = [".png", ".jpg"], case_insensitive = true))]
+///     file_name: String,
+/// }
+///
+/// let upload = Upload { file_name: "photo.JPG".to_string() };
+/// assert!(upload.validate().is_ok());
+///
+/// let upload = Upload { file_name: "notes.txt".to_string() };
+/// let err = upload.validate().unwrap_err();
+/// let failure = err.file_name().ends_with_any_validation().unwrap();
+/// assert_eq!(
+///     failure.to_string(),
+///     r#"value does not end with any of [".png", ".jpg"] (case-insensitive)"#
+/// );
+/// ```
+///
+/// Validates that a string ends with at least one of several suffixes, e.g.
+/// a set of accepted file extensions. Set `case_insensitive = true` to
+/// lowercase both sides before comparing.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Ends With Any '.png' or '.jpg'",
+    description = "Validates that the input ends with '.png' or '.jpg'",
+    create = |input: &str| {
+        EndsWithAnyValidation::builder()
+            .suffixes([".png", ".jpg"])
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct EndsWithAnyValidation<T: AsRef<str>> {
+    /// The accepted suffixes
+    #[builder(with = |suffixes: impl IntoIterator<Item = impl Into<String>>| {
+        suffixes.into_iter().map(Into::into).collect()
+    })]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Vec<String>| {
+        x.iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>().join(", ")
+    })))]
+    pub suffixes: Vec<String>,
+    /// Whether both sides are lowercased before comparing (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub case_insensitive: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+}
+
+impl<T: AsRef<str>> Validate<T> for EndsWithAnyValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let s = value.as_ref();
+        if self.case_insensitive {
+            let s = s.to_lowercase();
+            self.suffixes
+                .iter()
+                .any(|suffix| s.ends_with(&suffix.to_lowercase()))
+        } else {
+            self.suffixes.iter().any(|suffix| s.ends_with(suffix))
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: AsRef<str>> std::fmt::Display for EndsWithAnyValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value does not end with any of {:?}", self.suffixes)?;
+        if self.case_insensitive {
+            write!(f, " (case-insensitive)")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use koruma::{ObserveValue, Validate, validator};
+
+use super::StringLike;
+
+/// A requirement checked by [`EntropyValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EntropyRequirement {
+    /// At least `min_entropy_bits` bits of estimated entropy.
+    EntropyBits,
+    /// Characters from at least `require_classes` of the lowercase,
+    /// uppercase, digit and symbol classes.
+    CharacterClasses,
+}
+
+impl EntropyRequirement {
+    /// Returns the Fluent selector key for this requirement
+    /// (`entropy_bits` or `character_classes`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::EntropyBits => "entropy_bits",
+            Self::CharacterClasses => "character_classes",
+        }
+    }
+}
+
+/// Returns the Shannon entropy of `value` in bits: the entropy per character
+/// of its character distribution, times its length in characters.
+fn estimate_entropy_bits(value: &str) -> f64 {
+    let mut counts = std::collections::HashMap::new();
+    for c in value.chars() {
+        *counts.entry(c).or_insert(0usize) += 1;
+    }
+    let len: usize = counts.values().sum();
+    let per_char: f64 = counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / len as f64;
+            -p * p.log2()
+        })
+        .sum();
+    per_char * len as f64
+}
+
+/// Returns how many of the lowercase, uppercase, digit and symbol classes
+/// appear in `value`. Symbols are characters that are neither alphanumeric
+/// nor whitespace.
+fn count_character_classes(value: &str) -> usize {
+    let classes: [fn(char) -> bool; 4] = [
+        char::is_lowercase,
+        char::is_uppercase,
+        |c| c.is_ascii_digit(),
+        |c| !c.is_alphanumeric() && !c.is_whitespace(),
+    ];
+    classes
+        .iter()
+        .filter(|&&class| value.chars().any(class))
+        .count()
+}
+
+/// Returns the requirements a value with the given score doesn't meet.
+fn unmet_requirements(
+    entropy_bits: f64,
+    classes: usize,
+    min_entropy_bits: Option<u32>,
+    require_classes: Option<usize>,
+) -> Vec<EntropyRequirement> {
+    let mut unmet = Vec::new();
+    if min_entropy_bits.is_some_and(|min| entropy_bits < f64::from(min)) {
+        unmet.push(EntropyRequirement::EntropyBits);
+    }
+    if require_classes.is_some_and(|min| classes < min) {
+        unmet.push(EntropyRequirement::CharacterClasses);
+    }
+    unmet
+}
+
+/// String entropy validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::{EntropyRequirement, EntropyValidation};
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(redact, EntropyValidation(min_entropy_bits = 40))]
+///     password: String,
+///     #[koruma(EntropyValidation(require_classes = 3))]
+///     recovery_phrase: String,
+/// }
+///
+/// let signup = Signup {
+///     password: "correct horse battery staple".to_string(),
+///     recovery_phrase: "Tr0ub4dor".to_string(),
+/// };
+/// assert!(signup.validate().is_ok());
+///
+/// let signup = Signup {
+///     password: "password".to_string(),
+///     recovery_phrase: "tr0ub4dor".to_string(),
+/// };
+/// let err = signup.validate().unwrap_err();
+/// let password_err = err.password().entropy_validation().unwrap();
+/// assert_eq!(password_err.entropy_bits.round(), 22.0);
+/// assert_eq!(password_err.unmet, vec![EntropyRequirement::EntropyBits]);
+/// assert_eq!(password_err.actual, "********");
+/// assert_eq!(
+///     password_err.to_string(),
+///     "value is too predictable: 22 bits of entropy, at least 40 required"
+/// );
+///
+/// let phrase_err = err.recovery_phrase().entropy_validation().unwrap();
+/// assert_eq!(phrase_err.classes, 2);
+/// assert_eq!(phrase_err.unmet, vec![EntropyRequirement::CharacterClasses]);
+/// ```
+///
+/// Validates that a string, typically a password, is hard to guess. The
+/// score is a rough estimate: the Shannon entropy of its character
+/// distribution times its length, so repeated characters count for little.
+///
+/// Set `min_entropy_bits` to require a minimum score, and `require_classes`
+/// to require characters from that many of the lowercase, uppercase, digit
+/// and symbol classes. The computed score is kept in `entropy_bits`, the
+/// number of classes in `classes`, and the requirements that weren't met in
+/// `unmet`.
+///
+/// The checked value is never part of the message. Mark the field `redact`
+/// so the stored copy is masked too; the score still describes the real
+/// value.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Entropy",
+    description = "Validates that the input has at least 40 bits of estimated entropy",
+    create = |input: &str| {
+        EntropyValidation::builder()
+            .min_entropy_bits(40)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct EntropyValidation<T: StringLike = String> {
+    /// Minimum estimated entropy in bits, if any
+    #[cfg_attr(feature = "fluent", fluent(value(crate::i18n::count_or_none)))]
+    pub min_entropy_bits: Option<u32>,
+    /// Minimum number of character classes, if any
+    #[cfg_attr(feature = "fluent", fluent(value(crate::i18n::count_or_none)))]
+    pub require_classes: Option<usize>,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub actual: T,
+    /// Estimated entropy of `actual` in bits
+    #[builder(skip = estimate_entropy_bits(actual.as_ref()))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &f64| x.round())))]
+    pub entropy_bits: f64,
+    /// Number of character classes in `actual`
+    #[builder(skip = count_character_classes(actual.as_ref()))]
+    pub classes: usize,
+    /// Requirements `actual` doesn't meet
+    #[builder(skip = unmet_requirements(entropy_bits, classes, min_entropy_bits, require_classes))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Vec<EntropyRequirement>| {
+        x.first().map_or("none", |r| r.key()).to_string()
+    })))]
+    pub unmet: Vec<EntropyRequirement>,
+}
+
+impl<T: StringLike> Validate<T> for EntropyValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let value = value.as_ref();
+        unmet_requirements(
+            estimate_entropy_bits(value),
+            count_character_classes(value),
+            self.min_entropy_bits,
+            self.require_classes,
+        )
+        .is_empty()
+    }
+}
+
+impl<T: StringLike> ObserveValue<T> for EntropyValidation<T> {
+    fn observe_value(&mut self, value: &T) {
+        let value = value.as_ref();
+        self.entropy_bits = estimate_entropy_bits(value);
+        self.classes = count_character_classes(value);
+        self.unmet = unmet_requirements(
+            self.entropy_bits,
+            self.classes,
+            self.min_entropy_bits,
+            self.require_classes,
+        );
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for EntropyValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (
+            self.unmet.first(),
+            self.min_entropy_bits,
+            self.require_classes,
+        ) {
+            (Some(EntropyRequirement::EntropyBits), Some(min), _) => write!(
+                f,
+                "value is too predictable: {:.0} bits of entropy, at least {} required",
+                self.entropy_bits, min
+            ),
+            (Some(EntropyRequirement::CharacterClasses), _, Some(min)) => write!(
+                f,
+                "value uses {} character classes, at least {} required",
+                self.classes, min
+            ),
+            _ => write!(f, "value is too predictable"),
+        }
+    }
+}
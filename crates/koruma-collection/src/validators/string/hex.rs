@@ -0,0 +1,155 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// Why a string failed [`HexValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HexFailure {
+    /// There are no hex digits (after any allowed prefix).
+    Empty,
+    /// A character isn't `0-9`, `a-f` or `A-F`; `index` is its byte index.
+    InvalidChar { index: usize, found: char },
+    /// The number of hex digits isn't `expected_len`.
+    WrongLength { len: usize },
+}
+
+impl HexFailure {
+    /// Returns the Fluent selector key for this failure
+    /// (`empty`, `invalid_char` or `wrong_length`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::InvalidChar { .. } => "invalid_char",
+            Self::WrongLength { .. } => "wrong_length",
+        }
+    }
+}
+
+/// Returns why `actual` fails, or `None` if it is hex of the expected length.
+fn hex_failure(
+    actual: &str,
+    expected_len: Option<usize>,
+    allow_prefix: bool,
+) -> Option<HexFailure> {
+    let prefix_len = ["0x", "0X", "#"]
+        .into_iter()
+        .find(|prefix| allow_prefix && actual.starts_with(prefix))
+        .map_or(0, str::len);
+    let digits = &actual[prefix_len..];
+
+    if let Some((index, found)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Some(HexFailure::InvalidChar {
+            index: prefix_len + index,
+            found,
+        });
+    }
+    // Every character is an ASCII hex digit, so bytes and chars agree
+    let len = digits.len();
+    if expected_len.is_some_and(|expected| expected != len) {
+        return Some(HexFailure::WrongLength { len });
+    }
+    (len == 0).then_some(HexFailure::Empty)
+}
+
+/// Hex validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::{HexFailure, HexValidation};
+///
+/// #[derive(Koruma)]
+/// struct Theme {
+///     #[koruma(HexValidation::<_>(expected_len = 6, allow_prefix = true))]
+///     accent: String,
+///     #[koruma(HexValidation::<_>(expected_len = 64))]
+///     checksum: String,
+/// }
+///
+/// let theme = Theme {
+///     accent: "#1e90FF".to_string(),
+///     checksum: "ab".repeat(32),
+/// };
+/// assert!(theme.validate().is_ok());
+///
+/// let theme = Theme {
+///     accent: "#1e90fg".to_string(),
+///     checksum: "abc".to_string(),
+/// };
+/// let err = theme.validate().unwrap_err();
+/// let accent_err = err.accent().hex_validation().unwrap();
+/// assert_eq!(accent_err.failure, Some(HexFailure::InvalidChar { index: 6, found: 'g' }));
+/// assert_eq!(accent_err.to_string(), "invalid hex character 'g' at byte 6");
+///
+/// let checksum_err = err.checksum().hex_validation().unwrap();
+/// assert_eq!(checksum_err.failure, Some(HexFailure::WrongLength { len: 3 }));
+/// ```
+///
+/// Validates that a string is non-empty and made only of hex digits
+/// (`0-9`, `a-f`, `A-F`).
+///
+/// Set `expected_len` to require an exact number of hex digits, and
+/// `allow_prefix = true` to accept a leading `0x`, `0X` or `#` (not counted
+/// in the length). Why validation failed, including the byte index of the
+/// first non-hex character, is kept in `failure`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Hex",
+    description = "Validates that the input is hex-encoded, optionally prefixed with 0x or #",
+    create = |input: &str| {
+        HexValidation::builder()
+            .allow_prefix(true)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct HexValidation<T: StringLike> {
+    /// Required number of hex digits, if any
+    #[cfg_attr(feature = "fluent", fluent(value(crate::i18n::count_or_none)))]
+    pub expected_len: Option<usize>,
+    /// Whether a leading `0x`, `0X` or `#` is accepted (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub allow_prefix: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+    /// Why `actual` fails, or `None` if it is valid
+    #[builder(skip = hex_failure(actual.as_ref(), expected_len, allow_prefix))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<HexFailure>| {
+        x.map_or("none", HexFailure::key).to_string()
+    })))]
+    pub failure: Option<HexFailure>,
+}
+
+impl<T: StringLike> Validate<T> for HexValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        hex_failure(value.as_ref(), self.expected_len, self.allow_prefix).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for HexValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.failure, self.expected_len) {
+            (Some(HexFailure::InvalidChar { index, found }), _) => {
+                write!(f, "invalid hex character '{}' at byte {}", found, index)
+            },
+            (Some(HexFailure::WrongLength { len }), Some(expected)) => {
+                write!(f, "expected {} hex digits, found {}", expected, len)
+            },
+            (Some(HexFailure::Empty), _) => write!(f, "hex value must not be empty"),
+            _ => write!(f, "value is not valid hex"),
+        }
+    }
+}
@@ -17,7 +17,7 @@ use koruma::{Validate, validator};
 /// ```
 ///
 /// Validates that a value matches another value.
-#[validator]
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Matches Value",
     description = "Validates that the input matches 'expected'",
@@ -29,6 +29,7 @@ use koruma::{Validate, validator};
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct MatchesValidation<T: PartialEq + std::fmt::Display + Clone> {
     /// The value to match against
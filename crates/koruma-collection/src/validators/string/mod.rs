@@ -9,20 +9,38 @@ impl<T: AsRef<str>> StringLike for T {}
 
 mod alphanumeric;
 mod ascii;
+mod balanced_delimiters;
 mod contains;
+mod ends_with_any;
+mod entropy;
+mod hex;
 mod matches;
+mod no_control_chars;
 #[cfg(feature = "regex")]
 mod pattern;
 mod prefix;
+mod split_count;
+mod starts_with_any;
 mod suffix;
+mod trimmed_length;
+mod word_count;
 
 pub use alphanumeric::AlphanumericValidation;
 pub use ascii::AsciiValidation;
+pub use balanced_delimiters::{BalancedDelimitersValidation, Imbalance, ImbalanceKind};
 pub use contains::ContainsValidation;
+pub use ends_with_any::EndsWithAnyValidation;
+pub use entropy::{EntropyRequirement, EntropyValidation};
+pub use hex::{HexFailure, HexValidation};
 pub use matches::MatchesValidation;
+pub use no_control_chars::NoControlCharsValidation;
 #[cfg(feature = "regex")]
-pub use pattern::PatternValidation;
+pub use pattern::{PatternCaptures, PatternValidation};
 pub use prefix::PrefixValidation;
+pub use split_count::SplitCountValidation;
+pub use starts_with_any::StartsWithAnyValidation;
 pub use suffix::SuffixValidation;
+pub use trimmed_length::TrimmedLengthValidation;
+pub use word_count::WordCountValidation;
 
 pub mod en;
@@ -0,0 +1,88 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// No control characters validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::NoControlCharsValidation;
+///
+/// #[derive(Koruma)]
+/// struct Post {
+///     #[koruma(NoControlCharsValidation::<_>)]
+///     title: String,
+///     #[koruma(NoControlCharsValidation::<_>(allow_whitespace = true))]
+///     body: String,
+/// }
+///
+/// let post = Post {
+///     title: "hi\u{1b}[31m".to_string(),
+///     body: "line one\n\tline two".to_string(),
+/// };
+/// let err = post.validate().unwrap_err();
+/// let failure = err.title().no_control_chars_validation().unwrap();
+/// assert_eq!(failure.first_offending, Some((2, '\u{1b}')));
+/// assert!(err.body().no_control_chars_validation().is_none());
+/// ```
+///
+/// Validates that a string contains no control characters (as reported by
+/// [`char::is_control`]), such as null bytes or ANSI escapes.
+///
+/// Set `allow_whitespace = true` to still accept `\n` and `\t`. The first
+/// offending character and its byte index are kept in `first_offending`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "No Control Characters",
+    description = "Validates that the input contains no control characters",
+    create = |input: &str| {
+        NoControlCharsValidation::builder()
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct NoControlCharsValidation<T: StringLike> {
+    /// Whether `\n` and `\t` are allowed (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub allow_whitespace: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub actual: T,
+    /// Byte index and value of the first control character in `actual`
+    #[builder(skip = first_control_char(actual.as_ref(), allow_whitespace))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub first_offending: Option<(usize, char)>,
+}
+
+/// Returns the byte index and value of the first disallowed control character.
+fn first_control_char(s: &str, allow_whitespace: bool) -> Option<(usize, char)> {
+    s.char_indices()
+        .find(|&(_, c)| c.is_control() && !(allow_whitespace && matches!(c, '\n' | '\t')))
+}
+
+impl<T: StringLike> Validate<T> for NoControlCharsValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        first_control_char(value.as_ref(), self.allow_whitespace).is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for NoControlCharsValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.first_offending {
+            Some((index, c)) => write!(
+                f,
+                "value contains control character U+{:04X} at byte {}",
+                c as u32, index
+            ),
+            None => write!(f, "value contains control characters"),
+        }
+    }
+}
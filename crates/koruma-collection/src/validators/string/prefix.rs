@@ -15,8 +15,9 @@ use koruma::{Validate, validator};
 /// }
 /// ```
 ///
-/// Validates that a string starts with a specified prefix.
-#[validator]
+/// Validates that a string starts with a specified prefix. Set
+/// `case_insensitive = true` to lowercase both sides before comparing.
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Prefix 'hello'",
     description = "Validates that the input starts with 'hello'",
@@ -28,11 +29,16 @@ use koruma::{Validate, validator};
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct PrefixValidation<T: AsRef<str>> {
     /// The prefix to check for
     #[builder(into)]
     pub prefix: String,
+    /// Whether both sides are lowercased before comparing (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub case_insensitive: bool,
     /// The string being validated (stored for error context)
     #[koruma(value)]
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
@@ -42,13 +48,21 @@ pub struct PrefixValidation<T: AsRef<str>> {
 impl<T: AsRef<str>> Validate<T> for PrefixValidation<T> {
     fn validate(&self, value: &T) -> bool {
         let s = value.as_ref();
-        s.starts_with(&self.prefix)
+        if self.case_insensitive {
+            s.to_lowercase().starts_with(&self.prefix.to_lowercase())
+        } else {
+            s.starts_with(&self.prefix)
+        }
     }
 }
 
 #[cfg(feature = "fmt")]
 impl<T: AsRef<str>> std::fmt::Display for PrefixValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "value does not start with \"{}\"", self.prefix)
+        write!(f, "value does not start with \"{}\"", self.prefix)?;
+        if self.case_insensitive {
+            write!(f, " (case-insensitive)")?;
+        }
+        Ok(())
     }
 }
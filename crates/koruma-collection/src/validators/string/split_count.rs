@@ -0,0 +1,80 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// Split count validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::SplitCountValidation;
+///
+/// #[derive(Koruma)]
+/// struct Post {
+///     #[koruma(SplitCountValidation::<_>(delimiter = ",", min = 1, max = 3))]
+///     tags: String,
+/// }
+///
+/// let post = Post { tags: "rust,web,async,cli".to_string() };
+/// let err = post.validate().unwrap_err();
+/// let failure = err.tags().split_count_validation().unwrap();
+/// assert_eq!(failure.segment_count, 4);
+/// ```
+///
+/// Validates that splitting a string on a delimiter yields a number of
+/// segments within the specified bounds, e.g. before parsing a
+/// comma-separated list.
+///
+/// Segments are counted with [`str::split`], so empty segments count too:
+/// `""` has one segment and `"a,,b"` has three.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Split Count",
+    description = "Validates the input has between 2 and 4 comma-separated fields",
+    create = |input: &str| {
+        SplitCountValidation::builder()
+            .delimiter(",")
+            .min(2)
+            .max(4)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct SplitCountValidation<T: StringLike> {
+    /// The delimiter to split on
+    #[builder(into)]
+    pub delimiter: String,
+    /// Minimum allowed number of segments (inclusive)
+    pub min: usize,
+    /// Maximum allowed number of segments (inclusive)
+    pub max: usize,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub actual: T,
+    /// Number of segments in `actual`
+    #[builder(skip = actual.as_ref().split(delimiter.as_str()).count())]
+    pub segment_count: usize,
+}
+
+impl<T: StringLike> Validate<T> for SplitCountValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let count = value.as_ref().split(self.delimiter.as_str()).count();
+        count >= self.min && count <= self.max
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for SplitCountValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} segments split on {:?} is not within bounds [{}, {}]",
+            self.segment_count, self.delimiter, self.min, self.max
+        )
+    }
+}
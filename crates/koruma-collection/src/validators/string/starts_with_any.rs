@@ -0,0 +1,84 @@
+use koruma::{Validate, validator};
+
+/// Starts-with-any validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::StartsWithAnyValidation;
+///
+/// #[derive(Koruma)]
+/// struct Link {
+///     #[koruma(StartsWithAnyValidation::<_>(prefixes = ["http://", "https://"]))]
+///     url: String,
+/// }
+///
+/// let link = Link { url: "ftp://example.com".to_string() };
+/// let err = link.validate().unwrap_err();
+/// let failure = err.url().starts_with_any_validation().unwrap();
+/// assert_eq!(
+///     failure.to_string(),
+///     r#"value does not start with any of ["http://", "https://"]"#
+/// );
+/// ```
+///
+/// Validates that a string starts with at least one of several prefixes.
+/// Set `case_insensitive = true` to lowercase both sides before comparing.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Starts With Any 'http://' or 'https://'",
+    description = "Validates that the input starts with 'http://' or 'https://'",
+    create = |input: &str| {
+        StartsWithAnyValidation::builder()
+            .prefixes(["http://", "https://"])
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct StartsWithAnyValidation<T: AsRef<str>> {
+    /// The accepted prefixes
+    #[builder(with = |prefixes: impl IntoIterator<Item = impl Into<String>>| {
+        prefixes.into_iter().map(Into::into).collect()
+    })]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Vec<String>| {
+        x.iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>().join(", ")
+    })))]
+    pub prefixes: Vec<String>,
+    /// Whether both sides are lowercased before comparing (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub case_insensitive: bool,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
+    pub actual: T,
+}
+
+impl<T: AsRef<str>> Validate<T> for StartsWithAnyValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let s = value.as_ref();
+        if self.case_insensitive {
+            let s = s.to_lowercase();
+            self.prefixes
+                .iter()
+                .any(|prefix| s.starts_with(&prefix.to_lowercase()))
+        } else {
+            self.prefixes.iter().any(|prefix| s.starts_with(prefix))
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: AsRef<str>> std::fmt::Display for StartsWithAnyValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value does not start with any of {:?}", self.prefixes)?;
+        if self.case_insensitive {
+            write!(f, " (case-insensitive)")?;
+        }
+        Ok(())
+    }
+}
@@ -15,8 +15,9 @@ use koruma::{Validate, validator};
 /// }
 /// ```
 ///
-/// Validates that a string ends with a specified suffix.
-#[validator]
+/// Validates that a string ends with a specified suffix. Set
+/// `case_insensitive = true` to lowercase both sides before comparing.
+#[validator(deferred_value)]
 #[cfg_attr(feature = "showcase", showcase(
     name = "Suffix '.rs'",
     description = "Validates that the input ends with '.rs'",
@@ -28,11 +29,16 @@ use koruma::{Validate, validator};
     }
 ))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
 pub struct SuffixValidation<T: AsRef<str>> {
     /// The suffix to check for
     #[builder(into)]
     pub suffix: String,
+    /// Whether both sides are lowercased before comparing (default `false`)
+    #[builder(default)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub case_insensitive: bool,
     /// The string being validated (stored for error context)
     #[koruma(value)]
     #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().to_string())))]
@@ -42,13 +48,21 @@ pub struct SuffixValidation<T: AsRef<str>> {
 impl<T: AsRef<str>> Validate<T> for SuffixValidation<T> {
     fn validate(&self, value: &T) -> bool {
         let s = value.as_ref();
-        s.ends_with(&self.suffix)
+        if self.case_insensitive {
+            s.to_lowercase().ends_with(&self.suffix.to_lowercase())
+        } else {
+            s.ends_with(&self.suffix)
+        }
     }
 }
 
 #[cfg(feature = "fmt")]
 impl<T: AsRef<str>> std::fmt::Display for SuffixValidation<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "value does not end with \"{}\"", self.suffix)
+        write!(f, "value does not end with \"{}\"", self.suffix)?;
+        if self.case_insensitive {
+            write!(f, " (case-insensitive)")?;
+        }
+        Ok(())
     }
 }
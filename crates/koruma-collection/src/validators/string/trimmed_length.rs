@@ -0,0 +1,89 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// Trimmed length validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::TrimmedLengthValidation;
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(TrimmedLengthValidation::<_>(min = 3, max = 20))]
+///     username: String,
+/// }
+///
+/// let signup = Signup { username: "  ab  ".to_string() };
+/// let err = signup.validate().unwrap_err();
+/// let failure = err.username().trimmed_length_validation().unwrap();
+/// assert_eq!(failure.trimmed(), "ab");
+/// assert_eq!(failure.trimmed_len(), 2);
+/// assert_eq!(failure.actual, "  ab  ");
+/// ```
+///
+/// Validates that a string's length, after trimming leading and trailing
+/// whitespace, is within the specified bounds.
+///
+/// Length is counted in characters. The raw input is kept in `actual`, so
+/// error messages can show both the original and trimmed forms.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Trimmed Length",
+    description = "Validates trimmed string length is between 3 and 10",
+    create = |input: &str| {
+        TrimmedLengthValidation::builder()
+            .min(3)
+            .max(10)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct TrimmedLengthValidation<T: StringLike> {
+    /// Minimum allowed trimmed length (inclusive)
+    pub min: usize,
+    /// Maximum allowed trimmed length (inclusive)
+    pub max: usize,
+    /// The string being validated, untrimmed (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.as_ref().trim().chars().count())))]
+    pub actual: T,
+}
+
+impl<T: StringLike> TrimmedLengthValidation<T> {
+    /// Returns the validated string with leading and trailing whitespace removed.
+    pub fn trimmed(&self) -> &str {
+        self.actual.as_ref().trim()
+    }
+
+    /// Returns the length of the trimmed string, in characters.
+    pub fn trimmed_len(&self) -> usize {
+        self.trimmed().chars().count()
+    }
+}
+
+impl<T: StringLike> Validate<T> for TrimmedLengthValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let len = value.as_ref().trim().chars().count();
+        len >= self.min && len <= self.max
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for TrimmedLengthValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "trimmed length {} of \"{}\" is not within bounds [{}, {}]",
+            self.trimmed_len(),
+            self.actual.as_ref(),
+            self.min,
+            self.max
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use koruma::{Validate, validator};
+
+use super::StringLike;
+
+/// Word count validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use koruma::Koruma;
+/// use koruma_collection::string::WordCountValidation;
+///
+/// #[derive(Koruma)]
+/// struct Review {
+///     #[koruma(WordCountValidation::<_>(min = 10, max = 200))]
+///     body: String,
+///     #[koruma(each(WordCountValidation::<_>(min = 1, max = 3)))]
+///     tags: Vec<String>,
+/// }
+///
+/// let review = Review {
+///     body: "Great product, would buy again".to_string(),
+///     tags: vec!["value for money".to_string(), "fast".to_string()],
+/// };
+/// let err = review.validate().unwrap_err();
+/// let failure = err.body().word_count_validation().unwrap();
+/// assert_eq!(failure.word_count, 5);
+/// assert!(err.tags().element_errors().is_empty());
+/// ```
+///
+/// Validates that the number of whitespace-separated words in a string is
+/// within the specified bounds.
+///
+/// Words are counted with [`str::split_whitespace`], so runs of spaces,
+/// tabs and newlines all count as a single separator.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Word Count",
+    description = "Validates the input has between 2 and 5 words",
+    create = |input: &str| {
+        WordCountValidation::builder()
+            .min(2)
+            .max(5)
+            .with_value(input.to_string())
+            .build()
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct WordCountValidation<T: StringLike> {
+    /// Minimum allowed number of words (inclusive)
+    pub min: usize,
+    /// Maximum allowed number of words (inclusive)
+    pub max: usize,
+    /// The string being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub actual: T,
+    /// Number of words in `actual`
+    #[builder(skip = actual.as_ref().split_whitespace().count())]
+    pub word_count: usize,
+}
+
+impl<T: StringLike> Validate<T> for WordCountValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let count = value.as_ref().split_whitespace().count();
+        count >= self.min && count <= self.max
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: StringLike> std::fmt::Display for WordCountValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "word count {} is not within bounds [{}, {}]",
+            self.word_count, self.min, self.max
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::Datelike;
+use koruma::{Validate, validator};
+
+use super::{ChronoNow, now_or};
+
+/// Returns the number of full years from `birthdate` to `now`.
+///
+/// A year is only counted once its anniversary has passed, comparing
+/// `(month, day)` tuples: someone born on February 29th turns a year older
+/// on March 1st in non-leap years. Negative if `birthdate` is after `now`.
+fn full_years<T: Datelike>(birthdate: &T, now: &T) -> i32 {
+    let years = now.year() - birthdate.year();
+    if (now.month(), now.day()) < (birthdate.month(), birthdate.day()) {
+        years - 1
+    } else {
+        years
+    }
+}
+
+/// Age validation for koruma.
+///
+/// # Example
+/// ```rust
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use koruma::Koruma;
+/// use koruma_collection::time::AgeValidation;
+///
+/// #[derive(Koruma)]
+/// struct Signup {
+///     #[koruma(AgeValidation::<_>(min_years = 13))]
+///     birthdate: NaiveDate,
+/// }
+///
+/// let signup = Signup { birthdate: NaiveDate::from_ymd_opt(1990, 5, 17).unwrap() };
+/// assert!(signup.validate().is_ok());
+///
+/// // Pin the reference date: born on a leap day, 18 only from March 1st
+/// let check = |month, day| {
+///     AgeValidation::builder()
+///         .min_years(18)
+///         .reference(Utc.with_ymd_and_hms(2022, month, day, 12, 0, 0).unwrap())
+///         .with_value(NaiveDate::from_ymd_opt(2004, 2, 29).unwrap())
+///         .build()
+/// };
+/// let feb_28 = check(2, 28);
+/// assert_eq!(feb_28.age, 17);
+/// assert!(!koruma::Validate::validate(&feb_28, &feb_28.actual));
+/// assert_eq!(feb_28.to_string(), "age 17 must be at least 18");
+/// // `validate` checks the value it's given against the same date
+/// assert!(koruma::Validate::validate(&feb_28, &NaiveDate::from_ymd_opt(2004, 2, 28).unwrap()));
+///
+/// let mar_1 = check(3, 1);
+/// assert_eq!(mar_1.age, 18);
+/// assert!(koruma::Validate::validate(&mar_1, &mar_1.actual));
+/// ```
+///
+/// Validates that the full years between a birthdate and the current date
+/// are at least `min_years` and, if set, at most `max_years`.
+///
+/// The current date is read from [`Utc::now`](chrono::Utc::now) when the
+/// validator is built, unless `reference` pins it, and kept in `now`. The age
+/// of the stored birthdate is kept in `age` for error reporting; a birthdate
+/// in the future gives a negative age.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Age 18+",
+    description = "Validates that a YYYY-MM-DD birthdate is at least 18 years ago",
+    try_create = |input: &str| {
+        let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .map_err(|e| format!("expected a YYYY-MM-DD date: {e}"))?;
+        Ok(AgeValidation::builder().min_years(18).with_value(date).build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct AgeValidation<T: ChronoNow + Datelike> {
+    /// Minimum age in full years
+    pub min_years: u32,
+    /// Maximum age in full years, if any
+    #[cfg_attr(feature = "fluent", fluent(value(crate::i18n::count_or_none)))]
+    pub max_years: Option<u32>,
+    /// Fixed time to use as "now" instead of the wall clock
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub reference: Option<chrono::DateTime<chrono::Utc>>,
+    /// The birthdate being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// The current date, or `reference` if set
+    #[builder(skip = now_or(reference.as_ref()))]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub now: T,
+    /// Full years between `actual` and `now`
+    #[builder(skip = full_years(&actual, &now))]
+    pub age: i32,
+}
+
+impl<T: ChronoNow + Datelike> Validate<T> for AgeValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        let age = i64::from(full_years(value, &self.now));
+        let min_ok = age >= i64::from(self.min_years);
+        let max_ok = self.max_years.is_none_or(|max| age <= i64::from(max));
+        min_ok && max_ok
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: ChronoNow + Datelike> std::fmt::Display for AgeValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.max_years {
+            Some(max) => write!(
+                f,
+                "age {} must be between {} and {}",
+                self.age, self.min_years, max
+            ),
+            None => write!(f, "age {} must be at least {}", self.age, self.min_years),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use koruma::{Validate, validator};
+
+use super::ChronoDate;
+
+/// The bound a date failed in [`DateRangeValidation`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum DateBound {
+    /// The date is before `min`.
+    Min,
+    /// The date is after `max`.
+    Max,
+}
+
+impl DateBound {
+    /// Returns the Fluent selector key for this bound (`min` or `max`).
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+}
+
+/// Returns the bound `actual` violates, or `None` if it is within range.
+fn violated_bound<T: ChronoDate>(
+    actual: &T,
+    min: Option<&T>,
+    max: Option<&T>,
+    allow_equal: bool,
+) -> Option<DateBound> {
+    let after = |a: &T, b: &T| if allow_equal { a >= b } else { a > b };
+    if min.is_some_and(|min| !after(actual, min)) {
+        Some(DateBound::Min)
+    } else if max.is_some_and(|max| !after(max, actual)) {
+        Some(DateBound::Max)
+    } else {
+        None
+    }
+}
+
+/// Date range validation for koruma.
+///
+///
+/// # Example
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koruma::Koruma;
+/// use koruma_collection::time::{DateBound, DateRangeValidation};
+///
+/// #[derive(Koruma)]
+/// struct Booking {
+///     #[koruma(DateRangeValidation::<_>(
+///         min = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///         max = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+///     ))]
+///     check_in: NaiveDate,
+/// }
+///
+/// let booking = Booking { check_in: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap() };
+/// let err = booking.validate().unwrap_err();
+/// let failure = err.check_in().date_range_validation().unwrap();
+/// assert_eq!(failure.violated, Some(DateBound::Max));
+/// assert_eq!(failure.to_string(), "date 2026-03-01 must not be after 2025-12-31");
+/// ```
+///
+/// Validates that a date or timestamp falls within `[min, max]`.
+///
+/// Either bound may be omitted. Bounds are inclusive by default; set
+/// `allow_equal = false` to reject dates equal to a bound. The bound that
+/// was violated is kept in `violated`.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Date Range 2025",
+    description = "Validates that the input is a YYYY-MM-DD date in 2025",
+    try_create = |input: &str| {
+        let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .map_err(|e| format!("expected a YYYY-MM-DD date: {e}"))?;
+        Ok(DateRangeValidation::builder()
+            .min(chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+            .max(chrono::NaiveDate::from_ymd_opt(2025, 12, 31).unwrap())
+            .with_value(date)
+            .build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct DateRangeValidation<T: ChronoDate> {
+    /// Earliest allowed date, if any
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<T>| {
+        x.as_ref().map_or_else(String::new, ToString::to_string)
+    })))]
+    pub min: Option<T>,
+    /// Latest allowed date, if any
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<T>| {
+        x.as_ref().map_or_else(String::new, ToString::to_string)
+    })))]
+    pub max: Option<T>,
+    /// Whether a date equal to a bound is allowed (default `true`)
+    #[builder(default = true)]
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub allow_equal: bool,
+    /// The date being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// The bound `actual` violates, or `None` if it is within range
+    #[builder(skip = violated_bound(&actual, min.as_ref(), max.as_ref(), allow_equal))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &Option<DateBound>| {
+        x.map_or("none", DateBound::key).to_string()
+    })))]
+    pub violated: Option<DateBound>,
+}
+
+impl<T: ChronoDate> Validate<T> for DateRangeValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        violated_bound(
+            value,
+            self.min.as_ref(),
+            self.max.as_ref(),
+            self.allow_equal,
+        )
+        .is_none()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: ChronoDate> std::fmt::Display for DateRangeValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (requirement, bound) = match (self.violated, &self.min, &self.max) {
+            (Some(DateBound::Min), Some(min), _) if self.allow_equal => ("not be before", min),
+            (Some(DateBound::Min), Some(min), _) => ("be after", min),
+            (Some(DateBound::Max), _, Some(max)) if self.allow_equal => ("not be after", max),
+            (Some(DateBound::Max), _, Some(max)) => ("be before", max),
+            _ => return write!(f, "date {} is out of range", self.actual),
+        };
+        write!(f, "date {} must {} {}", self.actual, requirement, bound)
+    }
+}
@@ -0,0 +1,61 @@
+//! Date and time validation validators.
+//!
+//! This module contains validators for [`chrono`] dates and timestamps. It is
+//! only available with the `chrono` feature.
+
+use std::fmt::Display;
+
+/// Trait for chrono types that can be validated against date bounds.
+///
+/// Implemented for [`NaiveDate`](chrono::NaiveDate),
+/// [`NaiveDateTime`](chrono::NaiveDateTime) and
+/// [`DateTime<Tz>`](chrono::DateTime).
+pub trait ChronoDate: PartialOrd + Clone + Display {}
+
+impl ChronoDate for chrono::NaiveDate {}
+impl ChronoDate for chrono::NaiveDateTime {}
+impl<Tz: chrono::TimeZone> ChronoDate for chrono::DateTime<Tz> where Tz::Offset: Display {}
+
+/// Trait for chrono types that can be compared against the current time.
+///
+/// Implemented for [`NaiveDate`](chrono::NaiveDate),
+/// [`NaiveDateTime`](chrono::NaiveDateTime) and
+/// [`DateTime<Utc>`](chrono::DateTime); naive types are read in UTC.
+pub trait ChronoNow: ChronoDate {
+    /// Converts a UTC timestamp into this type.
+    fn from_utc(now: &chrono::DateTime<chrono::Utc>) -> Self;
+}
+
+impl ChronoNow for chrono::NaiveDate {
+    fn from_utc(now: &chrono::DateTime<chrono::Utc>) -> Self {
+        now.date_naive()
+    }
+}
+
+impl ChronoNow for chrono::NaiveDateTime {
+    fn from_utc(now: &chrono::DateTime<chrono::Utc>) -> Self {
+        now.naive_utc()
+    }
+}
+
+impl ChronoNow for chrono::DateTime<chrono::Utc> {
+    fn from_utc(now: &chrono::DateTime<chrono::Utc>) -> Self {
+        *now
+    }
+}
+
+/// Returns `reference` converted to `T`, or the current time if unset.
+fn now_or<T: ChronoNow>(reference: Option<&chrono::DateTime<chrono::Utc>>) -> T {
+    match reference {
+        Some(reference) => T::from_utc(reference),
+        None => T::from_utc(&chrono::Utc::now()),
+    }
+}
+
+mod age;
+mod date_range;
+mod relative;
+
+pub use age::AgeValidation;
+pub use date_range::{DateBound, DateRangeValidation};
+pub use relative::{NotInFutureValidation, NotInPastValidation};
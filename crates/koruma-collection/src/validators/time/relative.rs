@@ -0,0 +1,149 @@
+use koruma::{Validate, validator};
+
+use super::{ChronoNow, now_or};
+
+/// Not-in-future validation for koruma.
+///
+/// # Example
+/// ```rust
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use koruma::Koruma;
+/// use koruma_collection::time::NotInFutureValidation;
+///
+/// #[derive(Koruma)]
+/// struct Person {
+///     #[koruma(NotInFutureValidation::<_>)]
+///     birthdate: NaiveDate,
+/// }
+///
+/// let person = Person { birthdate: NaiveDate::from_ymd_opt(1990, 5, 17).unwrap() };
+/// assert!(person.validate().is_ok());
+///
+/// // Pin "now" for deterministic checks
+/// let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+/// let check = NotInFutureValidation::builder()
+///     .reference(now)
+///     .with_value(NaiveDate::from_ymd_opt(2025, 6, 2).unwrap())
+///     .build();
+/// assert!(!koruma::Validate::validate(&check, &check.actual));
+/// assert_eq!(check.now, NaiveDate::from_ymd_opt(2025, 6, 1).unwrap());
+/// assert_eq!(check.to_string(), "date 2025-06-02 must not be after 2025-06-01");
+/// ```
+///
+/// Validates that a date or timestamp is not later than the current time.
+///
+/// "Now" is read from [`Utc::now`](chrono::Utc::now) when the validator is
+/// built, unless `reference` pins it to a fixed time. Naive dates and
+/// timestamps are compared in UTC. A value equal to now is valid.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Not In Future",
+    description = "Validates that the input is a YYYY-MM-DD date no later than today",
+    try_create = |input: &str| {
+        let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .map_err(|e| format!("expected a YYYY-MM-DD date: {e}"))?;
+        Ok(NotInFutureValidation::builder().with_value(date).build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct NotInFutureValidation<T: ChronoNow> {
+    /// Fixed time to use as "now" instead of the wall clock
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub reference: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// The time compared against: `reference`, or the current time
+    #[builder(skip = now_or(reference.as_ref()))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub now: T,
+}
+
+impl<T: ChronoNow> Validate<T> for NotInFutureValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        *value <= self.now
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: ChronoNow> std::fmt::Display for NotInFutureValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "date {} must not be after {}", self.actual, self.now)
+    }
+}
+
+/// Not-in-past validation for koruma.
+///
+/// # Example
+/// ```rust
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use koruma::Koruma;
+/// use koruma_collection::time::NotInPastValidation;
+///
+/// #[derive(Koruma)]
+/// struct Appointment {
+///     #[koruma(NotInPastValidation::<_>)]
+///     starts_at: DateTime<Utc>,
+/// }
+///
+/// let appointment = Appointment { starts_at: Utc.with_ymd_and_hms(2001, 1, 1, 9, 0, 0).unwrap() };
+/// let err = appointment.validate().unwrap_err();
+/// assert!(err.starts_at().not_in_past_validation().is_some());
+///
+/// // Pin "now" for deterministic checks
+/// let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+/// let check = NotInPastValidation::builder()
+///     .reference(now)
+///     .with_value(Utc.with_ymd_and_hms(2025, 6, 1, 13, 30, 0).unwrap())
+///     .build();
+/// assert!(koruma::Validate::validate(&check, &check.actual));
+/// ```
+///
+/// Validates that a date or timestamp is not earlier than the current time.
+///
+/// "Now" is read from [`Utc::now`](chrono::Utc::now) when the validator is
+/// built, unless `reference` pins it to a fixed time. Naive dates and
+/// timestamps are compared in UTC. A value equal to now is valid, so a
+/// [`NaiveDate`](chrono::NaiveDate) of today passes.
+#[validator(deferred_value)]
+#[cfg_attr(feature = "showcase", showcase(
+    name = "Not In Past",
+    description = "Validates that the input is a YYYY-MM-DD date no earlier than today",
+    try_create = |input: &str| {
+        let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .map_err(|e| format!("expected a YYYY-MM-DD date: {e}"))?;
+        Ok(NotInPastValidation::builder().with_value(date).build())
+    }
+))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "fluent", derive(es_fluent::EsFluent))]
+pub struct NotInPastValidation<T: ChronoNow> {
+    /// Fixed time to use as "now" instead of the wall clock
+    #[cfg_attr(feature = "fluent", fluent(skip))]
+    pub reference: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date being validated (stored for error context)
+    #[koruma(value)]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub actual: T,
+    /// The time compared against: `reference`, or the current time
+    #[builder(skip = now_or(reference.as_ref()))]
+    #[cfg_attr(feature = "fluent", fluent(value(|x: &T| x.to_string())))]
+    pub now: T,
+}
+
+impl<T: ChronoNow> Validate<T> for NotInPastValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        *value >= self.now
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<T: ChronoNow> std::fmt::Display for NotInPastValidation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "date {} must not be before {}", self.actual, self.now)
+    }
+}
@@ -0,0 +1,164 @@
+//! Tests for the fill ratio bounds of `LenValidation`.
+
+use koruma::Koruma;
+use koruma_collection::collection::{HasCapacity, HasLen, LenValidation};
+
+/// A fixed-capacity buffer whose capacity is chosen at runtime.
+#[derive(Clone, Debug)]
+struct RingBuffer {
+    items: Vec<u8>,
+    capacity: usize,
+}
+
+impl RingBuffer {
+    fn holding(len: usize, capacity: usize) -> Self {
+        Self {
+            items: vec![0; len],
+            capacity,
+        }
+    }
+}
+
+impl HasLen for RingBuffer {
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl HasCapacity for RingBuffer {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[derive(Koruma)]
+struct Sampler {
+    #[koruma(LenValidation::<_>(min = 1, max = 8, min_fill_ratio = 0.25, max_fill_ratio = 0.75))]
+    buffer: RingBuffer,
+}
+
+#[test]
+fn test_custom_capacity_within_bounds() {
+    for len in [1, 2, 3] {
+        let sampler = Sampler {
+            buffer: RingBuffer::holding(len, 4),
+        };
+        assert!(sampler.validate().is_ok(), "{len} of 4 should pass");
+    }
+}
+
+#[test]
+fn test_custom_capacity_outside_bounds() {
+    let err = Sampler {
+        buffer: RingBuffer::holding(4, 4),
+    }
+    .validate()
+    .unwrap_err();
+    let len_err = err.buffer().len_validation().unwrap();
+    assert_eq!(len_err.actual_fill_ratio, Some(1.0));
+    #[cfg(feature = "fmt")]
+    assert_eq!(
+        len_err.to_string(),
+        "fill ratio 1 is not within bounds [0.25, 0.75]"
+    );
+
+    let err = Sampler {
+        buffer: RingBuffer::holding(1, 8),
+    }
+    .validate()
+    .unwrap_err();
+    let len_err = err.buffer().len_validation().unwrap();
+    assert_eq!(len_err.actual_fill_ratio, Some(0.125));
+}
+
+#[test]
+fn test_length_failure_is_reported_before_fill_ratio() {
+    let err = Sampler {
+        buffer: RingBuffer::holding(0, 0),
+    }
+    .validate()
+    .unwrap_err();
+    let len_err = err.buffer().len_validation().unwrap();
+    assert_eq!(len_err.actual_fill_ratio, Some(0.0));
+    #[cfg(feature = "fmt")]
+    assert_eq!(len_err.to_string(), "length 0 is not within bounds [1, 8]");
+}
+
+#[test]
+fn test_no_fill_ratio_without_bounds() {
+    #[derive(Koruma)]
+    struct Unbounded {
+        #[koruma(LenValidation::<_>(min = 2, max = 8))]
+        buffer: RingBuffer,
+    }
+
+    let err = Unbounded {
+        buffer: RingBuffer::holding(1, 4),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        err.buffer().len_validation().unwrap().actual_fill_ratio,
+        None
+    );
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec_fill_ratio() {
+    use smallvec::SmallVec;
+
+    #[derive(Koruma)]
+    struct Batch {
+        #[koruma(LenValidation::<_>(min = 0, max = 16, max_fill_ratio = 0.75))]
+        items: SmallVec<[u8; 4]>,
+    }
+
+    let inline = Batch {
+        items: SmallVec::from_slice(&[1, 2, 3]),
+    };
+    assert!(!inline.items.spilled());
+    assert!(inline.validate().is_ok());
+
+    // A spilled vector counts as full rather than over its inline capacity
+    let spilled = Batch {
+        items: SmallVec::from_slice(&[1, 2, 3, 4, 5, 6]),
+    };
+    assert!(spilled.items.spilled());
+    assert_eq!(spilled.items.fill_ratio(), 1.0);
+    let err = spilled.validate().unwrap_err();
+    assert_eq!(
+        err.items().len_validation().unwrap().actual_fill_ratio,
+        Some(1.0)
+    );
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn test_fill_ratio_fluent_message() {
+    use es_fluent::ToFluentString as _;
+    use koruma::KorumaAllFluent;
+    use unic_langid::langid;
+
+    #[derive(Koruma, KorumaAllFluent)]
+    struct Pool {
+        #[koruma(LenValidation::<_>(min = 0, max = 8, max_fill_ratio = 0.5))]
+        buffer: RingBuffer,
+    }
+
+    es_fluent_manager_embedded::init();
+    es_fluent_manager_embedded::select_language(langid!("en"));
+
+    let err = Pool {
+        buffer: RingBuffer::holding(3, 4),
+    }
+    .validate()
+    .unwrap_err();
+    let message = err.buffer().all()[0]
+        .to_fluent_string()
+        .replace(['\u{2068}', '\u{2069}'], "");
+    assert_eq!(
+        message,
+        "The length must be between 0 and 8 items, filling between 0 and 0.5 of the capacity (actual: 3, fill ratio 0.75)."
+    );
+}
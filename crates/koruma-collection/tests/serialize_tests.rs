@@ -0,0 +1,165 @@
+//! Tests for the serialized shape of errors from the collection validators.
+#![cfg(feature = "serde")]
+
+use koruma::{KeyedError, Koruma};
+use koruma_collection::{collection::LenValidation, numeric::RangeValidation};
+
+#[derive(Koruma)]
+struct Address {
+    #[koruma(LenValidation::<_>(min = 5, max = 5))]
+    zip_code: String,
+}
+
+#[derive(Koruma)]
+#[koruma(cross_field(|this: &Self| {
+    if this.password == this.confirm_password {
+        Ok(())
+    } else {
+        Err(KeyedError::new("passwords_match", "passwords do not match"))
+    }
+}))]
+struct Signup {
+    #[koruma(LenValidation::<_>(min = 3, max = 20))]
+    username: String,
+
+    #[koruma(each(LenValidation::<_>(min = 1, max = 10)))]
+    tags: Vec<String>,
+
+    #[koruma(RangeValidation::<_>(min = 13, max = 130))]
+    age: u8,
+
+    #[koruma(nested)]
+    address: Address,
+
+    password: String,
+
+    confirm_password: String,
+}
+
+#[test]
+fn test_validator_parameters_are_serialized() {
+    let err = Signup {
+        username: "al".to_string(),
+        tags: vec!["rust".to_string(), "a-very-long-tag".to_string()],
+        age: 7,
+        address: Address {
+            zip_code: "123".to_string(),
+        },
+        password: "hunter22".to_string(),
+        confirm_password: "hunter2".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let mut json = serde_json::to_value(&err).unwrap();
+    // Messages are covered by the validators' own tests
+    strip_messages(&mut json);
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "username": {
+                "len_validation": {
+                    "min": 3,
+                    "max": 20,
+                    "mode": "bytes",
+                    "min_fill_ratio": null,
+                    "max_fill_ratio": null,
+                    "actual": "al",
+                    "actual_len": 2,
+                    "actual_fill_ratio": null
+                }
+            },
+            "tags": {
+                "elements": [
+                    {
+                        "index": 1,
+                        "len_validation": {
+                            "min": 1,
+                            "max": 10,
+                            "mode": "bytes",
+                            "min_fill_ratio": null,
+                            "max_fill_ratio": null,
+                            "actual": "a-very-long-tag",
+                            "actual_len": 15,
+                            "actual_fill_ratio": null
+                        }
+                    }
+                ]
+            },
+            "age": {
+                "range_validation": {
+                    "min": 13,
+                    "exclusive_min": false,
+                    "max": 130,
+                    "exclusive_max": false,
+                    "actual": 7
+                }
+            },
+            "address": {
+                "zip_code": {
+                    "len_validation": {
+                        "min": 5,
+                        "max": 5,
+                        "mode": "bytes",
+                        "min_fill_ratio": null,
+                        "max_fill_ratio": null,
+                        "actual": "123",
+                        "actual_len": 3,
+                        "actual_fill_ratio": null
+                    }
+                }
+            },
+            "cross_field": { "passwords_match": "passwords do not match" }
+        })
+    );
+}
+
+#[cfg(feature = "fmt")]
+#[test]
+fn test_message_is_the_validator_display() {
+    let err = Address {
+        zip_code: "123".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        json["zip_code"]["len_validation"]["message"],
+        "length 3 is not within bounds [5, 5]"
+    );
+}
+
+#[test]
+fn test_secrets_are_not_serialized() {
+    use koruma_collection::security::PasswordStrengthValidation;
+
+    #[derive(Koruma)]
+    struct Credentials {
+        #[koruma(PasswordStrengthValidation::<_>(min_length = 12))]
+        password: String,
+    }
+
+    let err = Credentials {
+        password: "hunter2".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let json = serde_json::to_value(&err).unwrap();
+    let failure = &json["password"]["password_strength_validation"];
+    assert_eq!(failure["min_length"], 12);
+    assert!(failure.get("actual").is_none());
+    assert!(!json.to_string().contains("hunter2"));
+}
+
+fn strip_messages(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("message");
+            map.values_mut().for_each(strip_messages);
+        },
+        serde_json::Value::Array(values) => values.iter_mut().for_each(strip_messages),
+        _ => {},
+    }
+}
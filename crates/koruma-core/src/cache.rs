@@ -0,0 +1,143 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::Validate;
+
+/// Memoizes the results of an expensive validator, keyed by a hash of the value.
+///
+/// Useful when the same values recur across many records and the validator is
+/// pure: the wrapped validator only runs the first time a value is seen, later
+/// calls with an equal value reuse the stored result. Only use it for
+/// validators whose outcome depends solely on the value.
+///
+/// The cache holds at most `capacity` results; once full, the oldest entry is
+/// evicted. Values are identified by their 64-bit hash rather than stored, so
+/// caching a long string doesn't keep a copy of it around.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::{Cached, Validate};
+///
+/// struct NoDigits;
+///
+/// impl Validate<String> for NoDigits {
+///     fn validate(&self, value: &String) -> bool {
+///         !value.chars().any(|c| c.is_ascii_digit())
+///     }
+/// }
+///
+/// let cached = Cached::new(NoDigits);
+/// let value = "abc".to_string();
+/// assert!(cached.validate(&value));
+/// assert!(cached.validate(&value)); // served from the cache
+/// assert_eq!(cached.len(), 1);
+/// ```
+pub struct Cached<V, T: ?Sized> {
+    inner: V,
+    capacity: usize,
+    state: Mutex<CacheState>,
+    _value: PhantomData<fn(&T)>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    results: HashMap<u64, bool>,
+    order: VecDeque<u64>,
+}
+
+impl<V, T: ?Sized> Cached<V, T> {
+    /// Default number of results kept by [`Cached::new`].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Wraps `inner` with a cache of [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY) entries.
+    pub fn new(inner: V) -> Self {
+        Self::with_capacity(inner, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Wraps `inner` with a cache holding at most `capacity` results.
+    ///
+    /// A capacity of `0` disables caching.
+    pub fn with_capacity(inner: V, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            state: Mutex::new(CacheState::default()),
+            _value: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped validator.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.lock().results.len()
+    }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every cached result.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.results.clear();
+        state.order.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        // A panic inside the wrapped validator can't leave the map half-updated,
+        // so a poisoned lock is still safe to use.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<V, T> Validate<T> for Cached<V, T>
+where
+    V: Validate<T>,
+    T: Hash,
+{
+    fn validate(&self, value: &T) -> bool {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let key = hasher.finish();
+
+        if let Some(&result) = self.lock().results.get(&key) {
+            return result;
+        }
+
+        // Run the validator without holding the lock so concurrent callers
+        // aren't serialized behind a slow validation.
+        let result = self.inner.validate(value);
+
+        if self.capacity > 0 {
+            let mut state = self.lock();
+            if state.results.insert(key, result).is_none() {
+                state.order.push_back(key);
+                if state.order.len() > self.capacity
+                    && let Some(oldest) = state.order.pop_front()
+                {
+                    state.results.remove(&oldest);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<V: std::fmt::Debug, T: ?Sized> std::fmt::Debug for Cached<V, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cached")
+            .field("inner", &self.inner)
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
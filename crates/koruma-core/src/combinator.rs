@@ -0,0 +1,399 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::{BuilderWithValue, StoredValue, Validate};
+
+/// A child validator of [`AllOfValidation`] or [`AnyOfValidation`].
+pub type BoxedValidator<T> = Box<dyn Validate<T> + Send + Sync>;
+
+/// Children are shared so the combinators stay `Clone`, as error structs
+/// require of every validator.
+type SharedValidator<T> = Arc<dyn Validate<T> + Send + Sync>;
+
+/// Indices of the children that reject `value`.
+fn failing_children<T>(validators: &[SharedValidator<T>], value: Option<&T>) -> Vec<usize> {
+    let Some(value) = value else {
+        return Vec::new();
+    };
+    validators
+        .iter()
+        .enumerate()
+        .filter(|(_, validator)| !validator.validate(value))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+macro_rules! combinator {
+    ($(#[$doc:meta])* $name:ident, $builder:ident) => {
+        $(#[$doc])*
+        pub struct $name<T> {
+            validators: Vec<SharedValidator<T>>,
+            actual: Option<T>,
+        }
+
+        impl<T> $name<T> {
+            /// Combines the boxed `validators`.
+            pub fn new(validators: Vec<BoxedValidator<T>>) -> Self {
+                Self {
+                    validators: validators.into_iter().map(Arc::from).collect(),
+                    actual: None,
+                }
+            }
+
+            /// Starts a builder, the form `#[koruma(...)]` constructs validators with.
+            pub fn builder() -> $builder<T> {
+                $builder {
+                    validators: Vec::new(),
+                    actual: None,
+                }
+            }
+
+            /// Returns the number of child validators.
+            pub fn len(&self) -> usize {
+                self.validators.len()
+            }
+
+            /// Returns `true` if there are no child validators.
+            pub fn is_empty(&self) -> bool {
+                self.validators.is_empty()
+            }
+
+            /// Returns the indices of the children that reject the stored
+            /// value, in declaration order. Empty if no value was stored.
+            pub fn failures(&self) -> Vec<usize> {
+                failing_children(&self.validators, self.actual.as_ref())
+            }
+        }
+
+        impl<T: Clone> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                Self {
+                    validators: self.validators.clone(),
+                    actual: self.actual.clone(),
+                }
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("validators", &self.validators.len())
+                    .field("actual", &self.actual)
+                    .finish()
+            }
+        }
+
+        impl<T> StoredValue for $name<T> {
+            type Value = Option<T>;
+
+            fn stored_value(&self) -> &Option<T> {
+                &self.actual
+            }
+        }
+
+        #[doc = concat!("Builder for [`", stringify!($name), "`].")]
+        pub struct $builder<T> {
+            validators: Vec<SharedValidator<T>>,
+            actual: Option<T>,
+        }
+
+        impl<T> $builder<T> {
+            /// Adds the boxed `validators` as children.
+            pub fn validators(mut self, validators: Vec<BoxedValidator<T>>) -> Self {
+                self.validators
+                    .extend(validators.into_iter().map(SharedValidator::from));
+                self
+            }
+
+            /// Adds `validator` as a child.
+            pub fn validator<V>(mut self, validator: V) -> Self
+            where
+                V: Validate<T> + Send + Sync + 'static,
+            {
+                self.validators.push(Arc::new(validator));
+                self
+            }
+
+            /// Stores the validated value, used by
+            #[doc = concat!("[`", stringify!($name), "::failures`].")]
+            pub fn with_value(mut self, value: T) -> Self {
+                self.actual = Some(value);
+                self
+            }
+
+            /// Builds the combinator.
+            pub fn build(self) -> $name<T> {
+                $name {
+                    validators: self.validators,
+                    actual: self.actual,
+                }
+            }
+        }
+
+        impl<T> BuilderWithValue<T> for $builder<T> {
+            fn with_value(self, value: T) -> Self {
+                $builder::with_value(self, value)
+            }
+        }
+    };
+}
+
+combinator!(
+    /// Passes only if every child validator passes.
+    ///
+    /// Composes existing validators without a new validator type. With no
+    /// children it always passes. When it fails,
+    /// [`failures`](Self::failures) lists the children that rejected the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koruma_core::{AllOfValidation, Validate};
+    ///
+    /// struct Positive;
+    /// impl Validate<i32> for Positive {
+    ///     fn validate(&self, value: &i32) -> bool {
+    ///         *value > 0
+    ///     }
+    /// }
+    ///
+    /// struct Even;
+    /// impl Validate<i32> for Even {
+    ///     fn validate(&self, value: &i32) -> bool {
+    ///         value % 2 == 0
+    ///     }
+    /// }
+    ///
+    /// let all = AllOfValidation::new(vec![Box::new(Positive), Box::new(Even)]);
+    /// assert!(all.validate(&4));
+    /// assert!(!all.validate(&3));
+    ///
+    /// let failed = AllOfValidation::builder()
+    ///     .validator(Positive)
+    ///     .validator(Even)
+    ///     .with_value(-3)
+    ///     .build();
+    /// assert_eq!(failed.failures(), vec![0, 1]);
+    /// assert_eq!(failed.to_string(), "failed 2 of 2 required checks");
+    /// ```
+    AllOfValidation,
+    AllOfValidationBuilder
+);
+
+combinator!(
+    /// Passes if at least one child validator passes.
+    ///
+    /// Composes existing validators without a new validator type, e.g. to
+    /// accept any of several formats. With no children it always fails. When
+    /// it fails, [`failures`](Self::failures) lists every child, since all of
+    /// them rejected the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use koruma_core::{AnyOfValidation, Validate};
+    ///
+    /// struct Digits;
+    /// impl Validate<String> for Digits {
+    ///     fn validate(&self, value: &String) -> bool {
+    ///         !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+    ///     }
+    /// }
+    ///
+    /// struct Lowercase;
+    /// impl Validate<String> for Lowercase {
+    ///     fn validate(&self, value: &String) -> bool {
+    ///         !value.is_empty() && value.chars().all(|c| c.is_ascii_lowercase())
+    ///     }
+    /// }
+    ///
+    /// let any = AnyOfValidation::new(vec![Box::new(Digits), Box::new(Lowercase)]);
+    /// assert!(any.validate(&"123".to_string()));
+    /// assert!(any.validate(&"abc".to_string()));
+    /// assert!(!any.validate(&"Abc".to_string()));
+    /// ```
+    AnyOfValidation,
+    AnyOfValidationBuilder
+);
+
+impl<T> Validate<T> for AllOfValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        self.validators
+            .iter()
+            .all(|validator| validator.validate(value))
+    }
+}
+
+impl<T> Validate<T> for AnyOfValidation<T> {
+    fn validate(&self, value: &T) -> bool {
+        self.validators
+            .iter()
+            .any(|validator| validator.validate(value))
+    }
+}
+
+impl<T> fmt::Display for AllOfValidation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed {} of {} required checks",
+            self.failures().len(),
+            self.len()
+        )
+    }
+}
+
+impl<T> fmt::Display for AnyOfValidation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed all {} alternative checks", self.len())
+    }
+}
+
+/// Passes exactly when the wrapped validator fails.
+///
+/// Inverts an existing validator, e.g. "must not be a valid email" for a
+/// honeypot field. The failed validator keeps the wrapped one, so
+/// [`inner`](Self::inner) and the `Display` message can refer to it. It stores
+/// the validated value as the wrapped validator's [`StoredValue::Value`].
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::{NotValidation, StoredValue, Validate};
+///
+/// struct Even(i32);
+/// impl Validate<i32> for Even {
+///     fn validate(&self, value: &i32) -> bool {
+///         value % 2 == 0
+///     }
+/// }
+/// impl StoredValue for Even {
+///     type Value = i32;
+///     fn stored_value(&self) -> &i32 {
+///         &self.0
+///     }
+/// }
+///
+/// let odd = NotValidation::builder().validator(Even(0)).with_value(4).build();
+/// assert!(odd.validate(&3));
+/// assert!(!odd.validate(&4));
+/// assert_eq!(odd.stored_value(), &Some(4));
+/// ```
+pub struct NotValidation<V: StoredValue> {
+    inner: V,
+    actual: Option<V::Value>,
+}
+
+impl<V: StoredValue> NotValidation<V> {
+    /// Wraps `inner`.
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            actual: None,
+        }
+    }
+
+    /// Starts a builder, the form `#[koruma(...)]` constructs validators with.
+    /// It can only build once [`validator`](NotValidationBuilder::validator)
+    /// has set the validator to invert.
+    pub fn builder() -> NotValidationBuilder<V> {
+        NotValidationBuilder {
+            inner: (),
+            actual: None,
+            _validator: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped validator.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+}
+
+impl<V: StoredValue + Clone> Clone for NotValidation<V>
+where
+    V::Value: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            actual: self.actual.clone(),
+        }
+    }
+}
+
+impl<V: StoredValue + fmt::Debug> fmt::Debug for NotValidation<V>
+where
+    V::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotValidation")
+            .field("inner", &self.inner)
+            .field("actual", &self.actual)
+            .finish()
+    }
+}
+
+impl<V: Validate<T> + StoredValue, T> Validate<T> for NotValidation<V> {
+    fn validate(&self, value: &T) -> bool {
+        !self.inner.validate(value)
+    }
+}
+
+impl<V: StoredValue> StoredValue for NotValidation<V> {
+    type Value = Option<V::Value>;
+
+    fn stored_value(&self) -> &Option<V::Value> {
+        &self.actual
+    }
+}
+
+impl<V: StoredValue + fmt::Display> fmt::Display for NotValidation<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected value to fail: {}", self.inner)
+    }
+}
+
+/// Builder for [`NotValidation`]. `I` is `V` once the validator to invert
+/// is set, and only then can it build.
+pub struct NotValidationBuilder<V: StoredValue, I = ()> {
+    inner: I,
+    actual: Option<V::Value>,
+    _validator: PhantomData<fn() -> V>,
+}
+
+impl<V: StoredValue> NotValidationBuilder<V> {
+    /// Sets the validator to invert.
+    pub fn validator(self, validator: V) -> NotValidationBuilder<V, V> {
+        NotValidationBuilder {
+            inner: validator,
+            actual: self.actual,
+            _validator: PhantomData,
+        }
+    }
+}
+
+impl<V: StoredValue, I> NotValidationBuilder<V, I> {
+    /// Stores the validated value.
+    pub fn with_value(mut self, value: V::Value) -> Self {
+        self.actual = Some(value);
+        self
+    }
+}
+
+impl<V: StoredValue> NotValidationBuilder<V, V> {
+    /// Builds the inverted validator.
+    pub fn build(self) -> NotValidation<V> {
+        NotValidation {
+            inner: self.inner,
+            actual: self.actual,
+        }
+    }
+}
+
+impl<V: StoredValue, I> BuilderWithValue<V::Value> for NotValidationBuilder<V, I> {
+    fn with_value(self, value: V::Value) -> Self {
+        NotValidationBuilder::with_value(self, value)
+    }
+}
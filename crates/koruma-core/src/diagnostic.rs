@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::{Failure, Severity, ValidationMessages};
+
+/// A single field failure, reported as a `miette` diagnostic.
+///
+/// Rendered as one of the [`related`](miette::Diagnostic::related)
+/// diagnostics of a [`ValidationDiagnostic`]. Validated values are runtime
+/// data with no source text, so there are no labels: the field path leads
+/// the message instead.
+#[derive(Clone, Debug)]
+pub struct FieldDiagnostic {
+    failure: Failure,
+    message: String,
+    code: String,
+}
+
+impl FieldDiagnostic {
+    /// Returns the failure this diagnostic reports.
+    pub fn failure(&self) -> &Failure {
+        &self.failure
+    }
+
+    /// Returns the failure's message, without the field path.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for FieldDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.failure.path(), self.message)
+    }
+}
+
+impl std::error::Error for FieldDiagnostic {}
+
+impl miette::Diagnostic for FieldDiagnostic {
+    /// `koruma::` followed by the snake_case validator name
+    /// (e.g. `koruma::range_validation`).
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(&self.code))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.failure.severity() {
+            Severity::Error => miette::Severity::Error,
+            Severity::Warning => miette::Severity::Warning,
+        })
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.failure
+            .help()
+            .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+}
+
+/// A validation error reported as a `miette` diagnostic, with one
+/// [`FieldDiagnostic`] per failure as its related diagnostics.
+///
+/// Created with [`ValidationMessages::to_diagnostic`], so any error deriving
+/// `KorumaAllDisplay` can be returned from a function using
+/// `miette::Result`.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::{Failure, FieldPath, ValidationError, ValidationMessages};
+/// use miette::Diagnostic;
+///
+/// struct AgeError;
+///
+/// impl ValidationError for AgeError {
+///     fn is_empty(&self) -> bool {
+///         false
+///     }
+///
+///     fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+///         std::iter::once(Failure::new(FieldPath::field("age"), "range_validation"))
+///     }
+/// }
+///
+/// impl ValidationMessages for AgeError {
+///     fn failure_messages(&self) -> Vec<(Failure, String)> {
+///         self.iter_failures()
+///             .map(|failure| (failure, "must be between 0 and 150".to_string()))
+///             .collect()
+///     }
+/// }
+///
+/// let diagnostic = AgeError.to_diagnostic();
+/// assert_eq!(diagnostic.to_string(), "validation failed with 1 failure");
+///
+/// let related: Vec<String> = diagnostic.related().unwrap().map(|d| d.to_string()).collect();
+/// assert_eq!(related, ["age: must be between 0 and 150"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ValidationDiagnostic {
+    fields: Vec<FieldDiagnostic>,
+}
+
+impl ValidationDiagnostic {
+    /// Collects every failure of `errors` with its message.
+    pub fn new<E: ValidationMessages + ?Sized>(errors: &E) -> Self {
+        Self {
+            fields: errors
+                .failure_messages()
+                .into_iter()
+                .map(|(failure, message)| FieldDiagnostic {
+                    code: format!("koruma::{}", failure.validator()),
+                    failure,
+                    message,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the per-failure diagnostics, in failure order.
+    pub fn fields(&self) -> &[FieldDiagnostic] {
+        &self.fields
+    }
+}
+
+impl fmt::Display for ValidationDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.fields.len();
+        let plural = if count == 1 { "" } else { "s" };
+        write!(f, "validation failed with {} failure{}", count, plural)
+    }
+}
+
+impl std::error::Error for ValidationDiagnostic {}
+
+impl miette::Diagnostic for ValidationDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("koruma::validation"))
+    }
+
+    /// Warning if every failure is a warning, error otherwise.
+    fn severity(&self) -> Option<miette::Severity> {
+        let blocking = self.fields.iter().any(|field| field.failure.is_blocking());
+        Some(if blocking || self.fields.is_empty() {
+            miette::Severity::Error
+        } else {
+            miette::Severity::Warning
+        })
+    }
+
+    /// Lists the failing fields, each once, in failure order.
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let mut paths: Vec<String> = Vec::new();
+        for field in &self.fields {
+            let path = field.failure.path().to_string();
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        if paths.is_empty() {
+            return None;
+        }
+        Some(Box::new(format!("check {}", paths.join(", "))))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        Some(Box::new(
+            self.fields
+                .iter()
+                .map(|field| field as &dyn miette::Diagnostic),
+        ))
+    }
+}
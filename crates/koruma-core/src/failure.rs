@@ -0,0 +1,267 @@
+use std::fmt;
+
+/// A single segment of a [`FieldPath`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// A named struct field.
+    Field(&'static str),
+    /// The index of an element validated with `each(...)`.
+    Index(usize),
+    /// The key of a map entry validated with `entries(...)`.
+    Key(String),
+}
+
+/// Location of a failure inside a (possibly nested) validation error.
+///
+/// Displays as a dotted path with bracketed indices and keys, e.g.
+/// `address.street`, `scores[2]` or `settings[theme]`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldPath {
+    segments: Vec<PathSegment>,
+}
+
+impl FieldPath {
+    /// Creates a path pointing at a single top-level field.
+    pub fn field(name: &'static str) -> Self {
+        Self {
+            segments: vec![PathSegment::Field(name)],
+        }
+    }
+
+    /// Appends an element index to the path.
+    pub fn index(mut self, index: usize) -> Self {
+        self.segments.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Appends a map key to the path.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.segments.push(PathSegment::Key(key.into()));
+        self
+    }
+
+    /// Prepends a field name to the path.
+    pub fn prefixed(mut self, name: &'static str) -> Self {
+        self.segments.insert(0, PathSegment::Field(name));
+        self
+    }
+
+    /// Returns the segments of the path, outermost first.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Renders the path as an RFC 6901 JSON Pointer, e.g. `/address/zip_code`
+    /// or `/tags/2`.
+    ///
+    /// `~` and `/` inside a segment are escaped as `~0` and `~1`.
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.segments {
+            pointer.push('/');
+            match segment {
+                PathSegment::Field(name) => push_escaped(&mut pointer, name),
+                PathSegment::Index(index) => pointer.push_str(&index.to_string()),
+                PathSegment::Key(key) => push_escaped(&mut pointer, key),
+            }
+        }
+        pointer
+    }
+}
+
+/// Appends a JSON Pointer reference token, escaping `~` and `/`.
+fn push_escaped(pointer: &mut String, token: &str) {
+    for c in token.chars() {
+        match c {
+            '~' => pointer.push_str("~0"),
+            '/' => pointer.push_str("~1"),
+            c => pointer.push(c),
+        }
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) => write!(f, "[{key}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How serious a failure is.
+///
+/// Warnings are reported in the error struct but don't make `validate()`
+/// fail. Severities are ordered, so `Severity::Error > Severity::Warning`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    Warning,
+    #[default]
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Help attached to a failure with `help = "..."` or `help = fluent("...")`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Help {
+    /// Literal help text.
+    Text(&'static str),
+    /// A fluent message id, to be localized by the application.
+    Fluent(&'static str),
+}
+
+impl Help {
+    /// Returns the help text or fluent message id.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Help::Text(text) | Help::Fluent(text) => text,
+        }
+    }
+}
+
+/// A single failed validator, as reported by
+/// [`ValidationError::iter_failures`](crate::ValidationError::iter_failures).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Failure {
+    path: FieldPath,
+    validator: &'static str,
+    severity: Severity,
+    help: Option<Help>,
+    priority: i32,
+}
+
+impl Failure {
+    /// Creates an error-severity failure for the validator named `validator`
+    /// at `path`.
+    pub fn new(path: FieldPath, validator: &'static str) -> Self {
+        Self {
+            path,
+            validator,
+            severity: Severity::Error,
+            help: None,
+            priority: 0,
+        }
+    }
+
+    /// Sets the severity of the failure.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Attaches help to the failure.
+    pub fn with_help(mut self, help: Help) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    /// Sets the summary priority of the failure (higher sorts first).
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns where the failure occurred.
+    pub fn path(&self) -> &FieldPath {
+        &self.path
+    }
+
+    /// Returns the snake_case name of the failed validator
+    /// (e.g. `range_validation`).
+    pub fn validator(&self) -> &'static str {
+        self.validator
+    }
+
+    /// Returns the severity of the failure.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the help text (or fluent message id) attached to the validator,
+    /// if any.
+    pub fn help(&self) -> Option<&'static str> {
+        self.help.map(|help| help.as_str())
+    }
+
+    /// Returns the attached help, telling literal text and fluent message ids apart.
+    pub fn help_entry(&self) -> Option<Help> {
+        self.help
+    }
+
+    /// Returns the priority set with `priority = N` on the validator, `0` by default.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns `true` if the failure makes validation fail.
+    pub fn is_blocking(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Returns the failure with `name` prepended to its path.
+    ///
+    /// Used when lifting failures out of a nested error struct.
+    pub fn prefixed(mut self, name: &'static str) -> Self {
+        self.path = self.path.prefixed(name);
+        self
+    }
+}
+
+/// A failed cross-field check, identified by its key.
+///
+/// Returned by the closures of a struct-level `#[koruma(cross_field(...))]`
+/// and stored in the error struct's `cross_field_errors` map under `key`.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::KeyedError;
+///
+/// let error = KeyedError::new("passwords_match", "passwords don't match");
+/// assert_eq!(error.key(), "passwords_match");
+/// assert_eq!(error.to_string(), "passwords don't match");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct KeyedError {
+    key: &'static str,
+    message: String,
+}
+
+impl KeyedError {
+    /// Creates a cross-field error with the given key and message.
+    pub fn new(key: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            key,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the key naming the failed check (e.g. `passwords_match`).
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Returns the message describing the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for KeyedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
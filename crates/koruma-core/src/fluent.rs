@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use es_fluent::__manager_core::FluentManager;
+use es_fluent::ToFluentString;
+use es_fluent::unic_langid::LanguageIdentifier;
+
+/// One manager per language rendered through [`with_locale`], shared by all
+/// threads.
+static MANAGERS: Mutex<Vec<(LanguageIdentifier, Arc<FluentManager>)>> = Mutex::new(Vec::new());
+
+/// Whether [`with_locale`] managed to install es-fluent's custom localizer.
+static LOCALIZER_INSTALLED: OnceLock<bool> = OnceLock::new();
+
+thread_local! {
+    /// The manager for the locale this thread renders in, if overridden.
+    static LOCALE: RefCell<Option<Arc<FluentManager>>> = const { RefCell::new(None) };
+}
+
+fn manager_for(lang: &LanguageIdentifier) -> Arc<FluentManager> {
+    let mut managers = MANAGERS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, manager)) = managers.iter().find(|(known, _)| known == lang) {
+        return Arc::clone(manager);
+    }
+    let manager = Arc::new(FluentManager::new_with_discovered_modules());
+    manager.select_language(lang);
+    managers.push((lang.clone(), Arc::clone(&manager)));
+    manager
+}
+
+/// Looks `id` up in the thread's overridden locale. A miss returns `None`, so
+/// es-fluent goes on to its global context, fallback chain included.
+fn localize_in_thread_locale(
+    id: &str,
+    args: Option<&std::collections::HashMap<&str, es_fluent::FluentValue>>,
+) -> Option<String> {
+    LOCALE.with_borrow(Clone::clone)?.localize(id, args)
+}
+
+fn install_localizer() -> bool {
+    *LOCALIZER_INSTALLED.get_or_init(|| {
+        // es-fluent only reports an already-set localizer by panicking
+        std::panic::catch_unwind(|| es_fluent::set_custom_localizer(localize_in_thread_locale))
+            .is_ok()
+    })
+}
+
+/// Error returned by [`with_locale`] when the application has already set
+/// es-fluent's custom localizer, which the per-thread locale relies on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocalizerTaken;
+
+impl std::fmt::Display for LocalizerTaken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "es-fluent's custom localizer is already set, so the locale can't be overridden per thread"
+        )
+    }
+}
+
+impl std::error::Error for LocalizerTaken {}
+
+/// Restores the previous override when [`with_locale`] returns or unwinds.
+struct RestoreLocale(Option<Arc<FluentManager>>);
+
+impl Drop for RestoreLocale {
+    fn drop(&mut self) {
+        LOCALE.set(self.0.take());
+    }
+}
+
+/// Runs `f` with es-fluent messages rendered in `lang` on the current thread.
+///
+/// Unlike selecting a language in es-fluent, this leaves the global language
+/// and other threads alone, so concurrent requests can each render in their
+/// own locale. Overrides nest, and the previous one is restored when `f`
+/// returns. A message missing from `lang` is resolved like any other miss:
+/// through the fallback chain, if one is set, then the global language.
+///
+/// The first call installs es-fluent's custom localizer, which can only be
+/// set once. If the application has already set its own, `f` isn't run and
+/// [`LocalizerTaken`] is returned.
+///
+/// ```rust
+/// use es_fluent::unic_langid::LanguageIdentifier;
+/// use koruma_core::{LocalizerTaken, with_locale};
+///
+/// // The application set its own localizer first
+/// es_fluent::set_custom_localizer(|_, _| None);
+///
+/// let fr: LanguageIdentifier = "fr".parse().unwrap();
+/// assert_eq!(with_locale(&fr, || ()), Err(LocalizerTaken));
+/// ```
+pub fn with_locale<R>(
+    lang: &LanguageIdentifier,
+    f: impl FnOnce() -> R,
+) -> Result<R, LocalizerTaken> {
+    if !install_localizer() {
+        return Err(LocalizerTaken);
+    }
+    let _restore = RestoreLocale(LOCALE.replace(Some(manager_for(lang))));
+    Ok(f())
+}
+
+/// Renders a fluent message in a given locale without changing es-fluent's
+/// global language.
+///
+/// Implemented for every [`ToFluentString`] type, including the validators
+/// and the `{Struct}{Field}KorumaValidator` enums of `KorumaAllFluent`. See
+/// [`with_locale`].
+pub trait ToFluentStringIn {
+    /// Converts the value into a fluent string translated into `lang`.
+    ///
+    /// Fails like [`with_locale`] if the application has set its own
+    /// es-fluent custom localizer.
+    fn to_fluent_string_in(&self, lang: &LanguageIdentifier) -> Result<String, LocalizerTaken>;
+}
+
+impl<T: ToFluentString + ?Sized> ToFluentStringIn for T {
+    fn to_fluent_string_in(&self, lang: &LanguageIdentifier) -> Result<String, LocalizerTaken> {
+        with_locale(lang, || self.to_fluent_string())
+    }
+}
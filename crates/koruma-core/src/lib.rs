@@ -1,3 +1,35 @@
+mod cache;
+mod combinator;
+#[cfg(feature = "miette")]
+mod diagnostic;
+mod failure;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod log;
+mod redact;
+mod step;
+mod stream;
+mod validated;
+
+pub use cache::Cached;
+pub use combinator::{
+    AllOfValidation, AllOfValidationBuilder, AnyOfValidation, AnyOfValidationBuilder,
+    BoxedValidator, NotValidation, NotValidationBuilder,
+};
+#[cfg(feature = "miette")]
+pub use diagnostic::{FieldDiagnostic, ValidationDiagnostic};
+pub use failure::{Failure, FieldPath, Help, KeyedError, PathSegment, Severity};
+#[cfg(feature = "fluent")]
+pub use fluent::{LocalizerTaken, ToFluentStringIn, with_locale};
+pub use log::{LogEntry, ValidationLog};
+pub use redact::{ObserveValue, Redact};
+pub use step::StepResult;
+pub use stream::{LineFailure, StreamValidation};
+pub use validated::Validated;
+
+#[cfg(feature = "validator-compat")]
+pub mod validator_compat;
+
 /// Trait for types that can validate a value of type `T`.
 ///
 /// Implementors should return `true` if validation passes,
@@ -7,6 +39,20 @@ pub trait Validate<T> {
     fn validate(&self, value: &T) -> bool;
 }
 
+/// Trait for types that validate a value of type `T` asynchronously, for
+/// rules that need IO such as checking a database or DNS.
+///
+/// Like [`Validate`], implementors return `true` if validation passes; the
+/// error details are captured in the validation struct itself. Implementations
+/// can be written as `async fn validate`. The future must be `Send` so the
+/// generated `validate()` can run on multi-threaded executors.
+///
+/// Fields use these validators with `#[koruma(async, ...)]`, which makes the
+/// struct's generated `validate()` an `async fn`.
+pub trait AsyncValidate<T> {
+    fn validate(&self, value: &T) -> impl Future<Output = bool> + Send;
+}
+
 /// Trait for validation error structs that have no errors.
 ///
 /// This is auto-implemented by the derive macro for generated
@@ -19,6 +65,342 @@ pub trait ValidationError {
     fn has_errors(&self) -> bool {
         !self.is_empty()
     }
+
+    /// Returns `true` if any error-severity failure is present.
+    ///
+    /// Warning-severity failures are reported by [`iter_failures`](Self::iter_failures)
+    /// but don't block validation. Errors that don't report their failures
+    /// are treated as blocking whenever they are non-empty.
+    fn has_blocking_errors(&self) -> bool {
+        let mut failures = self.iter_failures().peekable();
+        if failures.peek().is_none() {
+            return self.has_errors();
+        }
+        failures.any(|failure| failure.is_blocking())
+    }
+
+    /// Iterates over every failed validator, in field declaration order.
+    ///
+    /// Failures from nested structs and `each(...)` elements carry their
+    /// full path (e.g. `address.street` or `scores[1]`). The default
+    /// implementation reports nothing; `#[derive(Koruma)]` overrides it.
+    fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+        std::iter::empty()
+    }
+
+    /// Iterates over the failed struct-level `cross_field(...)` checks, in
+    /// key order.
+    ///
+    /// Cross-field failures are always blocking. They aren't reported by
+    /// [`iter_failures`](Self::iter_failures) since they have no field path,
+    /// but [`errors_count`](Self::errors_count) and
+    /// [`count_by_severity`](Self::count_by_severity) include them. The
+    /// default implementation reports none; `#[derive(Koruma)]` overrides it
+    /// for structs with `cross_field(...)` checks.
+    fn iter_cross_field_errors(&self) -> impl Iterator<Item = &KeyedError> {
+        std::iter::empty()
+    }
+
+    /// Returns the names of the validated fields, in declaration order.
+    ///
+    /// The default implementation reports none; `#[derive(Koruma)]` overrides it.
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns the names of the `(field, validator)` slots, in declaration
+    /// order: `field.validator`, `field[].validator` for element validators,
+    /// or just `field` for nested and newtype fields.
+    ///
+    /// The default implementation reports none; `#[derive(Koruma)]` overrides
+    /// it with the error struct's `FAILURE_BIT_NAMES`.
+    fn failure_slot_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Returns every failure sorted by field declaration order, then by
+    /// validator declaration order, then by element index or map key.
+    ///
+    /// Unlike [`iter_failures`](Self::iter_failures), the order doesn't
+    /// depend on how failures are stored (e.g. the iteration order of a
+    /// `HashMap` validated with `entries(...)`), so it is stable across
+    /// calls and runs. Failures lifted from nested and newtype fields sort
+    /// after every failure of this struct's own fields, keeping their
+    /// reported order.
+    fn failures_sorted(&self) -> Vec<Failure> {
+        let slots = self.failure_slot_names();
+        let slot_index = |name: &str| {
+            slots
+                .iter()
+                .position(|slot| *slot == name)
+                .unwrap_or(usize::MAX)
+        };
+
+        let mut failures: Vec<Failure> = self.iter_failures().collect();
+        failures.sort_by_cached_key(|failure| {
+            let (field, rest) = match failure.path().segments() {
+                [PathSegment::Field(field), rest @ ..] => (*field, rest),
+                _ => return (false, usize::MAX, Vec::new()),
+            };
+            match rest {
+                [] => (
+                    false,
+                    slot_index(&format!("{field}.{}", failure.validator())),
+                    Vec::new(),
+                ),
+                [PathSegment::Field(_), ..] => (true, slot_index(field), Vec::new()),
+                _ => (
+                    false,
+                    slot_index(&format!("{field}[].{}", failure.validator())),
+                    rest.to_vec(),
+                ),
+            }
+        });
+        failures
+    }
+
+    /// Panics if `field` has any failures, listing them in the panic message.
+    ///
+    /// Meant for tests that only care about one field of a larger error.
+    /// Warnings count as failures too. Panics with a distinct message if
+    /// `field` isn't one of the [`field_names`](Self::field_names).
+    #[cfg(feature = "test-util")]
+    #[track_caller]
+    fn assert_field_valid(&self, field: &str) {
+        let names = self.field_names();
+        assert!(
+            names.contains(&field),
+            "unknown field `{field}`, expected one of: {}",
+            names.join(", ")
+        );
+
+        let failures: Vec<String> = self
+            .iter_failures()
+            .filter(|failure| {
+                matches!(
+                    failure.path().segments().first(),
+                    Some(PathSegment::Field(name)) if *name == field
+                )
+            })
+            .map(|failure| {
+                format!(
+                    "  {}: {} ({})",
+                    failure.path(),
+                    failure.validator(),
+                    failure.severity()
+                )
+            })
+            .collect();
+        assert!(
+            failures.is_empty(),
+            "expected field `{field}` to be valid, but it has {} failure(s):\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+
+    /// Returns the number of `(blocking errors, warnings)` across all fields,
+    /// including nested structs and `each(...)` elements. Failed cross-field
+    /// checks count as blocking errors.
+    fn count_by_severity(&self) -> (usize, usize) {
+        let cross_field = self.iter_cross_field_errors().count();
+        self.iter_failures().fold(
+            (cross_field, 0),
+            |(errors, warnings), failure| match failure.severity() {
+                Severity::Error => (errors + 1, warnings),
+                Severity::Warning => (errors, warnings + 1),
+            },
+        )
+    }
+
+    /// Returns the total number of failures, warnings included, across all
+    /// fields, nested structs, `each(...)` elements and cross-field checks.
+    ///
+    /// Use [`count_by_severity`](Self::count_by_severity) to count errors
+    /// and warnings separately.
+    fn errors_count(&self) -> usize {
+        self.iter_failures().count() + self.iter_cross_field_errors().count()
+    }
+
+    /// Returns `true` if any field, nested struct or `each(...)` element
+    /// failed the validator with the given snake_case name
+    /// (e.g. `"email_validation"`).
+    fn contains_validator(&self, validator: &str) -> bool {
+        self.iter_failures()
+            .any(|failure| failure.validator() == validator)
+    }
+
+    /// Returns `true` if both errors failed the same validators at the
+    /// same paths, ignoring the values stored in the validators.
+    ///
+    /// Useful for structural assertions in tests where the exact failing
+    /// values are irrelevant.
+    fn same_failures(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        let keys = |error: &Self| {
+            error
+                .iter_failures()
+                .map(|failure| (failure.path().clone(), failure.validator()))
+                .collect::<std::collections::HashSet<_>>()
+        };
+        keys(self) == keys(other)
+    }
+}
+
+/// Trait for validation errors that can render a message for each failure.
+///
+/// This is auto-implemented by `#[derive(KorumaAllDisplay)]` using each failed
+/// validator's `Display` impl. Nested and newtype fields require their own
+/// error types to implement it too.
+pub trait ValidationMessages: ValidationError {
+    /// Returns every failure paired with its message, in the same order as
+    /// [`iter_failures`](ValidationError::iter_failures).
+    fn failure_messages(&self) -> Vec<(Failure, String)>;
+
+    /// Returns the message of the first blocking error, or of the first
+    /// warning if there are no errors, for a compact banner.
+    ///
+    /// Field errors come before failed cross-field checks. Returns `None`
+    /// if there are no failures.
+    fn first_blocking_or_warning(&self) -> Option<(Severity, String)> {
+        let mut first_warning = None;
+        for (failure, message) in self.failure_messages() {
+            match failure.severity() {
+                Severity::Error => return Some((Severity::Error, message)),
+                Severity::Warning if first_warning.is_none() => {
+                    first_warning = Some((Severity::Warning, message));
+                },
+                Severity::Warning => {},
+            }
+        }
+        if let Some(cross_field_error) = self.iter_cross_field_errors().next() {
+            return Some((Severity::Error, cross_field_error.to_string()));
+        }
+        first_warning
+    }
+
+    /// Returns every failure's path paired with its message, in failure
+    /// order, e.g. `("address.zip_code", "...")` or `("scores[2]", "...")`.
+    ///
+    /// Failed cross-field checks follow the field failures, paired with
+    /// their key (e.g. `("passwords_match", "...")`).
+    fn all_messages(&self) -> Vec<(String, String)> {
+        self.failure_messages()
+            .into_iter()
+            .map(|(failure, message)| (failure.path().to_string(), message))
+            .chain(self.iter_cross_field_errors().map(|cross_field_error| {
+                (
+                    cross_field_error.key().to_owned(),
+                    cross_field_error.to_string(),
+                )
+            }))
+            .collect()
+    }
+
+    /// Converts the failures into GraphQL-style error extensions.
+    ///
+    /// Returns an object mapping each dotted field path (e.g. `address.street`
+    /// or `scores[1]`) to an array of `{ "code": ..., "message": ... }` entries,
+    /// where `code` is the snake_case validator name. Failures with help
+    /// attached also carry a `"help"` entry.
+    #[cfg(feature = "serde")]
+    fn to_graphql_extensions(&self) -> serde_json::Value {
+        let mut fields = serde_json::Map::new();
+        for (failure, message) in self.failure_messages() {
+            let mut entry = serde_json::json!({
+                "code": failure.validator(),
+                "message": message,
+            });
+            if let Some(help) = failure.help() {
+                entry["help"] = help.into();
+            }
+            match fields
+                .entry(failure.path().to_string())
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()))
+            {
+                serde_json::Value::Array(entries) => entries.push(entry),
+                _ => unreachable!("field entries are always arrays"),
+            }
+        }
+        serde_json::Value::Object(fields)
+    }
+
+    /// Groups the failure messages by the JSON Pointer of their path
+    /// (e.g. `/address/zip_code` or `/tags/2`), in failure order.
+    ///
+    /// Useful for pointing API clients at the offending part of a JSON
+    /// request body. See [`FieldPath::to_json_pointer`] for the escaping rules.
+    #[cfg(feature = "serde")]
+    fn to_pointer_map(&self) -> std::collections::HashMap<String, Vec<String>> {
+        let mut pointers = std::collections::HashMap::<String, Vec<String>>::new();
+        for (failure, message) in self.failure_messages() {
+            pointers
+                .entry(failure.path().to_json_pointer())
+                .or_default()
+                .push(message);
+        }
+        pointers
+    }
+
+    /// Converts the failures into a `miette` diagnostic, with one related
+    /// diagnostic per failure.
+    ///
+    /// See [`ValidationDiagnostic`] for how each failure is rendered.
+    #[cfg(feature = "miette")]
+    fn to_diagnostic(&self) -> ValidationDiagnostic {
+        ValidationDiagnostic::new(self)
+    }
+}
+
+/// Trait for validation errors that can render a localized message for each failure.
+///
+/// This is auto-implemented by `#[derive(KorumaAllFluent)]` using each failed
+/// validator's `ToFluentString` impl, so messages are rendered in the language
+/// currently selected in es-fluent. Nested and newtype fields require their own
+/// error types to implement it too.
+pub trait LocalizedMessages: ValidationError {
+    /// Returns every failure paired with its localized message, in the same
+    /// order as [`iter_failures`](ValidationError::iter_failures).
+    fn localized_failure_messages(&self) -> Vec<(Failure, String)>;
+
+    /// Returns every failure's path paired with its localized message, in
+    /// failure order, like
+    /// [`ValidationMessages::all_messages`] for the localized text.
+    ///
+    /// Failed cross-field checks follow, paired with their key and the
+    /// message they returned.
+    fn all_localized_messages(&self) -> Vec<(String, String)> {
+        self.localized_failure_messages()
+            .into_iter()
+            .map(|(failure, message)| (failure.path().to_string(), message))
+            .chain(self.iter_cross_field_errors().map(|cross_field_error| {
+                (
+                    cross_field_error.key().to_owned(),
+                    cross_field_error.to_string(),
+                )
+            }))
+            .collect()
+    }
+
+    /// Returns the localized messages sorted by descending
+    /// [`priority`](Failure::priority), keeping declaration order between
+    /// failures of equal priority. Failed cross-field checks have priority
+    /// `0` and come after the field failures of the same priority.
+    fn prioritized_messages(&self) -> Vec<String> {
+        let mut messages: Vec<(i32, String)> = self
+            .localized_failure_messages()
+            .into_iter()
+            .map(|(failure, message)| (failure.priority(), message))
+            .chain(
+                self.iter_cross_field_errors()
+                    .map(|cross_field_error| (0, cross_field_error.to_string())),
+            )
+            .collect();
+        messages.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
+        messages.into_iter().map(|(_, message)| message).collect()
+    }
 }
 
 /// Trait for validator builders that can receive the value being validated.
@@ -29,6 +411,310 @@ pub trait BuilderWithValue<T> {
     fn with_value(self, value: T) -> Self;
 }
 
+/// Trait for validators that expose the value they were built with.
+///
+/// This is auto-implemented by `#[koruma::validator]` for the field marked with
+/// `#[koruma(value)]`. For redacted fields it returns the redacted copy.
+pub trait StoredValue {
+    /// The type of the stored value.
+    type Value;
+
+    /// Returns the stored value.
+    fn stored_value(&self) -> &Self::Value;
+}
+
+/// Marker for validators whose `Validate` impl reads only their
+/// configuration and the value it is given, never the stored value or fields
+/// computed from it.
+///
+/// `#[derive(Koruma)]` normally clones a field into every validator on it
+/// before validating. For these validators, when the value type implements
+/// `Default`, it validates the field by reference with a validator holding
+/// the default value instead, and clones the field only if it fails.
+/// Implemented by `#[koruma::validator(deferred_value)]`.
+pub trait DeferredValue {}
+
+/// Trait for validators that record the index of the collection element they
+/// failed on.
+///
+/// This is auto-implemented by `#[koruma::validator]` for the field marked with
+/// `#[koruma(index)]` (an `Option<usize>`), and set by `#[derive(Koruma)]` for
+/// validators used in `each(...)`. Validators without an index field are unaffected.
+pub trait ElementIndex {
+    /// Records the index of the failing element.
+    fn set_index(&mut self, index: usize);
+
+    /// Returns the index of the failing element, if the validator was used in `each(...)`.
+    fn index(&self) -> Option<usize>;
+}
+
+/// Trait for validators that record the name of the field they validate.
+///
+/// This is auto-implemented by `#[koruma::validator]` when the struct has a
+/// `field_name: Option<&'static str>` field, and set by `#[derive(Koruma)]`
+/// on every failing validator. Validators without that field are unaffected.
+pub trait HasFieldName {
+    /// Records the name of the validated field.
+    fn set_field_name(&mut self, name: &'static str);
+
+    /// Returns the name of the validated field, if it was set.
+    fn field_name(&self) -> Option<&'static str>;
+}
+
+/// Support code for the derive macros. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    use std::marker::PhantomData;
+
+    use crate::{DeferredValue, ElementIndex, HasFieldName, ObserveValue, ValidateExt, Validated};
+
+    /// Validates `value`, wrapping it in [`Validated`] if it passes. Backs the
+    /// generated `into_validated`.
+    pub fn validated<T: ValidateExt>(value: T) -> Result<Validated<T>, T::Error> {
+        value.validate()?;
+        Ok(Validated::new(value))
+    }
+
+    /// Wraps an element validator so the derive can set its index only when it
+    /// implements [`ElementIndex`], without knowing that at expansion time.
+    pub struct IndexTag<'a, V>(pub &'a mut V);
+
+    pub trait ViaElementIndex {
+        fn koruma_set_index(&mut self, index: usize);
+    }
+
+    impl<V: ElementIndex> ViaElementIndex for IndexTag<'_, V> {
+        fn koruma_set_index(&mut self, index: usize) {
+            self.0.set_index(index);
+        }
+    }
+
+    pub trait ViaNoElementIndex {
+        fn koruma_set_index(&mut self, _index: usize) {}
+    }
+
+    impl<V> ViaNoElementIndex for &mut IndexTag<'_, V> {}
+
+    /// Wraps a validator so the derive can set its field name only when it
+    /// implements [`HasFieldName`], without knowing that at expansion time.
+    pub struct FieldNameTag<'a, V>(pub &'a mut V);
+
+    pub trait ViaFieldName {
+        fn koruma_set_field_name(&mut self, name: &'static str);
+    }
+
+    impl<V: HasFieldName> ViaFieldName for FieldNameTag<'_, V> {
+        fn koruma_set_field_name(&mut self, name: &'static str) {
+            self.0.set_field_name(name);
+        }
+    }
+
+    pub trait ViaNoFieldName {
+        fn koruma_set_field_name(&mut self, _name: &'static str) {}
+    }
+
+    impl<V> ViaNoFieldName for &mut FieldNameTag<'_, V> {}
+
+    /// Builds the validator a value is checked with, so the derive can defer
+    /// cloning the value until the check fails when the validator implements
+    /// [`DeferredValue`], without knowing that at expansion time.
+    ///
+    /// `value` produces the value to store and `build` builds a validator
+    /// holding it.
+    pub struct ProbeTag<T, V, G, F> {
+        value: G,
+        build: F,
+        _types: PhantomData<fn(T) -> V>,
+    }
+
+    impl<T, V, G: Fn() -> T, F: Fn(T) -> V> ProbeTag<T, V, G, F> {
+        pub fn new(value: G, build: F) -> Self {
+            Self {
+                value,
+                build,
+                _types: PhantomData,
+            }
+        }
+
+        /// Builds a validator holding the real value, for a failed check.
+        pub fn build_stored(&self) -> V {
+            (self.build)((self.value)())
+        }
+    }
+
+    pub trait ViaDeferredProbe<V> {
+        /// Returns the validator to check with, and whether it holds a
+        /// placeholder instead of the real value.
+        fn koruma_probe(&self) -> (V, bool);
+    }
+
+    impl<T: Default, V: DeferredValue, G, F: Fn(T) -> V> ViaDeferredProbe<V> for ProbeTag<T, V, G, F> {
+        fn koruma_probe(&self) -> (V, bool) {
+            ((self.build)(T::default()), true)
+        }
+    }
+
+    pub trait ViaClonedProbe<V> {
+        fn koruma_probe(&self) -> (V, bool);
+    }
+
+    impl<T, V, G: Fn() -> T, F: Fn(T) -> V> ViaClonedProbe<V> for &ProbeTag<T, V, G, F> {
+        fn koruma_probe(&self) -> (V, bool) {
+            (self.build_stored(), false)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "serde")]
+    pub use serde_json;
+
+    /// Wraps a failed validator so the derive can serialize its parameters
+    /// only when it implements `Serialize`, without knowing that at
+    /// expansion time.
+    #[cfg(feature = "serde")]
+    pub struct ParamsTag<'a, V>(pub &'a V);
+
+    #[cfg(feature = "serde")]
+    pub trait ViaSerializeParams {
+        fn koruma_failure_json(&self, message: Option<String>) -> serde_json::Value;
+    }
+
+    #[cfg(feature = "serde")]
+    impl<V: serde::Serialize> ViaSerializeParams for ParamsTag<'_, V> {
+        fn koruma_failure_json(&self, message: Option<String>) -> serde_json::Value {
+            let mut failure = match serde_json::to_value(self.0) {
+                Ok(serde_json::Value::Object(params)) => params,
+                _ => serde_json::Map::new(),
+            };
+            if let Some(message) = message {
+                failure.insert("message".to_owned(), serde_json::Value::String(message));
+            }
+            serde_json::Value::Object(failure)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub trait ViaNoSerializeParams {
+        fn koruma_failure_json(&self, message: Option<String>) -> serde_json::Value {
+            let mut failure = serde_json::Map::new();
+            if let Some(message) = message {
+                failure.insert("message".to_owned(), serde_json::Value::String(message));
+            }
+            serde_json::Value::Object(failure)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<V> ViaNoSerializeParams for &ParamsTag<'_, V> {}
+
+    /// Wraps a failed validator so the serialized error carries its
+    /// `Display` output as the message only when it implements `Display`.
+    #[cfg(feature = "serde")]
+    pub struct MessageTag<'a, V>(pub &'a V);
+
+    #[cfg(feature = "serde")]
+    pub trait ViaDisplayMessage {
+        fn koruma_message(&self) -> Option<String>;
+    }
+
+    #[cfg(feature = "serde")]
+    impl<V: std::fmt::Display> ViaDisplayMessage for MessageTag<'_, V> {
+        fn koruma_message(&self) -> Option<String> {
+            Some(self.0.to_string())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub trait ViaNoDisplayMessage {
+        fn koruma_message(&self) -> Option<String> {
+            None
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<V> ViaNoDisplayMessage for &MessageTag<'_, V> {}
+
+    /// Serializes one element's failures as an object that also records the
+    /// element's position under `position_key` (`index` or `key`).
+    #[cfg(feature = "serde")]
+    pub fn element_json<E: serde::Serialize>(
+        position_key: &str,
+        position: serde_json::Value,
+        error: &E,
+    ) -> Result<serde_json::Value, serde_json::Error> {
+        let mut element = serde_json::Map::new();
+        element.insert(position_key.to_owned(), position);
+        if let serde_json::Value::Object(failures) = serde_json::to_value(error)? {
+            element.extend(failures);
+        }
+        Ok(serde_json::Value::Object(element))
+    }
+
+    /// Wraps a redacted validator and the real value so the derive can hand
+    /// it over only when the validator implements [`ObserveValue`].
+    pub struct ObserveTag<'a, V, T>(pub &'a mut V, pub &'a T);
+
+    pub trait ViaObserveValue {
+        fn koruma_observe_value(&mut self);
+    }
+
+    impl<V: ObserveValue<T>, T> ViaObserveValue for ObserveTag<'_, V, T> {
+        fn koruma_observe_value(&mut self) {
+            self.0.observe_value(self.1);
+        }
+    }
+
+    pub trait ViaNoObserveValue {
+        fn koruma_observe_value(&mut self) {}
+    }
+
+    impl<V, T> ViaNoObserveValue for &mut ObserveTag<'_, V, T> {}
+
+    /// Wraps the stored value substituted for `{value}` in a `message = "..."`,
+    /// so the derive formats it with `Display`, or with `Debug` when the type
+    /// has no `Display` (e.g. `Option<T>` or `Vec<T>`).
+    pub struct ValueTextTag<'a, T>(pub &'a T);
+
+    pub trait ViaDisplayText {
+        fn koruma_value_text(&self) -> String;
+    }
+
+    impl<T: std::fmt::Display> ViaDisplayText for ValueTextTag<'_, T> {
+        fn koruma_value_text(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    pub trait ViaDebugText {
+        fn koruma_value_text(&self) -> String;
+    }
+
+    impl<T: std::fmt::Debug> ViaDebugText for &ValueTextTag<'_, T> {
+        fn koruma_value_text(&self) -> String {
+            format!("{:?}", self.0)
+        }
+    }
+
+    /// Implemented for every `Clone` type. The derive asserts it on validated
+    /// field types so that a missing `Clone` is reported at the field.
+    ///
+    /// ```compile_fail
+    /// struct NotClone;
+    ///
+    /// fn assert_field_clone<T: koruma_core::__private::FieldClone>() {}
+    /// assert_field_clone::<NotClone>();
+    /// ```
+    #[diagnostic::on_unimplemented(
+        message = "field type `{Self}` must implement `Clone` to be validated",
+        label = "validators store a clone of this field's value",
+        note = "derive `Clone` for the type, or mark its validators `redact` to store a redacted copy instead"
+    )]
+    pub trait FieldClone {}
+
+    impl<T: Clone> FieldClone for T {}
+}
+
 /// Trait for structs that derive `Koruma` and have a `validate()` method.
 ///
 /// This trait provides an associated type for the validation error struct,
@@ -40,7 +726,79 @@ pub trait ValidateExt {
     type Error: ValidationError;
 
     /// Validates the struct and returns the error struct if validation fails.
+    ///
+    /// Only error-severity failures make validation fail.
     fn validate(&self) -> Result<(), Self::Error>;
+
+    /// Runs every validator and returns all failures, including warnings.
+    ///
+    /// The returned error is empty if nothing failed.
+    fn validation_report(&self) -> Self::Error
+    where
+        Self::Error: Default,
+    {
+        self.validate().err().unwrap_or_default()
+    }
+
+    /// Returns `true` if [`validate`](Self::validate) would pass.
+    ///
+    /// The derived implementation stops at the first blocking failure without
+    /// building the error struct, for callers that only need the outcome.
+    fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Validates the struct, mapping a failure into the caller's own error type.
+    ///
+    /// Shorthand for `self.validate().map_err(f)`, so service code can write
+    /// `input.validate_or_else(AppError::Validation)?`.
+    fn validate_or_else<F, E>(&self, f: F) -> Result<(), E>
+    where
+        F: FnOnce(Self::Error) -> E,
+    {
+        self.validate().map_err(f)
+    }
+}
+
+/// Validates every item of a slice, returning the errors of the failing items
+/// along with their indices.
+///
+/// This validates a top-level collection without declaring a wrapper struct
+/// with a `#[koruma(each(...))]` field. An empty slice is valid.
+pub fn validate_each<T: ValidateExt>(items: &[T]) -> Result<(), Vec<(usize, T::Error)>> {
+    let errors: Vec<(usize, T::Error)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| item.validate().err().map(|error| (index, error)))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validates every item of a collection in one call.
+///
+/// Implemented for slices of types that derive `Koruma`, so it also applies to
+/// `Vec<T>` and arrays: `users.validate_all()` behaves like
+/// [`validate_each`]`(&users)`.
+pub trait ValidateAll {
+    /// The validation error type of a single item.
+    type Error;
+
+    /// Returns the index and error of every failing item, or `Ok(())` if all
+    /// items pass.
+    fn validate_all(&self) -> Result<(), Vec<(usize, Self::Error)>>;
+}
+
+impl<T: ValidateExt> ValidateAll for [T] {
+    type Error = T::Error;
+
+    fn validate_all(&self) -> Result<(), Vec<(usize, T::Error)>> {
+        validate_each(self)
+    }
 }
 
 /// Marker trait for newtype structs (single-field wrappers) that derive `Koruma`.
@@ -82,14 +840,72 @@ pub mod showcase {
         fn fluent_string(&self) -> String;
     }
 
-    /// The type of input expected by the validator.
+    /// The kind of input a showcase validator is constructed from.
+    ///
+    /// Consumers use it to restrict and parse what the user types before the
+    /// input reaches [`ValidatorShowcase::create_validator`].
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-    pub enum InputType {
+    pub enum InputKind {
         /// Any text input (default)
         #[default]
-        Text,
-        /// Numeric input only (integers)
-        Numeric,
+        String,
+        /// An integer, e.g. `-42`
+        Integer,
+        /// A floating-point number, e.g. `3.5`
+        Float,
+        /// `true` or `false`
+        Bool,
+    }
+
+    impl InputKind {
+        /// Returns `true` if `input` parses as this kind of input.
+        pub fn accepts(&self, input: &str) -> bool {
+            match self {
+                InputKind::String => true,
+                InputKind::Integer => input.parse::<i128>().is_ok(),
+                InputKind::Float => input.parse::<f64>().is_ok_and(f64::is_finite),
+                InputKind::Bool => input.parse::<bool>().is_ok(),
+            }
+        }
+
+        /// Returns `true` if `c` may appear in this kind of input.
+        ///
+        /// Useful for filtering keystrokes; a string made of allowed
+        /// characters still needs [`accepts`](Self::accepts) to be valid.
+        pub fn allows_char(&self, c: char) -> bool {
+            match self {
+                InputKind::String | InputKind::Bool => true,
+                InputKind::Integer => c.is_ascii_digit() || c == '-',
+                InputKind::Float => c.is_ascii_digit() || matches!(c, '-' | '.' | 'e' | 'E'),
+            }
+        }
+
+        /// Describes the input this kind expects, for reporting input it
+        /// doesn't [`accept`](Self::accepts).
+        pub fn expected(&self) -> &'static str {
+            match self {
+                InputKind::String => "expected text",
+                InputKind::Integer => "expected an integer",
+                InputKind::Float => "expected a finite number",
+                InputKind::Bool => "expected `true` or `false`",
+            }
+        }
+    }
+
+    /// A configurable parameter of a showcase validator, for rendering an
+    /// input for it.
+    ///
+    /// `#[showcase(...)]` lists one per field set through the validator's
+    /// builder, leaving out the validated value.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct ShowcaseParam {
+        /// Name of the field, which is also the builder setter's name
+        pub name: &'static str,
+        /// The field's type as written in the struct, e.g. `Vec<String>`
+        pub type_name: &'static str,
+        /// Source of the builder default, e.g. `Default::default()` or
+        /// `10`, or `None` if the parameter is required
+        pub default: Option<&'static str>,
     }
 
     /// Information about a validator for showcase purposes.
@@ -101,10 +917,38 @@ pub mod showcase {
         pub name: &'static str,
         /// Description of what the validator checks
         pub description: &'static str,
-        /// The type of input expected by the validator
-        pub input_type: InputType,
-        /// Factory function that creates a validator from string input.
-        pub create_validator: fn(&str) -> Box<dyn DynValidator>,
+        /// The kind of input the validator is constructed from
+        pub input_kind: InputKind,
+        /// `module_path!()` of the module defining the validator
+        pub module_path: &'static str,
+        /// The parameters set through the validator's builder, in
+        /// declaration order
+        pub parameters: &'static [ShowcaseParam],
+        /// Factory function that creates a validator from string input, or
+        /// returns a message explaining why the input can't build one.
+        pub create_validator: fn(&str) -> Result<Box<dyn DynValidator>, String>,
+    }
+
+    impl ValidatorShowcase {
+        /// Returns the category of the validator: the parent of the module
+        /// defining it, e.g. `"string"` for
+        /// `koruma_collection::validators::string::prefix`, or the crate name
+        /// for a validator defined at the crate root.
+        pub fn category(&self) -> &'static str {
+            let mut segments = self.module_path.rsplit("::");
+            let last = segments.next().unwrap_or_default();
+            segments.next().unwrap_or(last)
+        }
+
+        /// Creates a validator from `input`, or returns a message if the
+        /// input doesn't parse as the validator's [`InputKind`] or
+        /// [`create_validator`](Self::create_validator) rejects it.
+        pub fn try_create(&self, input: &str) -> Result<Box<dyn DynValidator>, String> {
+            if !self.input_kind.accepts(input) {
+                return Err(self.input_kind.expected().to_string());
+            }
+            (self.create_validator)(input)
+        }
     }
 
     inventory::collect!(ValidatorShowcase);
@@ -113,4 +957,57 @@ pub mod showcase {
     pub fn validators() -> Vec<&'static ValidatorShowcase> {
         inventory::iter::<ValidatorShowcase>().collect()
     }
+
+    /// Get the registered showcase validator named `name`, if any.
+    pub fn find(name: &str) -> Option<&'static ValidatorShowcase> {
+        inventory::iter::<ValidatorShowcase>().find(|showcase| showcase.name == name)
+    }
+
+    /// Error returned by [`run_batch`] when no showcase validator is
+    /// registered under the requested name.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct UnknownValidator {
+        name: String,
+    }
+
+    impl UnknownValidator {
+        /// Returns the name that wasn't found.
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl std::fmt::Display for UnknownValidator {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "no showcase validator named `{}`", self.name)
+        }
+    }
+
+    impl std::error::Error for UnknownValidator {}
+
+    /// Checks every input against the showcase validator named
+    /// `validator_name`, without any UI.
+    ///
+    /// Builds one validator per input and returns each input paired with
+    /// whether it passed, in order. An input that doesn't parse as the
+    /// validator's [`InputKind`], or that the validator can't be built
+    /// from, fails. Meant for CI and scripts, e.g. fed
+    /// the lines of a file.
+    pub fn run_batch(
+        validator_name: &str,
+        inputs: impl IntoIterator<Item = String>,
+    ) -> Result<Vec<(String, bool)>, UnknownValidator> {
+        let showcase = find(validator_name).ok_or_else(|| UnknownValidator {
+            name: validator_name.to_string(),
+        })?;
+        Ok(inputs
+            .into_iter()
+            .map(|input| {
+                let passed = showcase
+                    .try_create(&input)
+                    .is_ok_and(|validator| validator.is_valid());
+                (input, passed)
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,133 @@
+use std::time::SystemTime;
+
+use crate::{FieldPath, Severity, ValidationMessages};
+
+/// A failure recorded in a [`ValidationLog`].
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    /// When the failing validation pass was recorded.
+    pub timestamp: SystemTime,
+    /// Where the failure occurred (e.g. `address.street` or `tags[1]`).
+    pub path: FieldPath,
+    /// Snake_case name of the failed validator (e.g. `range_validation`).
+    pub validator: &'static str,
+    /// Severity of the failure.
+    pub severity: Severity,
+    /// The failure's message.
+    pub message: String,
+}
+
+/// Chronological log of failures across multiple validation passes.
+///
+/// Each call to [`record`](Self::record) appends every failure of one
+/// validation error, stamped with the time of the call, so repeatedly
+/// validating changing inputs builds an audit trail of what failed and when.
+///
+/// Recording takes `&mut self`; wrap the log in a `Mutex` to share it across
+/// threads.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::{Failure, FieldPath, ValidationError, ValidationLog, ValidationMessages};
+///
+/// struct AgeError(i32);
+///
+/// impl ValidationError for AgeError {
+///     fn is_empty(&self) -> bool {
+///         false
+///     }
+///
+///     fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+///         std::iter::once(Failure::new(FieldPath::field("age"), "range_validation"))
+///     }
+/// }
+///
+/// impl ValidationMessages for AgeError {
+///     fn failure_messages(&self) -> Vec<(Failure, String)> {
+///         self.iter_failures()
+///             .map(|failure| (failure, format!("{} is out of range", self.0)))
+///             .collect()
+///     }
+/// }
+///
+/// let mut log = ValidationLog::new();
+/// log.record(&AgeError(150));
+/// log.record(&AgeError(-1));
+///
+/// let messages: Vec<_> = log.iter().map(|entry| entry.message.as_str()).collect();
+/// assert_eq!(messages, ["150 is out of range", "-1 is out of range"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ValidationLog {
+    entries: Vec<LogEntry>,
+}
+
+impl ValidationLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends every failure of `errors`, stamped with the current time.
+    ///
+    /// Returns the number of entries added.
+    pub fn record<E: ValidationMessages>(&mut self, errors: &E) -> usize {
+        self.record_at(errors, SystemTime::now())
+    }
+
+    /// Appends every failure of `errors`, stamped with `timestamp`.
+    ///
+    /// Useful for deterministic tests or when replaying past validations.
+    /// Entries are kept in recording order, not sorted by timestamp.
+    pub fn record_at<E: ValidationMessages>(&mut self, errors: &E, timestamp: SystemTime) -> usize {
+        let before = self.entries.len();
+        self.entries.extend(
+            errors
+                .failure_messages()
+                .into_iter()
+                .map(|(failure, message)| LogEntry {
+                    timestamp,
+                    path: failure.path().clone(),
+                    validator: failure.validator(),
+                    severity: failure.severity(),
+                    message,
+                }),
+        );
+        self.entries.len() - before
+    }
+
+    /// Returns the recorded entries, in recording order.
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    /// Iterates over the recorded entries, in recording order.
+    pub fn iter(&self) -> std::slice::Iter<'_, LogEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationLog {
+    type Item = &'a LogEntry;
+    type IntoIter = std::slice::Iter<'a, LogEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
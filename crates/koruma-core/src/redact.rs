@@ -0,0 +1,70 @@
+/// Trait for values that can be masked before being stored in a validation error.
+///
+/// Used for fields marked with `#[koruma(redact)]`: validators still check the
+/// real value, but the copy kept for error messages is the redacted one.
+/// Implementations keep length information so messages like "must be at least
+/// 8 characters" stay meaningful.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::Redact;
+///
+/// assert_eq!("hunter2".to_string().redacted(), "*******");
+/// assert_eq!(vec![1, 2, 3].redacted(), vec![0, 0, 0]);
+/// ```
+pub trait Redact {
+    /// Returns a masked copy of the value.
+    fn redacted(&self) -> Self;
+}
+
+/// Trait for validators that compute details (a score, a list of unmet
+/// rules) from the value they check.
+///
+/// Validators on `#[koruma(redact)]` fields are built with the masked copy,
+/// so anything computed at build time would describe the mask.
+/// `#[derive(Koruma)]` calls [`observe_value`](Self::observe_value) with the
+/// real value on those validators when they fail, before storing them.
+/// Implementations should recompute their details without keeping the value.
+/// Validators that don't implement this are unaffected.
+pub trait ObserveValue<T> {
+    /// Recomputes the validator's details from the real value.
+    fn observe_value(&mut self, value: &T);
+}
+
+/// Replaces every character with `*`, keeping the character count.
+impl Redact for String {
+    fn redacted(&self) -> Self {
+        self.chars().map(|_| '*').collect()
+    }
+}
+
+impl<T: Redact> Redact for Option<T> {
+    fn redacted(&self) -> Self {
+        self.as_ref().map(Redact::redacted)
+    }
+}
+
+/// Redacts every element, keeping the element count.
+impl<T: Redact> Redact for Vec<T> {
+    fn redacted(&self) -> Self {
+        self.iter().map(Redact::redacted).collect()
+    }
+}
+
+macro_rules! impl_redact_default {
+    ($($ty:ty),*) => {
+        $(
+            /// Replaces the value with its default.
+            impl Redact for $ty {
+                fn redacted(&self) -> Self {
+                    <$ty>::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_redact_default!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+);
@@ -0,0 +1,80 @@
+use crate::ValidationError;
+
+/// Outcome of one step of a struct's `validation_steps()` iterator.
+///
+/// A step is one validator on one field (all elements for `each(...)`), or a
+/// whole nested or newtype field. Its `error` holds only that step's
+/// failures, in the struct's usual error type.
+///
+/// # Example
+///
+/// ```rust
+/// use koruma_core::{Failure, FieldPath, StepResult, ValidationError};
+///
+/// #[derive(Default)]
+/// struct AgeError(Option<i32>);
+///
+/// impl ValidationError for AgeError {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_none()
+///     }
+///
+///     fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+///         self.0
+///             .map(|_| Failure::new(FieldPath::field("age"), "range_validation"))
+///             .into_iter()
+///     }
+/// }
+///
+/// let step = StepResult::new("age", Some("range_validation"), AgeError(Some(150)));
+/// assert!(!step.passed());
+/// assert_eq!(step.field(), "age");
+/// assert_eq!(step.validator(), Some("range_validation"));
+/// assert_eq!(step.error().0, Some(150));
+/// ```
+#[derive(Clone, Debug)]
+pub struct StepResult<E> {
+    field: &'static str,
+    validator: Option<&'static str>,
+    error: E,
+}
+
+impl<E: ValidationError> StepResult<E> {
+    /// Creates the outcome of the step running `validator` on `field`, where
+    /// `validator` is `None` for nested and newtype fields.
+    pub fn new(field: &'static str, validator: Option<&'static str>, error: E) -> Self {
+        Self {
+            field,
+            validator,
+            error,
+        }
+    }
+
+    /// Returns the name of the checked field.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Returns the snake_case name of the validator that ran (e.g.
+    /// `range_validation`), or `None` if the step validated a nested or
+    /// newtype field.
+    pub fn validator(&self) -> Option<&'static str> {
+        self.validator
+    }
+
+    /// Returns `true` if the step has no blocking errors. Warnings alone
+    /// don't fail a step.
+    pub fn passed(&self) -> bool {
+        !self.error.has_blocking_errors()
+    }
+
+    /// Returns the step's failures.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes the step, returning its failures.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
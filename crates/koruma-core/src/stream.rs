@@ -0,0 +1,116 @@
+use std::io::{self, BufRead};
+
+use crate::Validate;
+
+/// A failed line reported by [`StreamValidation`].
+#[derive(Clone, Debug)]
+pub struct LineFailure<V> {
+    /// 1-based number of the failing line.
+    pub line: usize,
+    /// The validator built for the line, holding the line as its value.
+    pub validator: V,
+}
+
+/// Applies a per-line validator to a [`BufRead`] stream.
+///
+/// Lines are read one at a time, so arbitrarily large inputs are validated
+/// without being loaded into memory. Line endings (`\n` or `\r\n`) are
+/// stripped before validation, and a final line without a trailing newline
+/// is validated like any other.
+///
+/// The validator is built per line by a closure receiving the line, so each
+/// reported failure keeps the value it failed on:
+///
+/// ```rust
+/// use koruma_core::{StreamValidation, Validate};
+///
+/// struct MaxLen {
+///     max: usize,
+///     actual: String,
+/// }
+///
+/// impl Validate<String> for MaxLen {
+///     fn validate(&self, value: &String) -> bool {
+///         value.len() <= self.max
+///     }
+/// }
+///
+/// let input = "short\nthis line is too long\nok\nanother long line";
+/// let stream = StreamValidation::new(|line| MaxLen { max: 10, actual: line });
+///
+/// let first = stream.first_failure(input.as_bytes()).unwrap().unwrap();
+/// assert_eq!(first.line, 2);
+/// assert_eq!(first.validator.actual, "this line is too long");
+///
+/// let failures = stream.max_failures(10).validate(input.as_bytes()).unwrap();
+/// assert_eq!(failures.iter().map(|f| f.line).collect::<Vec<_>>(), vec![2, 4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamValidation<F> {
+    make_validator: F,
+    max_failures: usize,
+}
+
+impl<F, V> StreamValidation<F>
+where
+    F: Fn(String) -> V,
+    V: Validate<String>,
+{
+    /// Creates a stream validation that stops at the first failing line.
+    pub fn new(make_validator: F) -> Self {
+        Self {
+            make_validator,
+            max_failures: 1,
+        }
+    }
+
+    /// Sets how many failing lines to collect before stopping.
+    ///
+    /// `0` means no limit.
+    pub fn max_failures(mut self, max_failures: usize) -> Self {
+        self.max_failures = max_failures;
+        self
+    }
+
+    /// Validates `reader` line by line, returning the failing lines in order.
+    ///
+    /// Reading stops as soon as `max_failures` lines have failed.
+    pub fn validate<R: BufRead>(&self, reader: R) -> io::Result<Vec<LineFailure<V>>> {
+        self.collect(reader, self.max_failures)
+    }
+
+    /// Returns the first failing line, or `None` if every line is valid.
+    pub fn first_failure<R: BufRead>(&self, reader: R) -> io::Result<Option<LineFailure<V>>> {
+        Ok(self.collect(reader, 1)?.pop())
+    }
+
+    fn collect<R: BufRead>(&self, mut reader: R, limit: usize) -> io::Result<Vec<LineFailure<V>>> {
+        let mut failures = Vec::new();
+        let mut buf = String::new();
+        let mut line = 0;
+
+        loop {
+            buf.clear();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            line += 1;
+
+            if buf.ends_with('\n') {
+                buf.pop();
+                if buf.ends_with('\r') {
+                    buf.pop();
+                }
+            }
+            let validator = (self.make_validator)(buf.clone());
+            if !validator.validate(&buf) {
+                failures.push(LineFailure { line, validator });
+                if failures.len() == limit {
+                    break;
+                }
+            }
+        }
+
+        Ok(failures)
+    }
+}
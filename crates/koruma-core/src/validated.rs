@@ -0,0 +1,36 @@
+use std::ops::Deref;
+
+/// A value that passed validation.
+///
+/// Only `into_validated()`, generated by `#[koruma(validated_wrapper)]`, can
+/// create one, so taking a `Validated<T>` parameter proves the value was
+/// checked without validating it again. It derefs to `&T`; there is no
+/// mutable access, since changing the value could invalidate it. Use
+/// [`into_inner`](Self::into_inner) to take it back out.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Validated<T>(T);
+
+impl<T> Validated<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the validated value, giving up the proof of validation.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
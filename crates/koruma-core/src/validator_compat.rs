@@ -0,0 +1,38 @@
+//! Interop with the [`validator`] crate.
+//!
+//! Structs deriving `Koruma` with `#[koruma(compat_validator)]` implement
+//! [`validator::Validate`], so frameworks expecting that trait accept them.
+
+use std::borrow::Cow;
+
+pub use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::ValidationError;
+
+/// Converts a koruma error into [`validator::ValidationErrors`].
+///
+/// Each failure is keyed by its full path (e.g. `address.street` or
+/// `scores[1]`) and uses the validator's snake_case name as its code.
+/// Failed cross-field checks go under `__all__`, like the `validator`
+/// crate's own struct-level errors, with their key as the code and their
+/// message attached.
+pub fn to_validation_errors<E: ValidationError>(error: &E) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    for failure in error.iter_failures() {
+        let kind = errors
+            .0
+            .entry(Cow::Owned(failure.path().to_string()))
+            .or_insert_with(|| ValidationErrorsKind::Field(Vec::new()));
+        if let ValidationErrorsKind::Field(field_errors) = kind {
+            field_errors.push(validator::ValidationError::new(failure.validator()));
+        }
+    }
+    for cross_field_error in error.iter_cross_field_errors() {
+        errors.add(
+            "__all__",
+            validator::ValidationError::new(cross_field_error.key())
+                .with_message(Cow::Owned(cross_field_error.message().to_owned())),
+        );
+    }
+    errors
+}
@@ -0,0 +1,113 @@
+//! Tests for the Cached validator wrapper.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use koruma_core::{Cached, Validate};
+
+/// Counts how often it runs; fails values containing `forbidden`.
+struct CountingValidator<'a> {
+    calls: &'a AtomicUsize,
+    forbidden: &'static str,
+}
+
+impl Validate<String> for CountingValidator<'_> {
+    fn validate(&self, value: &String) -> bool {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        !value.contains(self.forbidden)
+    }
+}
+
+#[test]
+fn test_cached_runs_validator_once_per_value() {
+    let calls = AtomicUsize::new(0);
+    let cached = Cached::new(CountingValidator {
+        calls: &calls,
+        forbidden: "needle",
+    });
+    let long = "haystack ".repeat(10_000);
+
+    for _ in 0..1000 {
+        assert!(cached.validate(&long));
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(cached.len(), 1);
+}
+
+#[test]
+fn test_cached_keeps_failing_results() {
+    let calls = AtomicUsize::new(0);
+    let cached = Cached::new(CountingValidator {
+        calls: &calls,
+        forbidden: "needle",
+    });
+    let bad = "a needle".to_string();
+    let good = "hay".to_string();
+
+    assert!(!cached.validate(&bad));
+    assert!(cached.validate(&good));
+    assert!(!cached.validate(&bad));
+    assert!(cached.validate(&good));
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_cached_evicts_oldest_when_full() {
+    let calls = AtomicUsize::new(0);
+    let cached = Cached::with_capacity(
+        CountingValidator {
+            calls: &calls,
+            forbidden: "needle",
+        },
+        2,
+    );
+    let (a, b, c) = ("a".to_string(), "b".to_string(), "c".to_string());
+
+    cached.validate(&a);
+    cached.validate(&b);
+    cached.validate(&c);
+    assert_eq!(cached.len(), 2);
+
+    // `a` was evicted, `c` is still cached.
+    cached.validate(&c);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    cached.validate(&a);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_cached_zero_capacity_disables_caching() {
+    let calls = AtomicUsize::new(0);
+    let cached = Cached::with_capacity(
+        CountingValidator {
+            calls: &calls,
+            forbidden: "needle",
+        },
+        0,
+    );
+    let value = "hay".to_string();
+
+    cached.validate(&value);
+    cached.validate(&value);
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(cached.is_empty());
+}
+
+#[test]
+fn test_cached_clear() {
+    let calls = AtomicUsize::new(0);
+    let cached = Cached::new(CountingValidator {
+        calls: &calls,
+        forbidden: "needle",
+    });
+    let value = "hay".to_string();
+
+    cached.validate(&value);
+    cached.clear();
+    assert!(cached.is_empty());
+    cached.validate(&value);
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
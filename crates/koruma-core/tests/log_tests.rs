@@ -0,0 +1,132 @@
+//! Tests for ValidationLog.
+
+use std::time::{Duration, SystemTime};
+
+use koruma_core::{
+    Failure, FieldPath, Severity, ValidationError, ValidationLog, ValidationMessages,
+};
+
+/// Error failing `age` when out of range and warning on an empty `name`.
+struct ProfileError {
+    age: Option<i32>,
+    name_empty: bool,
+}
+
+impl ValidationError for ProfileError {
+    fn is_empty(&self) -> bool {
+        self.age.is_none() && !self.name_empty
+    }
+
+    fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+        self.failure_messages()
+            .into_iter()
+            .map(|(failure, _)| failure)
+    }
+}
+
+impl ValidationMessages for ProfileError {
+    fn failure_messages(&self) -> Vec<(Failure, String)> {
+        let mut messages = Vec::new();
+        if let Some(age) = self.age {
+            messages.push((
+                Failure::new(FieldPath::field("age"), "range_validation"),
+                format!("age {age} is out of range"),
+            ));
+        }
+        if self.name_empty {
+            messages.push((
+                Failure::new(FieldPath::field("name"), "non_empty_validation")
+                    .with_severity(Severity::Warning),
+                "name is empty".to_string(),
+            ));
+        }
+        messages
+    }
+}
+
+#[test]
+fn test_log_accumulates_passes_in_order() {
+    let start = SystemTime::UNIX_EPOCH;
+    let mut log = ValidationLog::new();
+
+    let added = log.record_at(
+        &ProfileError {
+            age: Some(150),
+            name_empty: true,
+        },
+        start,
+    );
+    assert_eq!(added, 2);
+
+    let added = log.record_at(
+        &ProfileError {
+            age: None,
+            name_empty: false,
+        },
+        start + Duration::from_secs(1),
+    );
+    assert_eq!(added, 0);
+
+    log.record_at(
+        &ProfileError {
+            age: Some(-3),
+            name_empty: false,
+        },
+        start + Duration::from_secs(2),
+    );
+
+    let entries: Vec<_> = log
+        .iter()
+        .map(|entry| {
+            (
+                entry.timestamp,
+                entry.path.to_string(),
+                entry.validator,
+                entry.severity,
+                entry.message.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (
+                start,
+                "age".to_string(),
+                "range_validation",
+                Severity::Error,
+                "age 150 is out of range"
+            ),
+            (
+                start,
+                "name".to_string(),
+                "non_empty_validation",
+                Severity::Warning,
+                "name is empty"
+            ),
+            (
+                start + Duration::from_secs(2),
+                "age".to_string(),
+                "range_validation",
+                Severity::Error,
+                "age -3 is out of range"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_log_record_stamps_current_time() {
+    let before = SystemTime::now();
+    let mut log = ValidationLog::new();
+    log.record(&ProfileError {
+        age: Some(200),
+        name_empty: false,
+    });
+
+    assert_eq!(log.len(), 1);
+    assert!(log.entries()[0].timestamp >= before);
+
+    log.clear();
+    assert!(log.is_empty());
+}
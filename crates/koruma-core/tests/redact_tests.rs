@@ -0,0 +1,29 @@
+//! Tests for the built-in Redact impls.
+
+use koruma_core::Redact;
+
+#[test]
+fn test_string_keeps_char_count() {
+    assert_eq!("hunter2".to_string().redacted(), "*******");
+    assert_eq!(String::new().redacted(), "");
+}
+
+#[test]
+fn test_non_ascii_string_gets_one_star_per_char() {
+    let redacted = "pässwörd🔑".to_string().redacted();
+    assert_eq!(redacted, "*********");
+    assert_eq!(redacted.chars().count(), "pässwörd🔑".chars().count());
+}
+
+#[test]
+fn test_containers_redact_their_contents() {
+    assert_eq!(
+        Some("héllo".to_string()).redacted(),
+        Some("*****".to_string())
+    );
+    assert_eq!(None::<String>.redacted(), None);
+    assert_eq!(
+        vec!["ab".to_string(), "ç".to_string()].redacted(),
+        vec!["**".to_string(), "*".to_string()]
+    );
+}
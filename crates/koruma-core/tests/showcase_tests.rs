@@ -0,0 +1,118 @@
+//! Tests for showcase input kinds.
+#![cfg(feature = "showcase")]
+
+use koruma_core::showcase::{DynValidator, InputKind, ValidatorShowcase, run_batch};
+
+struct Parsed(i32);
+
+impl DynValidator for Parsed {
+    fn is_valid(&self) -> bool {
+        self.0 >= 0
+    }
+
+    fn display_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn fluent_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[test]
+fn test_input_kind_accepts() {
+    assert!(InputKind::String.accepts("anything"));
+    assert!(InputKind::Integer.accepts("-42"));
+    assert!(!InputKind::Integer.accepts("4.2"));
+    assert!(!InputKind::Integer.accepts(""));
+    assert!(InputKind::Float.accepts("4.2"));
+    assert!(!InputKind::Float.accepts("NaN"));
+    assert!(InputKind::Bool.accepts("true"));
+    assert!(!InputKind::Bool.accepts("yes"));
+}
+
+#[test]
+fn test_try_create_rejects_unparseable_input() {
+    let showcase = ValidatorShowcase {
+        name: "Non-negative",
+        description: "Validates that the input is a non-negative integer",
+        input_kind: InputKind::Integer,
+        module_path: "my_crate::numeric::non_negative",
+        parameters: &[],
+        create_validator: |input| {
+            input
+                .parse()
+                .map(|value| Box::new(Parsed(value)) as Box<dyn DynValidator>)
+                .map_err(|e| format!("{e}"))
+        },
+    };
+
+    assert!(showcase.try_create("12").unwrap().is_valid());
+    assert!(!showcase.try_create("-3").unwrap().is_valid());
+    assert_eq!(
+        showcase.try_create("twelve").err().as_deref(),
+        Some("expected an integer")
+    );
+    assert_eq!(
+        showcase.try_create("99999999999").err().as_deref(),
+        Some("number too large to fit in target type")
+    );
+}
+
+#[test]
+fn test_category_is_parent_module() {
+    let showcase = |module_path| ValidatorShowcase {
+        name: "Non-negative",
+        description: "Validates that the input is a non-negative integer",
+        input_kind: InputKind::Integer,
+        module_path,
+        parameters: &[],
+        create_validator: |input| {
+            input
+                .parse()
+                .map(|value| Box::new(Parsed(value)) as Box<dyn DynValidator>)
+                .map_err(|e| format!("{e}"))
+        },
+    };
+
+    assert_eq!(
+        showcase("my_crate::numeric::non_negative").category(),
+        "numeric"
+    );
+    assert_eq!(showcase("my_crate::numeric").category(), "my_crate");
+    assert_eq!(showcase("my_crate").category(), "my_crate");
+}
+
+inventory::submit! {
+    ValidatorShowcase {
+        name: "Batch non-negative",
+        description: "Validates that the input is a non-negative integer",
+        input_kind: InputKind::Integer,
+        module_path: module_path!(),
+        parameters: &[],
+        create_validator: |input| {
+            input
+                .parse()
+                .map(|value| Box::new(Parsed(value)) as Box<dyn DynValidator>)
+                .map_err(|e| format!("{e}"))
+        },
+    }
+}
+
+#[test]
+fn test_run_batch_reports_each_input() {
+    let inputs = ["12", "-3", "twelve"].map(String::from);
+    let results = run_batch("Batch non-negative", inputs).unwrap();
+    assert_eq!(
+        results,
+        vec![
+            ("12".to_string(), true),
+            ("-3".to_string(), false),
+            ("twelve".to_string(), false),
+        ]
+    );
+
+    let err = run_batch("Missing", Vec::new()).unwrap_err();
+    assert_eq!(err.name(), "Missing");
+    assert_eq!(err.to_string(), "no showcase validator named `Missing`");
+}
@@ -0,0 +1,137 @@
+//! Tests for StreamValidation.
+
+use std::{
+    cell::Cell,
+    io::{self, BufRead, BufReader, Read},
+    rc::Rc,
+};
+
+use koruma_core::{StreamValidation, Validate};
+
+/// Fails lines containing a comma-separated field count other than `fields`.
+struct CsvRowValidator {
+    fields: usize,
+    row: String,
+}
+
+impl Validate<String> for CsvRowValidator {
+    fn validate(&self, value: &String) -> bool {
+        value.split(',').count() == self.fields
+    }
+}
+
+fn csv_rows(fields: usize) -> StreamValidation<impl Fn(String) -> CsvRowValidator> {
+    StreamValidation::new(move |row| CsvRowValidator { fields, row })
+}
+
+#[test]
+fn test_stream_reports_first_failing_line() {
+    let input = "a,b,c\nd,e,f\ng,h\ni,j,k\n";
+    let failure = csv_rows(3)
+        .first_failure(input.as_bytes())
+        .unwrap()
+        .unwrap();
+    assert_eq!(failure.line, 3);
+    assert_eq!(failure.validator.row, "g,h");
+}
+
+#[test]
+fn test_stream_valid_input_has_no_failures() {
+    let input = "a,b,c\nd,e,f\n";
+    assert!(
+        csv_rows(3)
+            .first_failure(input.as_bytes())
+            .unwrap()
+            .is_none()
+    );
+    assert!(csv_rows(3).validate(input.as_bytes()).unwrap().is_empty());
+}
+
+#[test]
+fn test_stream_collects_up_to_max_failures() {
+    let input = "a\nb\nc\nd,e\nf\n";
+    let failures = csv_rows(2)
+        .max_failures(3)
+        .validate(input.as_bytes())
+        .unwrap();
+    let lines: Vec<usize> = failures.iter().map(|f| f.line).collect();
+    assert_eq!(lines, vec![1, 2, 3]);
+
+    let all = csv_rows(2)
+        .max_failures(0)
+        .validate(input.as_bytes())
+        .unwrap();
+    assert_eq!(all.len(), 4);
+}
+
+#[test]
+fn test_stream_final_line_without_trailing_newline() {
+    let input = "a,b\r\nc,d\r\ne";
+    let failure = csv_rows(2)
+        .first_failure(input.as_bytes())
+        .unwrap()
+        .unwrap();
+    assert_eq!(failure.line, 3);
+    assert_eq!(failure.validator.row, "e");
+}
+
+/// Generates `total` rows where only row `broken` is invalid, counting bytes read.
+struct GeneratedRows {
+    next: usize,
+    total: usize,
+    broken: usize,
+    pending: Vec<u8>,
+    bytes_read: Rc<Cell<usize>>,
+}
+
+impl Read for GeneratedRows {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() {
+            if self.next == self.total {
+                return Ok(0);
+            }
+            self.pending = if self.next == self.broken {
+                b"broken row\n".to_vec()
+            } else {
+                format!("{},value,{}\n", self.next, self.next).into_bytes()
+            };
+            self.next += 1;
+        }
+        let len = buf.len().min(self.pending.len());
+        buf[..len].copy_from_slice(&self.pending[..len]);
+        self.pending.drain(..len);
+        self.bytes_read.set(self.bytes_read.get() + len);
+        Ok(len)
+    }
+}
+
+#[test]
+fn test_stream_large_input_stops_at_first_failure() {
+    // ~8 MB of rows with the invalid one in the middle
+    let bytes_read = Rc::new(Cell::new(0));
+    let rows = GeneratedRows {
+        next: 0,
+        total: 400_000,
+        broken: 200_000,
+        pending: Vec::new(),
+        bytes_read: bytes_read.clone(),
+    };
+
+    let failure = csv_rows(3)
+        .first_failure(BufReader::new(rows))
+        .unwrap()
+        .unwrap();
+    assert_eq!(failure.line, 200_001);
+    assert_eq!(failure.validator.row, "broken row");
+
+    // Reading stopped right after the failing line
+    let read = bytes_read.get();
+    assert!(read > 3 << 20);
+    assert!(read < (3 << 20) + (1 << 20));
+}
+
+#[test]
+fn test_stream_accepts_any_bufread() {
+    let reader: Box<dyn BufRead> = Box::new("x,y\n".as_bytes());
+    assert!(csv_rows(2).first_failure(reader).unwrap().is_none());
+}
@@ -1,5 +1,8 @@
 //! Tests for the Validate trait.
 
+// The validators spell out both outcomes of their checks
+#![allow(clippy::needless_bool)]
+
 use koruma_core::Validate;
 
 struct RangeValidator {
@@ -1,6 +1,6 @@
 //! Tests for the ValidationError trait.
 
-use koruma_core::ValidationError;
+use koruma_core::{Failure, FieldPath, Severity, ValidationError};
 
 struct TestError {
     has_age_error: bool,
@@ -39,3 +39,59 @@ fn test_validation_error_has_errors() {
     assert!(!error_with_both.is_empty());
     assert!(error_with_both.has_errors());
 }
+
+#[test]
+fn test_default_has_blocking_errors_without_reported_failures() {
+    // Errors that don't report failures are blocking whenever non-empty
+    let error = TestError {
+        has_age_error: true,
+        has_name_error: false,
+    };
+    assert_eq!(error.iter_failures().count(), 0);
+    assert!(error.has_blocking_errors());
+    assert_eq!(error.count_by_severity(), (0, 0));
+}
+
+/// Error reporting one blocking failure and two warnings.
+struct ReportingError;
+
+impl ValidationError for ReportingError {
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn iter_failures(&self) -> impl Iterator<Item = Failure> {
+        vec![
+            Failure::new(FieldPath::field("age"), "range_validation"),
+            Failure::new(FieldPath::field("name"), "len_validation")
+                .with_severity(Severity::Warning),
+            Failure::new(FieldPath::field("tags").index(0), "len_validation")
+                .with_severity(Severity::Warning),
+        ]
+        .into_iter()
+    }
+}
+
+#[test]
+fn test_count_by_severity() {
+    assert_eq!(ReportingError.count_by_severity(), (1, 2));
+    assert!(ReportingError.has_blocking_errors());
+}
+
+#[test]
+fn test_errors_count_includes_warnings() {
+    assert_eq!(ReportingError.errors_count(), 3);
+}
+
+#[test]
+fn test_field_path_to_json_pointer() {
+    let nested = FieldPath::field("zip_code").prefixed("address");
+    assert_eq!(nested.to_json_pointer(), "/address/zip_code");
+
+    let element = FieldPath::field("tags").index(2);
+    assert_eq!(element.to_json_pointer(), "/tags/2");
+
+    // RFC 6901: `~` becomes `~0` and `/` becomes `~1`
+    let escaped = FieldPath::field("headers").key("content/type~v2");
+    assert_eq!(escaped.to_json_pointer(), "/headers/content~1type~0v2");
+}
@@ -32,15 +32,17 @@ mod tests;
 
 // Re-export parsing types
 pub use parse::{
-    FieldInfo, KorumaAttr, ParseFieldResult, StructOptions, ValidationInfo, ValidatorAttr,
-    find_value_field, parse_field, parse_struct_options,
+    ContextField, FieldInfo, Help, KorumaAttr, ParseFieldResult, Severity, StructOptions,
+    ValidationInfo, ValidatorAttr, find_context_field, find_index_field, find_value_field,
+    parse_field, parse_struct_options,
 };
 
 #[cfg(feature = "showcase")]
-pub use parse::{ShowcaseAttr, find_showcase_attr};
+pub use parse::{ShowcaseAttr, ShowcaseParamInfo, find_showcase_attr, showcase_params};
 
 // Re-export utility functions
 pub use utils::{
     contains_infer_type, expr_as_simple_ident, first_generic_arg, is_option_infer_type,
-    is_option_type, option_inner_type, substitute_infer_type, vec_inner_type,
+    is_option_type, map_entry_types, option_inner_type, sequence_inner_type, substitute_infer_type,
+    vec_inner_type,
 };
@@ -4,8 +4,8 @@
 //! attributes from syn AST nodes.
 
 use syn::{
-    Attribute, Error, Expr, Field, Fields, Ident, Index, ItemStruct, Member, Path, Result, Token,
-    Type, parenthesized,
+    Attribute, Error, Expr, Field, Fields, Ident, Index, ItemStruct, LitStr, Member, Path, Result,
+    Token, Type, Visibility, parenthesized,
     parse::{Parse, ParseStream},
     spanned::Spanned,
     token,
@@ -13,6 +13,89 @@ use syn::{
 
 use syn_cfg_attr::AttributeHelpers;
 
+/// Severity of a validator failure, parsed from `severity = "warning"` or
+/// `severity = "error"`.
+///
+/// Warnings are reported in the error struct but don't make `validate()` fail.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Severity {
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    /// Parse a severity from a string literal.
+    pub fn from_lit(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            other => Err(Error::new(
+                lit.span(),
+                format!(
+                    "unknown severity `{}`. Expected `\"warning\"` or `\"error\"`",
+                    other
+                ),
+            )),
+        }
+    }
+
+    /// Parse a severity from an expression, which must be a string literal.
+    pub fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Self::from_lit(lit),
+            _ => Err(Error::new_spanned(
+                expr,
+                "severity must be a string literal: `\"warning\"` or `\"error\"`",
+            )),
+        }
+    }
+}
+
+/// Help text attached to a validator's failures, parsed from `help = "..."` or
+/// `help = fluent("message-id")`.
+#[derive(Clone, Debug)]
+pub enum Help {
+    /// Literal help text.
+    Text(LitStr),
+    /// A fluent message id, to be localized by the application.
+    Fluent(LitStr),
+}
+
+impl Help {
+    /// Parse help from an expression: a string literal or `fluent("message-id")`.
+    pub fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Ok(Help::Text(lit.clone())),
+            Expr::Call(call)
+                if matches!(&*call.func, Expr::Path(p) if p.path.is_ident("fluent"))
+                    && call.args.len() == 1 =>
+            {
+                match &call.args[0] {
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }) => Ok(Help::Fluent(lit.clone())),
+                    other => Err(Error::new_spanned(
+                        other,
+                        "fluent help must be a string literal message id: `fluent(\"my-help\")`",
+                    )),
+                }
+            },
+            _ => Err(Error::new_spanned(
+                expr,
+                "help must be a string literal or `fluent(\"message-id\")`",
+            )),
+        }
+    }
+}
+
 /// Represents a single parsed validator: `ValidatorName(arg = value, ...)` or
 /// `ValidatorName::<_>(arg = value, ...)` or `ValidatorName::<SomeType>(arg = value, ...)`
 /// Also supports fully-qualified paths like `module::path::ValidatorName::<_>`.
@@ -34,7 +117,25 @@ use syn_cfg_attr::AttributeHelpers;
 ///
 /// // Full path
 /// #[koruma(validators::numeric::RangeValidation::<_>(min = 0))]
+///
+/// // Downgrade failures to warnings
+/// #[koruma(RangeValidation::<_>(min = 0, max = 100, severity = "warning"))]
+///
+/// // Store a redacted copy of the value in the error
+/// #[koruma(LenValidation::<_>(min = 8, redact))]
+///
+/// // Attach help text (or a fluent message id) to the failure
+/// #[koruma(LenValidation::<_>(min = 8, help = "use a longer password"))]
+///
+/// // List this failure first in priority-sorted summaries
+/// #[koruma(LenValidation::<_>(min = 8, priority = 10))]
+///
+/// // Replace the validator's message, `{value}` being the failing value
+/// #[koruma(LenValidation::<_>(min = 1, message = "{value} is too short"))]
 /// ```
+///
+/// The `severity`, `help`, `priority` and `message` keys and the bare `redact`
+/// flag are reserved and are not passed to the validator's builder.
 #[derive(Clone, Debug)]
 pub struct ValidatorAttr {
     /// The validator path, which may be a simple identifier or a full path.
@@ -49,6 +150,21 @@ pub struct ValidatorAttr {
     pub explicit_type: Option<Type>,
     /// Key-value argument pairs passed to the validator.
     pub args: Vec<(Ident, Expr)>,
+    /// Severity override from `severity = "..."`, if given.
+    pub severity: Option<Severity>,
+    /// Whether the value stored in the validator is redacted (`redact` flag).
+    pub redact: bool,
+    /// Help text from `help = "..."` or `help = fluent("...")`, if given.
+    pub help: Option<Help>,
+    /// Summary ordering from `priority = N`, if given. Higher sorts first.
+    pub priority: Option<i32>,
+    /// Message override from `message = "..."`, if given. `{value}` is
+    /// replaced with the stored value. Only the `KorumaAllDisplay` messages
+    /// use it; the validator's own `Display` is unchanged.
+    pub message: Option<LitStr>,
+    /// Name of the struct-level shared config this validator was resolved from,
+    /// if it was referenced as `#[koruma(name)]`.
+    pub shared: Option<Ident>,
 }
 
 impl ValidatorAttr {
@@ -77,6 +193,20 @@ impl ValidatorAttr {
     pub fn has_explicit_type(&self) -> bool {
         self.explicit_type.is_some()
     }
+
+    /// Returns the identifier if this is a bare reference like `name_len`
+    /// (single segment, no type parameter, no args), which may name a shared config.
+    fn shared_reference(&self) -> Option<&Ident> {
+        if self.validator.leading_colon.is_none()
+            && self.validator.segments.len() == 1
+            && !self.infer_type
+            && self.explicit_type.is_none()
+        {
+            Some(self.name())
+        } else {
+            None
+        }
+    }
 }
 
 impl Parse for ValidatorAttr {
@@ -170,6 +300,11 @@ impl Parse for ValidatorAttr {
             (false, None)
         };
 
+        let mut severity = None;
+        let mut redact = false;
+        let mut help = None;
+        let mut priority = None;
+        let mut message = None;
         let args = if input.peek(token::Paren) {
             let content;
             parenthesized!(content in input);
@@ -177,10 +312,27 @@ impl Parse for ValidatorAttr {
             let mut args = Vec::new();
             while !content.is_empty() {
                 let name: Ident = content.parse()?;
+                if name == "redact" && !content.peek(Token![=]) {
+                    redact = true;
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
                 content.parse::<Token![=]>()?;
                 let value: Expr = content.parse()?;
 
-                args.push((name, value));
+                if name == "severity" {
+                    severity = Some(Severity::from_expr(&value)?);
+                } else if name == "help" {
+                    help = Some(Help::from_expr(&value)?);
+                } else if name == "priority" {
+                    priority = Some(parse_priority(&value)?);
+                } else if name == "message" {
+                    message = Some(parse_message(&value)?);
+                } else {
+                    args.push((name, value));
+                }
 
                 if content.peek(Token![,]) {
                     content.parse::<Token![,]>()?;
@@ -196,18 +348,87 @@ impl Parse for ValidatorAttr {
             infer_type,
             explicit_type,
             args,
+            severity,
+            redact,
+            help,
+            priority,
+            message,
+            shared: None,
         })
     }
 }
 
+/// Parse a `priority = N` value, which must be an integer literal (optionally negated).
+fn parse_priority(expr: &Expr) -> Result<i32> {
+    let (negative, lit) = match expr {
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, expr.as_ref()),
+        expr => (false, expr),
+    };
+    match lit {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => {
+            let value: i32 = int.base10_parse()?;
+            Ok(if negative { -value } else { value })
+        },
+        _ => Err(Error::new_spanned(
+            expr,
+            "priority must be an integer literal, e.g. `priority = 10`",
+        )),
+    }
+}
+
+/// Parse a `message = "..."` value, which must be a string literal.
+fn parse_message(expr: &Expr) -> Result<LitStr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(lit.clone()),
+        _ => Err(Error::new_spanned(
+            expr,
+            "message must be a string literal, e.g. `message = \"name is required\"`",
+        )),
+    }
+}
+
+/// How a `#[koruma(context)]` field is supplied when constructing through `try_new`.
+#[derive(Clone, Debug)]
+pub enum ContextField {
+    /// `#[koruma(context)]`: `try_new` takes the field as a parameter.
+    Param,
+    /// `#[koruma(context = expr)]`: `try_new` computes the field from `expr`,
+    /// which may refer to the other constructor parameters.
+    Computed(Expr),
+}
+
 /// Represents a parsed `#[koruma(...)]` attribute which can contain multiple validators
 /// separated by commas: `#[koruma(Validator1(a = 1), Validator2(b = 2))]`
 ///
 /// Can also include:
 /// - `each(...)` modifier for collection validation
+/// - `entries(...)` modifier for validating `(key, value)` pairs of a map
 /// - `skip` to skip validation for a field
 /// - `nested` to validate nested structs that also derive Koruma
 /// - `newtype` to validate a newtype wrapper with transparent error access
+/// - `context` or `context = expr` for validation-only inputs that aren't validated themselves
+/// - `severity = "warning"` to set the default severity of the field's validators
+/// - `redact` to store redacted copies of the value in the field's validators
+/// - `normalize = path` to mutate the field with `path(&mut field)` after
+///   `validate_and_normalize` succeeds
+/// - `when = predicate` to only run the field's validators if
+///   `predicate(&self)` returns `true`
+/// - `async` to run the field's validators through `AsyncValidate`, making the
+///   struct's `validate()` async
+/// - `required` on an `Option` field to fail with `RequiredValidation` when it
+///   is `None`, while the other validators check the inner value
+/// - `rename = "..."` to report the field under another name in failure paths
+///   and serialized errors
 ///
 /// # Examples
 ///
@@ -223,13 +444,41 @@ impl Parse for ValidatorAttr {
 ///
 /// // Nested Koruma struct
 /// #[koruma(nested)]
+///
+/// // Only used as an input to other fields' validators, computed by `try_new`
+/// #[koruma(context = config.max_name_len)]
+///
+/// // All validators on the field report warnings
+/// #[koruma(severity = "warning", Validator1(a = 1), Validator2(b = 2))]
+///
+/// // Failure messages never show the raw value
+/// #[koruma(redact, Validator1(a = 1))]
+///
+/// // Lowercased in place once the struct validates
+/// #[koruma(Validator1(a = 1), normalize = str::make_ascii_lowercase)]
+///
+/// // Only validated for US addresses
+/// #[koruma(when = |this: &Self| this.country == "US", Validator1(a = 1))]
+///
+/// // Checked against a database
+/// #[koruma(async, UniqueUsernameValidation)]
+///
+/// // `None` fails `RequiredValidation`; `Some` is length-checked
+/// #[koruma(required, Validator1(min = 1))]
+///
+/// // Failures are reported under `zipCode`
+/// #[koruma(rename = "zipCode", Validator1(a = 1))]
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct KorumaAttr {
     /// Validators applied to the field/collection itself
     pub field_validators: Vec<ValidatorAttr>,
     /// Validators applied to each element in a collection (from `each(...)`)
+    /// or to each map entry (from `entries(...)`)
     pub element_validators: Vec<ValidatorAttr>,
+    /// Whether `element_validators` came from `entries(...)` and run over
+    /// `(key, value)` pairs
+    pub entries: bool,
     /// Whether this field should be skipped
     pub is_skip: bool,
     /// Whether this field is a nested Koruma struct
@@ -237,6 +486,22 @@ pub struct KorumaAttr {
     /// Whether this field is a newtype wrapper (single-field struct deriving Koruma).
     /// Similar to nested, but generates a wrapper error struct with Deref for transparent access.
     pub is_newtype: bool,
+    /// Set if this field is a validation-only input (`context` or `context = expr`).
+    pub context: Option<ContextField>,
+    /// Field-level default severity from `severity = "..."`
+    pub severity: Option<Severity>,
+    /// Whether all validators on the field store redacted values
+    pub redact: bool,
+    /// Normalizer from `normalize = path`, called as `path(&mut field)`
+    pub normalize: Option<Expr>,
+    /// Predicate from `when = expr`, called as `expr(&self)`
+    pub when: Option<Expr>,
+    /// Whether the validators are `AsyncValidate` validators, from `async`
+    pub is_async: bool,
+    /// Whether `None` fails `RequiredValidation`, from `required`
+    pub required: bool,
+    /// External name of the field, from `rename = "..."`
+    pub rename: Option<LitStr>,
 }
 
 impl KorumaAttr {
@@ -245,67 +510,166 @@ impl KorumaAttr {
         !self.field_validators.is_empty() || !self.element_validators.is_empty()
     }
 
-    /// Returns whether this attribute represents a modifier (skip, nested, newtype).
+    /// Returns whether this attribute represents a modifier (skip, nested, newtype, context).
     pub fn is_modifier(&self) -> bool {
-        self.is_skip || self.is_nested || self.is_newtype
+        self.is_skip || self.is_nested || self.is_newtype || self.context.is_some()
     }
 }
 
 impl Parse for KorumaAttr {
     fn parse(input: ParseStream) -> Result<Self> {
-        // Check for skip, nested, or newtype
+        // Check for skip, nested, newtype, or context
         if input.peek(Ident) {
             let fork = input.fork();
             let ident: Ident = fork.parse()?;
             if ident == "skip" && fork.is_empty() {
                 input.parse::<Ident>()?; // consume "skip"
                 return Ok(KorumaAttr {
-                    field_validators: Vec::new(),
-                    element_validators: Vec::new(),
                     is_skip: true,
-                    is_nested: false,
-                    is_newtype: false,
+                    ..Default::default()
                 });
             }
             // Check for nested
             if ident == "nested" && fork.is_empty() {
                 input.parse::<Ident>()?; // consume "nested"
                 return Ok(KorumaAttr {
-                    field_validators: Vec::new(),
-                    element_validators: Vec::new(),
-                    is_skip: false,
                     is_nested: true,
-                    is_newtype: false,
+                    ..Default::default()
                 });
             }
             // Check for newtype
             if ident == "newtype" && fork.is_empty() {
                 input.parse::<Ident>()?; // consume "newtype"
                 return Ok(KorumaAttr {
-                    field_validators: Vec::new(),
-                    element_validators: Vec::new(),
-                    is_skip: false,
-                    is_nested: false,
                     is_newtype: true,
+                    ..Default::default()
+                });
+            }
+            // Check for context or context = expr
+            if ident == "context" && fork.is_empty() {
+                input.parse::<Ident>()?; // consume "context"
+                return Ok(KorumaAttr {
+                    context: Some(ContextField::Param),
+                    ..Default::default()
+                });
+            }
+            if ident == "context" && fork.peek(Token![=]) {
+                input.parse::<Ident>()?; // consume "context"
+                input.parse::<Token![=]>()?;
+                let expr: Expr = input.parse()?;
+                if !input.is_empty() {
+                    return Err(Error::new(
+                        input.span(),
+                        "`context` fields can't have validators; validate the fields that use them instead",
+                    ));
+                }
+                return Ok(KorumaAttr {
+                    context: Some(ContextField::Computed(expr)),
+                    ..Default::default()
                 });
             }
         }
 
         let mut field_validators = Vec::new();
         let mut element_validators = Vec::new();
-
-        // Parse comma-separated items (validators or each(...))
+        let mut element_block: Option<Ident> = None;
+        let mut severity = None;
+        let mut redact = false;
+        let mut normalize = None;
+        let mut when = None;
+        let mut is_async = false;
+        let mut required = false;
+        let mut rename = None;
+
+        // Parse comma-separated items (validators, each(...), entries(...),
+        // severity = "...", rename = "...", normalize = path, when = expr,
+        // redact, required or async)
         while !input.is_empty() {
-            // Check if this is an `each(...)` block
+            if input.peek(Token![async]) {
+                input.parse::<Token![async]>()?;
+                is_async = true;
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+
+            // Check if this is an `each(...)` or `entries(...)` block, a
+            // `severity = "..."` option or `redact`
             if input.peek(Ident) {
                 let fork = input.fork();
                 let ident: Ident = fork.parse()?;
-                if ident == "each" && fork.peek(token::Paren) {
-                    input.parse::<Ident>()?; // consume "each"
+                if ident == "redact" && (fork.is_empty() || fork.peek(Token![,])) {
+                    input.parse::<Ident>()?; // consume "redact"
+                    redact = true;
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if ident == "required" && (fork.is_empty() || fork.peek(Token![,])) {
+                    input.parse::<Ident>()?; // consume "required"
+                    required = true;
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if ident == "normalize" && fork.peek(Token![=]) {
+                    input.parse::<Ident>()?; // consume "normalize"
+                    input.parse::<Token![=]>()?;
+                    normalize = Some(input.parse::<Expr>()?);
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if ident == "when" && fork.peek(Token![=]) {
+                    input.parse::<Ident>()?; // consume "when"
+                    input.parse::<Token![=]>()?;
+                    when = Some(input.parse::<Expr>()?);
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if ident == "rename" && fork.peek(Token![=]) {
+                    input.parse::<Ident>()?; // consume "rename"
+                    input.parse::<Token![=]>()?;
+                    let lit: LitStr = input.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(Error::new(lit.span(), "`rename` can't be empty"));
+                    }
+                    rename = Some(lit);
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if ident == "severity" && fork.peek(Token![=]) {
+                    input.parse::<Ident>()?; // consume "severity"
+                    input.parse::<Token![=]>()?;
+                    severity = Some(Severity::from_lit(&input.parse::<LitStr>()?)?);
+                    if input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+                    continue;
+                }
+                if (ident == "each" || ident == "entries") && fork.peek(token::Paren) {
+                    let block: Ident = input.parse()?; // consume "each" / "entries"
+                    if let Some(ref previous) = element_block
+                        && *previous != block
+                    {
+                        return Err(Error::new(
+                            block.span(),
+                            "`each(...)` and `entries(...)` can't be combined on one field",
+                        ));
+                    }
+                    element_block = Some(block);
                     let content;
                     parenthesized!(content in input);
 
-                    // Parse validators inside each(...)
+                    // Parse validators inside each(...) / entries(...)
                     while !content.is_empty() {
                         element_validators.push(content.parse::<ValidatorAttr>()?);
                         if content.peek(Token![,]) {
@@ -315,7 +679,7 @@ impl Parse for KorumaAttr {
                         }
                     }
 
-                    // Continue parsing after each(...)
+                    // Continue parsing after the block
                     if input.peek(Token![,]) {
                         input.parse::<Token![,]>()?;
                     }
@@ -335,9 +699,15 @@ impl Parse for KorumaAttr {
         Ok(KorumaAttr {
             field_validators,
             element_validators,
-            is_skip: false,
-            is_nested: false,
-            is_newtype: false,
+            entries: element_block.is_some_and(|block| block == "entries"),
+            severity,
+            redact,
+            normalize,
+            when,
+            is_async,
+            required,
+            rename,
+            ..Default::default()
         })
     }
 }
@@ -357,10 +727,64 @@ impl Parse for KorumaAttr {
 /// #[derive(Koruma)]
 /// struct Email(String);
 ///
+/// // Implement `validator::Validate` (requires the `validator-compat` feature)
+/// #[koruma(compat_validator)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
 /// // Both options
 /// #[koruma(try_new, newtype)]
 /// #[derive(Koruma)]
 /// struct Email(String);
+///
+/// // Generate a `UserPatch` struct and `User::validate_patch`
+/// #[koruma(patch)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Derive a bon builder with a validating `build_validated()`
+/// #[koruma(builder)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Field getters return `Result<(), FieldValidator>` instead of the
+/// // per-field error struct
+/// #[koruma(typed_errors)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Generate `validate_and_normalize`, which runs the fields'
+/// // `normalize = ...` functions after validation succeeds
+/// #[koruma(normalize)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Generate `into_validated`, which consumes the struct and returns a
+/// // `koruma::Validated<User>` proving it passed validation
+/// #[koruma(validated_wrapper)]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Checks spanning several fields, stored under the returned error's key
+/// #[koruma(cross_field(|this: &Self| {
+///     if this.password == this.confirm_password {
+///         Ok(())
+///     } else {
+///         Err(koruma::KeyedError::new("passwords_match", "passwords don't match"))
+///     }
+/// }))]
+/// #[derive(Koruma)]
+/// struct Signup { ... }
+///
+/// // Make the generated error structs and methods crate-private
+/// #[koruma(visibility = "pub(crate)")]
+/// #[derive(Koruma)]
+/// struct User { ... }
+///
+/// // Named validator configs, referenced on fields as `#[koruma(name_len)]`
+/// #[koruma(shared(name_len = LenValidation::<_>(min = 1, max = 50)))]
+/// #[derive(Koruma)]
+/// struct Person { ... }
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct StructOptions {
@@ -370,6 +794,111 @@ pub struct StructOptions {
     /// Generates an `.all()` method on the error struct that aggregates
     /// all validators from the single field.
     pub newtype: bool,
+    /// Implement `validator::Validate` by converting the generated error.
+    pub compat_validator: bool,
+    /// Generate a `{Struct}Patch` struct (every field optional) and a
+    /// `validate_patch` function that only validates the fields it sets.
+    pub patch: bool,
+    /// Generate a bon builder (`Struct::builder()`) whose `build_validated()`
+    /// constructs and validates the struct.
+    pub builder: bool,
+    /// Make the error struct's getters for fields with only field-level
+    /// validators return `Result<(), {Struct}{Field}KorumaValidator>`.
+    pub typed_errors: bool,
+    /// Generate `validate_and_normalize(&mut self)`, which applies the
+    /// fields' `normalize = ...` functions once validation succeeds.
+    pub normalize: bool,
+    /// Generate `into_validated(self)`, which returns the struct wrapped in
+    /// `koruma::Validated` once it passes validation.
+    pub validated_wrapper: bool,
+    /// Visibility of the generated error structs and methods, from
+    /// `visibility = "..."` (`pub` if unset).
+    pub visibility: Option<Visibility>,
+    /// Named validator configs from `shared(name = Validator(...), ...)`.
+    pub shared: Vec<(Ident, ValidatorAttr)>,
+    /// Checks from `cross_field(...)`, each a `|this: &Self| -> Result<(), KeyedError>`
+    /// closure run after the field validators.
+    pub cross_field: Vec<Expr>,
+}
+
+impl StructOptions {
+    /// Returns the visibility of the generated API, `pub` by default.
+    pub fn vis(&self) -> Visibility {
+        self.visibility
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(pub))
+    }
+
+    /// Returns the shared config registered under `name`, if any.
+    pub fn shared_config(&self, name: &Ident) -> Option<&ValidatorAttr> {
+        self.shared
+            .iter()
+            .find(|(shared_name, _)| shared_name == name)
+            .map(|(_, validator)| validator)
+    }
+
+    /// Replaces references to shared configs in `info` with the configured validators.
+    ///
+    /// A reference may carry its own `severity = "..."`, `help = "..."`,
+    /// `priority = N` and `message = "..."`, which override the config's, and a
+    /// `redact` flag. Any other argument is an error, as is ending up with
+    /// the same validator twice on one field.
+    pub fn resolve_shared(&self, info: &mut FieldInfo) -> Result<()> {
+        if self.shared.is_empty() {
+            return Ok(());
+        }
+
+        let field_name = info.name.to_string();
+        for (validators, kind) in [
+            (&mut info.validation.field_validators, "validator"),
+            (&mut info.validation.element_validators, "element validator"),
+        ] {
+            let mut seen = std::collections::HashSet::new();
+            for validator in validators.iter_mut() {
+                if let Some(name) = validator.shared_reference()
+                    && let Some(config) = self.shared_config(name)
+                {
+                    if let Some((arg, _)) = validator.args.first() {
+                        return Err(Error::new(
+                            arg.span(),
+                            format!(
+                                "shared config `{}` doesn't take arguments other than `severity`, `help`, `priority` and `message`",
+                                name
+                            ),
+                        ));
+                    }
+                    let name = name.clone();
+                    let severity = validator.severity.or(config.severity);
+                    let redact = validator.redact || config.redact;
+                    let help = validator.help.take().or_else(|| config.help.clone());
+                    let priority = validator.priority.or(config.priority);
+                    let message = validator.message.take().or_else(|| config.message.clone());
+                    *validator = ValidatorAttr {
+                        severity,
+                        redact,
+                        help,
+                        priority,
+                        message,
+                        shared: Some(name),
+                        ..config.clone()
+                    };
+                }
+
+                let validator_name = validator.name().to_string();
+                if !seen.insert(validator_name.clone()) {
+                    return Err(Error::new(
+                        validator.validator.span(),
+                        format!(
+                            "duplicate {} `{}` on field `{}`",
+                            kind, validator_name, field_name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Parse for StructOptions {
@@ -381,11 +910,56 @@ impl Parse for StructOptions {
             match ident.to_string().as_str() {
                 "try_new" => options.try_new = true,
                 "newtype" => options.newtype = true,
+                "compat_validator" => options.compat_validator = true,
+                "patch" => options.patch = true,
+                "builder" => options.builder = true,
+                "typed_errors" => options.typed_errors = true,
+                "normalize" => options.normalize = true,
+                "validated_wrapper" => options.validated_wrapper = true,
+                "visibility" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: LitStr = input.parse()?;
+                    let visibility = lit.parse::<Visibility>().map_err(|_| {
+                        Error::new(
+                            lit.span(),
+                            format!(
+                                "invalid visibility `{}`. Expected e.g. `pub`, `pub(crate)` or `pub(super)`",
+                                lit.value()
+                            ),
+                        )
+                    })?;
+                    options.visibility = Some(visibility);
+                },
+                "shared" => {
+                    let content;
+                    parenthesized!(content in input);
+                    while !content.is_empty() {
+                        let name: Ident = content.parse()?;
+                        content.parse::<Token![=]>()?;
+                        let validator: ValidatorAttr = content.parse()?;
+                        if options.shared.iter().any(|(existing, _)| *existing == name) {
+                            return Err(Error::new(
+                                name.span(),
+                                format!("duplicate shared config `{}`", name),
+                            ));
+                        }
+                        options.shared.push((name, validator));
+                        if content.peek(Token![,]) {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                },
+                "cross_field" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let checks = content.parse_terminated(Expr::parse, Token![,])?;
+                    options.cross_field.extend(checks);
+                },
                 other => {
                     return Err(Error::new(
                         ident.span(),
                         format!(
-                            "unknown struct-level koruma option: `{}`. Expected `try_new` or `newtype`",
+                            "unknown struct-level koruma option: `{}`. Expected `try_new`, `newtype`, `compat_validator`, `patch`, `builder`, `typed_errors`, `normalize`, `validated_wrapper`, `visibility`, `shared` or `cross_field`",
                             other
                         ),
                     ));
@@ -403,13 +977,35 @@ impl Parse for StructOptions {
 
 /// Parse struct-level `#[koruma(...)]` attributes from a list of attributes.
 ///
+/// Options from multiple `#[koruma(...)]` attributes are merged.
 /// Returns `StructOptions::default()` if no `#[koruma(...)]` attribute is found.
 pub fn parse_struct_options(attrs: &[Attribute]) -> Result<StructOptions> {
-    if let Some(attr) = attrs.to_vec().find_attribute("koruma").first() {
-        attr.parse_args::<StructOptions>()
-    } else {
-        Ok(StructOptions::default())
+    let mut options = StructOptions::default();
+    for attr in attrs.to_vec().find_attribute("koruma") {
+        let parsed = attr.parse_args::<StructOptions>()?;
+        options.try_new |= parsed.try_new;
+        options.newtype |= parsed.newtype;
+        options.compat_validator |= parsed.compat_validator;
+        options.patch |= parsed.patch;
+        options.builder |= parsed.builder;
+        options.typed_errors |= parsed.typed_errors;
+        options.normalize |= parsed.normalize;
+        options.validated_wrapper |= parsed.validated_wrapper;
+        if parsed.visibility.is_some() {
+            options.visibility = parsed.visibility;
+        }
+        for (name, validator) in parsed.shared {
+            if options.shared_config(&name).is_some() {
+                return Err(Error::new(
+                    name.span(),
+                    format!("duplicate shared config `{}`", name),
+                ));
+            }
+            options.shared.push((name, validator));
+        }
+        options.cross_field.extend(parsed.cross_field);
     }
+    Ok(options)
 }
 
 /// Validation information extracted from `#[koruma(...)]` attributes.
@@ -417,12 +1013,27 @@ pub fn parse_struct_options(attrs: &[Attribute]) -> Result<StructOptions> {
 pub struct ValidationInfo {
     /// Validators for the field/collection itself
     pub field_validators: Vec<ValidatorAttr>,
-    /// Validators for each element in a collection
+    /// Validators for each element in a collection, or each map entry
     pub element_validators: Vec<ValidatorAttr>,
+    /// Whether the element validators run over map `(key, value)` entries
+    /// (`entries(...)`) instead of collection elements (`each(...)`)
+    pub entries: bool,
     /// Whether this field is a nested Koruma struct
     pub is_nested: bool,
     /// Whether this field is a newtype wrapper
     pub is_newtype: bool,
+    /// Default severity for this field's validators
+    pub severity: Option<Severity>,
+    /// Whether all validators on this field store redacted values
+    pub redact: bool,
+    /// Normalizer applied by `validate_and_normalize`, from `normalize = path`
+    pub normalize: Option<Expr>,
+    /// Predicate deciding whether the field is validated, from `when = expr`
+    pub when: Option<Expr>,
+    /// Whether the validators run through `AsyncValidate`, from `async`
+    pub is_async: bool,
+    /// External name of the field, from `rename = "..."`
+    pub rename: Option<LitStr>,
 }
 
 /// Field information extracted from parsing `#[koruma(...)]` attributes.
@@ -447,12 +1058,34 @@ impl FieldInfo {
         !self.validation.element_validators.is_empty()
     }
 
+    /// Returns true if this field's element validators run over map entries
+    /// (uses `entries(...)`)
+    pub fn validates_entries(&self) -> bool {
+        self.validation.entries && self.has_element_validators()
+    }
+
+    /// Returns true if this field's element validators run over a `HashMap`
+    /// or `BTreeMap`, with `entries(...)` or with `each(...)` on its values.
+    /// Failures are then keyed by the map key instead of an index.
+    pub fn validates_map(&self) -> bool {
+        self.has_element_validators() && crate::utils::map_entry_types(&self.ty).is_some()
+    }
+
     /// Returns true if this field has any validators (field or element)
     pub fn has_validators(&self) -> bool {
         !self.validation.field_validators.is_empty()
             || !self.validation.element_validators.is_empty()
     }
 
+    /// Returns the name the field is reported under in failure paths and
+    /// serialized errors: its `rename = "..."` if set, else its Rust name.
+    pub fn external_name(&self) -> String {
+        self.validation
+            .rename
+            .as_ref()
+            .map_or_else(|| self.name.to_string(), LitStr::value)
+    }
+
     /// Returns true if this field is a nested Koruma struct
     pub fn is_nested(&self) -> bool {
         self.validation.is_nested
@@ -463,6 +1096,27 @@ impl FieldInfo {
         self.validation.is_newtype
     }
 
+    /// Returns true if this field's validators are `AsyncValidate` validators
+    pub fn is_async(&self) -> bool {
+        self.validation.is_async
+    }
+
+    /// Returns the effective severity of a validator on this field.
+    ///
+    /// A per-validator severity overrides the field-level default.
+    pub fn severity_of(&self, validator: &ValidatorAttr) -> Severity {
+        validator
+            .severity
+            .or(self.validation.severity)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether a validator on this field stores a redacted value,
+    /// either from its own `redact` flag or the field-level one.
+    pub fn redacts(&self, validator: &ValidatorAttr) -> bool {
+        validator.redact || self.validation.redact
+    }
+
     /// Returns an iterator over all validator names on this field.
     pub fn validator_names(&self) -> impl Iterator<Item = &Ident> {
         self.validation
@@ -551,9 +1205,17 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
     // Collect validators from ALL #[koruma(...)] attributes on this field
     let mut all_field_validators = Vec::new();
     let mut all_element_validators = Vec::new();
+    let mut entries = None;
     let mut is_skip = false;
     let mut is_nested = false;
     let mut is_newtype = false;
+    let mut severity = None;
+    let mut redact = false;
+    let mut normalize = None;
+    let mut when = None;
+    let mut is_async = false;
+    let mut required = false;
+    let mut rename = None;
 
     // Track seen validator names to detect duplicates
     let mut seen_field_validators = std::collections::HashSet::new();
@@ -580,6 +1242,38 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
                     is_newtype = true;
                     continue;
                 }
+                // Context fields are inputs to other validators, not validated themselves
+                if koruma_attr.context.is_some() {
+                    is_skip = true;
+                    continue;
+                }
+                if koruma_attr.severity.is_some() {
+                    severity = koruma_attr.severity;
+                }
+                redact |= koruma_attr.redact;
+                if koruma_attr.normalize.is_some() {
+                    normalize = koruma_attr.normalize;
+                }
+                if koruma_attr.when.is_some() {
+                    when = koruma_attr.when;
+                }
+                is_async |= koruma_attr.is_async;
+                required |= koruma_attr.required;
+                if koruma_attr.rename.is_some() {
+                    rename = koruma_attr.rename;
+                }
+                if !koruma_attr.element_validators.is_empty() {
+                    if entries.is_some_and(|entries| entries != koruma_attr.entries) {
+                        return ParseFieldResult::Error(Error::new(
+                            field.span(),
+                            format!(
+                                "`each(...)` and `entries(...)` can't be combined on field `{}`",
+                                name
+                            ),
+                        ));
+                    }
+                    entries = Some(koruma_attr.entries);
+                }
                 // Collect validators from this attribute, checking for duplicates
                 for validator in koruma_attr.field_validators {
                     let validator_name = validator.name().to_string();
@@ -619,6 +1313,33 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
         return ParseFieldResult::Skip;
     }
 
+    // `required` checks the whole `Option` with `RequiredValidation`, ahead of
+    // the validators that check the inner value
+    if required {
+        let error = if is_nested || is_newtype {
+            Some(format!(
+                "`required` can't be combined with `nested` or `newtype` on field `{}`",
+                name
+            ))
+        } else if !crate::utils::is_option_type(&ty) {
+            Some(format!(
+                "`required` only applies to `Option` fields; field `{}` is always present",
+                name
+            ))
+        } else if seen_field_validators.contains("RequiredValidation") {
+            Some(format!(
+                "`required` already adds `RequiredValidation` to field `{}`",
+                name
+            ))
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            return ParseFieldResult::Error(Error::new(field.span(), error));
+        }
+        all_field_validators.insert(0, syn::parse_quote!(RequiredValidation::<Option<_>>));
+    }
+
     // Check for nested
     if is_nested {
         return ParseFieldResult::Valid(Box::new(FieldInfo {
@@ -628,8 +1349,15 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
             validation: ValidationInfo {
                 field_validators: all_field_validators,
                 element_validators: all_element_validators,
+                entries: entries.unwrap_or_default(),
                 is_nested: true,
                 is_newtype: false,
+                severity,
+                redact,
+                normalize,
+                when,
+                is_async: false,
+                rename,
             },
         }));
     }
@@ -643,14 +1371,57 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
             validation: ValidationInfo {
                 field_validators: all_field_validators,
                 element_validators: all_element_validators,
+                entries: entries.unwrap_or_default(),
                 is_nested: false,
                 is_newtype: true,
+                severity,
+                redact,
+                normalize,
+                when,
+                is_async: false,
+                rename,
             },
         }));
     }
 
     // Must have at least one validator or modifier
     if all_field_validators.is_empty() && all_element_validators.is_empty() {
+        if normalize.is_some() {
+            return ParseFieldResult::Error(Error::new(
+                field.span(),
+                format!(
+                    "`normalize` only runs after validation; field `{}` needs at least one validator",
+                    name
+                ),
+            ));
+        }
+        if when.is_some() {
+            return ParseFieldResult::Error(Error::new(
+                field.span(),
+                format!(
+                    "`when` decides whether validators run; field `{}` needs at least one validator",
+                    name
+                ),
+            ));
+        }
+        if is_async {
+            return ParseFieldResult::Error(Error::new(
+                field.span(),
+                format!(
+                    "`async` marks the field's validators as async; field `{}` needs at least one validator",
+                    name
+                ),
+            ));
+        }
+        if rename.is_some() {
+            return ParseFieldResult::Error(Error::new(
+                field.span(),
+                format!(
+                    "`rename` names the field in failure paths; field `{}` needs at least one validator",
+                    name
+                ),
+            ));
+        }
         return ParseFieldResult::Skip;
     }
 
@@ -661,22 +1432,67 @@ pub fn parse_field(field: &Field, index: usize) -> ParseFieldResult {
         validation: ValidationInfo {
             field_validators: all_field_validators,
             element_validators: all_element_validators,
+            entries: entries.unwrap_or_default(),
             is_nested: false,
             is_newtype: false,
+            severity,
+            redact,
+            normalize,
+            when,
+            is_async,
+            rename,
         },
     }))
 }
 
+/// Returns how a `#[koruma(context)]` field is supplied, or `None` if the field
+/// isn't marked as context.
+///
+/// A context field can't also carry validators or other modifiers.
+pub fn find_context_field(field: &Field) -> Result<Option<ContextField>> {
+    let mut context = None;
+    let mut has_other = false;
+    for attr in field.attrs.to_vec().find_attribute("koruma") {
+        let parsed = attr.parse_args::<KorumaAttr>()?;
+        match parsed.context {
+            Some(_) if context.is_some() => {
+                return Err(Error::new(field.span(), "duplicate `context` attribute"));
+            },
+            Some(field_context) => context = Some(field_context),
+            None => has_other = true,
+        }
+    }
+    if context.is_some() && has_other {
+        return Err(Error::new(
+            field.span(),
+            "`context` fields can't have validators; validate the fields that use them instead",
+        ));
+    }
+    Ok(context)
+}
+
 /// Find the field marked with `#[koruma(value)]` and return its name and type.
 ///
 /// This is used by the `#[koruma::validator]` attribute macro to find which
 /// field should receive the value being validated.
 pub fn find_value_field(input: &ItemStruct) -> Option<(Ident, Type)> {
+    find_marked_field(input, "value")
+}
+
+/// Find the field marked with `#[koruma(index)]` and return its name and type.
+///
+/// This is used by the `#[koruma::validator]` attribute macro to find which
+/// field should receive the index of the failing element in `each(...)`.
+pub fn find_index_field(input: &ItemStruct) -> Option<(Ident, Type)> {
+    find_marked_field(input, "index")
+}
+
+fn find_marked_field(input: &ItemStruct, marker: &str) -> Option<(Ident, Type)> {
     if let Fields::Named(ref fields) = input.fields {
         for field in &fields.named {
             if let Some(attr) = field.attrs.to_vec().find_attribute("koruma").first()
                 && let Ok(ident) = attr.parse_args::<Ident>()
-                && ident == "value"
+                && ident == marker
             {
                 return Some((field.ident.clone().unwrap(), field.ty.clone()));
             }
@@ -688,14 +1504,19 @@ pub fn find_value_field(input: &ItemStruct) -> Option<(Ident, Type)> {
 /// Parsed showcase attribute: `#[showcase(name = "...", description = "...", create = |input| { ... })]`
 ///
 /// The `create` closure takes a `&str` and returns the validator instance.
-/// Optional `input_type` can be "text" (default) or "numeric".
+/// `try_create = |input| { ... }` can replace it for a closure that returns
+/// `Result<Validator, String>`, rejecting input it can't build from.
+/// Optional `input` is the kind of input it's built from: `String` (default),
+/// `Integer`, `Float` or `Bool`.
 #[cfg(feature = "showcase")]
 #[derive(Clone, Debug)]
 pub struct ShowcaseAttr {
     pub name: syn::LitStr,
     pub description: syn::LitStr,
     pub create: syn::ExprClosure,
-    pub input_type: Option<Ident>,
+    /// Whether `create` came from `try_create` and returns a `Result`
+    pub fallible: bool,
+    pub input: Option<Ident>,
 }
 
 #[cfg(feature = "showcase")]
@@ -703,8 +1524,8 @@ impl Parse for ShowcaseAttr {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut name: Option<syn::LitStr> = None;
         let mut description: Option<syn::LitStr> = None;
-        let mut create: Option<syn::ExprClosure> = None;
-        let mut input_type: Option<Ident> = None;
+        let mut create: Option<(syn::ExprClosure, bool)> = None;
+        let mut input_kind: Option<Ident> = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -717,11 +1538,28 @@ impl Parse for ShowcaseAttr {
                 "description" => {
                     description = Some(input.parse()?);
                 },
-                "create" => {
-                    create = Some(input.parse()?);
+                key @ ("create" | "try_create") => {
+                    if create.is_some() {
+                        return Err(Error::new(
+                            ident.span(),
+                            "showcase accepts only one of `create` and `try_create`",
+                        ));
+                    }
+                    create = Some((input.parse()?, key == "try_create"));
                 },
-                "input_type" => {
-                    input_type = Some(input.parse()?);
+                "input" => {
+                    let kind: Ident = input.parse()?;
+                    if !["String", "Integer", "Float", "Bool"].contains(&kind.to_string().as_str())
+                    {
+                        return Err(Error::new(
+                            kind.span(),
+                            format!(
+                                "unknown showcase input kind `{}`. Expected `String`, `Integer`, `Float` or `Bool`",
+                                kind
+                            ),
+                        ));
+                    }
+                    input_kind = Some(kind);
                 },
                 other => {
                     return Err(Error::new(
@@ -736,15 +1574,22 @@ impl Parse for ShowcaseAttr {
             }
         }
 
+        let (create, fallible) = create.ok_or_else(|| {
+            Error::new(
+                input.span(),
+                "showcase requires a `create` or `try_create` attribute",
+            )
+        })?;
+
         Ok(ShowcaseAttr {
             name: name
                 .ok_or_else(|| Error::new(input.span(), "showcase requires `name` attribute"))?,
             description: description.ok_or_else(|| {
                 Error::new(input.span(), "showcase requires `description` attribute")
             })?,
-            create: create
-                .ok_or_else(|| Error::new(input.span(), "showcase requires `create` attribute"))?,
-            input_type,
+            create,
+            fallible,
+            input: input_kind,
         })
     }
 }
@@ -761,3 +1606,97 @@ pub fn find_showcase_attr(input: &ItemStruct) -> Option<ShowcaseAttr> {
     }
     None
 }
+
+/// A configurable parameter of a showcase validator, from a field its
+/// builder sets.
+#[cfg(feature = "showcase")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShowcaseParamInfo {
+    /// The field name
+    pub name: String,
+    /// The field type, as written in the struct
+    pub type_name: String,
+    /// Source of the field's builder default, if it has one
+    pub default: Option<String>,
+}
+
+/// List the builder parameters of a validator struct, in declaration order.
+///
+/// Every named field counts except the `#[koruma(value)]` and
+/// `#[koruma(index)]` fields, a `field_name` field and fields marked
+/// `#[builder(skip)]`. `#[builder(default)]` gives a default of
+/// `Default::default()`, `#[builder(default = expr)]` the source of `expr`.
+#[cfg(feature = "showcase")]
+pub fn showcase_params(input: &ItemStruct) -> Vec<ShowcaseParamInfo> {
+    let Fields::Named(ref fields) = input.fields else {
+        return Vec::new();
+    };
+
+    let mut params = Vec::new();
+    'fields: for field in &fields.named {
+        let Some(ref ident) = field.ident else {
+            continue;
+        };
+        if ident == "field_name" {
+            continue;
+        }
+
+        let mut default = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("koruma")
+                && attr
+                    .parse_args::<Ident>()
+                    .is_ok_and(|arg| arg == "value" || arg == "index")
+            {
+                continue 'fields;
+            }
+            if !attr.path().is_ident("builder") {
+                continue;
+            }
+            let Ok(args) = attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated,
+            ) else {
+                continue;
+            };
+            for arg in args {
+                if arg.path().is_ident("skip") {
+                    continue 'fields;
+                }
+                if !arg.path().is_ident("default") {
+                    continue;
+                }
+                default = Some(match arg {
+                    syn::Meta::NameValue(name_value) => {
+                        let value = name_value.value;
+                        type_source(&quote::quote!(#value).to_string())
+                    },
+                    _ => "Default::default()".to_string(),
+                });
+            }
+        }
+
+        let ty = &field.ty;
+        params.push(ShowcaseParamInfo {
+            name: ident.to_string(),
+            type_name: type_source(&quote::quote!(#ty).to_string()),
+            default,
+        });
+    }
+    params
+}
+
+/// Tidy the spacing `TokenStream::to_string` puts between tokens, e.g.
+/// `Vec < String >` becomes `Vec<String>`.
+#[cfg(feature = "showcase")]
+fn type_source(tokens: &str) -> String {
+    tokens
+        .replace(" :: ", "::")
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+}
@@ -1,3 +1,5 @@
 //! Tests for koruma-derive-core parsing functionality.
 
 mod parse_field_tests;
+#[cfg(feature = "showcase")]
+mod showcase_tests;
@@ -213,6 +213,25 @@ fn test_parse_field_cfg_attr_any_condition() {
     assert_debug_snapshot!(parse_field_info(&field));
 }
 
+#[test]
+fn test_parse_field_redact() {
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(redact, LenValidation::<_>(min = 8), PatternValidation::<_>(pattern = "[0-9]", redact))]
+        pub password: String
+    };
+
+    let info = parse_field_info(&field).unwrap();
+    assert!(info.validation.redact);
+    assert!(!info.validation.field_validators[0].redact);
+    assert!(info.validation.field_validators[1].redact);
+    assert!(
+        info.validation
+            .field_validators
+            .iter()
+            .all(|v| v.args.iter().all(|(name, _)| name != "redact"))
+    );
+}
+
 // =============================================================================
 // parse_struct_options tests
 // =============================================================================
@@ -261,6 +280,51 @@ fn test_parse_struct_options_cfg_attr_both() {
     assert_debug_snapshot!(parse_struct_options(&input.attrs));
 }
 
+#[test]
+fn test_parse_struct_options_shared() {
+    let input: syn::ItemStruct = syn::parse_quote! {
+        #[koruma(try_new)]
+        #[koruma(shared(name_len = StringLengthValidation(min = 1, max = 50, severity = "warning")))]
+        pub struct Person {
+            pub name: String,
+        }
+    };
+
+    assert_debug_snapshot!(parse_struct_options(&input.attrs));
+}
+
+#[test]
+fn test_parse_struct_options_cross_field_merged() {
+    let input: syn::ItemStruct = syn::parse_quote! {
+        #[koruma(cross_field(|this: &Self| check_passwords(this), |this: &Self| check_dates(this)))]
+        #[koruma(cross_field(|this: &Self| check_terms(this)))]
+        pub struct Signup {
+            pub password: String,
+        }
+    };
+
+    let options = parse_struct_options(&input.attrs).unwrap();
+    assert_eq!(options.cross_field.len(), 3);
+}
+
+#[test]
+fn test_resolve_shared_config() {
+    let input: syn::ItemStruct = syn::parse_quote! {
+        #[koruma(shared(name_len = StringLengthValidation(min = 1, max = 50)))]
+        pub struct Person {
+            #[koruma(name_len(severity = "warning"), each(name_len))]
+            pub names: Vec<String>,
+        }
+    };
+
+    let options = parse_struct_options(&input.attrs).unwrap();
+    let field = input.fields.iter().next().unwrap();
+    let mut info = parse_field_info(field).unwrap();
+    options.resolve_shared(&mut info).unwrap();
+
+    assert_debug_snapshot!(info);
+}
+
 // =============================================================================
 // find_value_field tests
 // =============================================================================
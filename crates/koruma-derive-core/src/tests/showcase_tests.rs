@@ -0,0 +1,70 @@
+//! Tests for reading showcase attributes and parameters from validator structs.
+
+use crate::{ShowcaseAttr, ShowcaseParamInfo, showcase_params};
+
+fn param(name: &str, type_name: &str, default: Option<&str>) -> ShowcaseParamInfo {
+    ShowcaseParamInfo {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        default: default.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_showcase_params_lists_builder_fields() {
+    let input: syn::ItemStruct = syn::parse_quote! {
+        pub struct StartsWithAnyValidation<T: AsRef<str>> {
+            #[builder(with = |prefixes: impl IntoIterator<Item = impl Into<String>>| {
+                prefixes.into_iter().map(Into::into).collect()
+            })]
+            pub prefixes: Vec<String>,
+            #[builder(default)]
+            pub case_insensitive: bool,
+            #[builder(default = 10)]
+            pub limit: usize,
+            #[koruma(value)]
+            pub actual: T,
+            #[koruma(index)]
+            pub index: Option<usize>,
+            pub field_name: Option<&'static str>,
+            #[builder(skip = actual.as_ref().len())]
+            pub actual_len: usize,
+        }
+    };
+
+    assert_eq!(
+        showcase_params(&input),
+        vec![
+            param("prefixes", "Vec<String>", None),
+            param("case_insensitive", "bool", Some("Default::default()")),
+            param("limit", "usize", Some("10")),
+        ]
+    );
+}
+
+#[test]
+fn test_showcase_attr_create_kinds() {
+    let infallible: ShowcaseAttr = syn::parse_quote! {
+        name = "Range", description = "In range", create = |input: &str| build(input)
+    };
+    assert!(!infallible.fallible);
+
+    let fallible: ShowcaseAttr = syn::parse_quote! {
+        name = "Range", description = "In range", try_create = |input: &str| parse(input)
+    };
+    assert!(fallible.fallible);
+
+    let both = syn::parse_str::<ShowcaseAttr>(
+        r#"name = "Range", description = "In range", create = |i: &str| a(i), try_create = |i: &str| b(i)"#,
+    );
+    assert_eq!(
+        both.unwrap_err().to_string(),
+        "showcase accepts only one of `create` and `try_create`"
+    );
+
+    let neither = syn::parse_str::<ShowcaseAttr>(r#"name = "Range", description = "In range""#);
+    assert_eq!(
+        neither.unwrap_err().to_string(),
+        "showcase requires a `create` or `try_create` attribute"
+    );
+}
@@ -241,6 +241,86 @@ pub fn vec_inner_type(ty: &Type) -> Option<&Type> {
     }
 }
 
+/// Extract the element type T of a sequence `each(...)` can iterate:
+/// `Vec<T>`, `VecDeque<T>`, `[T; N]`, `[T]` or a reference to a slice.
+///
+/// Returns `None` for any other type.
+///
+/// # Examples
+///
+/// ```ignore
+/// use syn::parse_quote;
+/// use koruma_derive_core::sequence_inner_type;
+///
+/// let ty: Type = parse_quote!([u8; 4]);
+/// let inner = sequence_inner_type(&ty);
+/// // inner is Some(&u8)
+///
+/// let ty2: Type = parse_quote!(VecDeque<String>);
+/// let inner2 = sequence_inner_type(&ty2);
+/// // inner2 is Some(&String)
+/// ```
+pub fn sequence_inner_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Reference(reference) if matches!(*reference.elem, Type::Slice(_)) => {
+            sequence_inner_type(&reference.elem)
+        },
+        Type::Paren(paren) => sequence_inner_type(&paren.elem),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            if segment.ident != "VecDeque" {
+                return vec_inner_type(ty);
+            }
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            match args.args.first()? {
+                GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Extract the key and value types `K` and `V` from `HashMap<K, V>` or
+/// `BTreeMap<K, V>`.
+///
+/// Returns `None` if the type is not one of those maps.
+///
+/// # Examples
+///
+/// ```ignore
+/// use syn::parse_quote;
+/// use koruma_derive_core::map_entry_types;
+///
+/// let ty: Type = parse_quote!(HashMap<String, u32>);
+/// let (key, value) = map_entry_types(&ty).unwrap();
+/// // key is String, value is u32
+/// ```
+pub fn map_entry_types(ty: &Type) -> Option<(&Type, &Type)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+
+    if segment.ident != "HashMap" && segment.ident != "BTreeMap" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    Some((types.next()?, types.next()?))
+}
+
 /// Check if a field type is `Option<T>`.
 ///
 /// # Examples
@@ -1,10 +1,13 @@
+use heck::ToSnakeCase;
 use koruma_derive_core::{
-    ValidatorAttr, contains_infer_type, expr_as_simple_ident, first_generic_arg,
-    is_option_infer_type, option_inner_type, substitute_infer_type, vec_inner_type,
+    FieldInfo, Help, Severity, StructOptions, ValidatorAttr, contains_infer_type,
+    expr_as_simple_ident, first_generic_arg, is_option_infer_type, map_entry_types,
+    option_inner_type, sequence_inner_type, substitute_infer_type,
 };
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{Expr, Type};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, Type};
 
 /// Check if a validator wants the full field type (not unwrapped from Option).
 /// This is true for `<Option<_>>` syntax.
@@ -23,13 +26,168 @@ pub(crate) fn transform_arg_value(arg_value: &Expr) -> TokenStream2 {
     }
 }
 
+/// Generate the value handed to a validator's `with_value`.
+///
+/// Redacted validators receive a masked copy (see `koruma::Redact`); the
+/// real value is still what gets validated.
+pub(crate) fn stored_value(value_expr: &TokenStream2, redact: bool) -> TokenStream2 {
+    if redact {
+        quote! {{
+            use koruma::Redact as _;
+            #value_expr.redacted()
+        }}
+    } else {
+        quote! { #value_expr.clone() }
+    }
+}
+
+/// Generate the statements that build `validator` for a check from `builder`,
+/// a validator builder with everything but the value set.
+///
+/// The stored value is cloned in up front, unless the validator implements
+/// `koruma::DeferredValue` and the value type `Default`: then `validator`
+/// holds the default, and [`store_deferred_value`] rebuilds it with the real
+/// value once the check fails.
+pub(crate) fn build_validator(builder: &TokenStream2, stored_value: &TokenStream2) -> TokenStream2 {
+    quote! {
+        let __probe = koruma::__private::ProbeTag::new(
+            || #stored_value,
+            |__value| #builder.with_value(__value).build(),
+        );
+        let (mut validator, __deferred) = {
+            use koruma::__private::{ViaClonedProbe as _, ViaDeferredProbe as _};
+            (&__probe).koruma_probe()
+        };
+    }
+}
+
+/// Generate the statements that build `validator` for a check whose failure
+/// is only detected, not reported: as [`build_validator`], but a deferred
+/// value is never stored.
+pub(crate) fn probe_validator(builder: &TokenStream2, stored_value: &TokenStream2) -> TokenStream2 {
+    quote! {
+        let __probe = koruma::__private::ProbeTag::new(
+            || #stored_value,
+            |__value| #builder.with_value(__value).build(),
+        );
+        let (validator, _) = {
+            use koruma::__private::{ViaClonedProbe as _, ViaDeferredProbe as _};
+            (&__probe).koruma_probe()
+        };
+    }
+}
+
+/// Generate the statement that gives a failing validator built by
+/// [`build_validator`] the real value, if it was deferred.
+pub(crate) fn store_deferred_value() -> TokenStream2 {
+    quote! {
+        if __deferred {
+            validator = __probe.build_stored();
+        }
+    }
+}
+
+/// Generate the statement that hands the field's name to a failing validator.
+///
+/// Only validators implementing `koruma::HasFieldName` record it; for the
+/// rest this is a no-op.
+pub(crate) fn set_field_name(f: &FieldInfo) -> TokenStream2 {
+    let field_name_str = f.name.to_string();
+    quote! {
+        {
+            use koruma::__private::{ViaFieldName as _, ViaNoFieldName as _};
+            (&mut koruma::__private::FieldNameTag(&mut validator)).koruma_set_field_name(#field_name_str);
+        }
+    }
+}
+
+/// Generate the statement that hands the real value to a failing redacted
+/// validator, which was built with a masked copy.
+///
+/// Only validators implementing `koruma::ObserveValue` use it; for the rest,
+/// and for validators that aren't redacted, this is a no-op.
+pub(crate) fn observe_value(ref_expr: &TokenStream2, redact: bool) -> TokenStream2 {
+    if !redact {
+        return quote! {};
+    }
+    quote! {
+        {
+            use koruma::__private::{ViaObserveValue as _, ViaNoObserveValue as _};
+            (&mut koruma::__private::ObserveTag(&mut validator, #ref_expr)).koruma_observe_value();
+        }
+    }
+}
+
+/// Check if a validator's type is resolved from the field it's applied to
+/// (`::<_>` or an explicit type containing `_`).
+pub(crate) fn validator_infers_type(v: &ValidatorAttr) -> bool {
+    v.infer_type || v.explicit_type.as_ref().is_some_and(contains_infer_type)
+}
+
+/// Name of the local holding a shared config arg, evaluated once per validation.
+fn shared_arg_ident(shared: &Ident, arg_name: &Ident) -> Ident {
+    format_ident!("__koruma_shared_{}_{}", shared, arg_name)
+}
+
+/// Generate the `.arg(value)` builder calls for a validator.
+///
+/// Args of non-generic shared configs read the locals bound by
+/// [`shared_config_bindings`]; everything else is evaluated in place.
+pub(crate) fn builder_calls(v: &ValidatorAttr) -> Vec<TokenStream2> {
+    v.args
+        .iter()
+        .map(|(arg_name, arg_value)| match v.shared {
+            Some(ref shared) if !validator_infers_type(v) => {
+                let local = shared_arg_ident(shared, arg_name);
+                quote! { .#arg_name(#local.clone()) }
+            },
+            _ => {
+                let transformed = transform_arg_value(arg_value);
+                quote! { .#arg_name(#transformed) }
+            },
+        })
+        .collect()
+}
+
+/// Bind the args of every used, non-generic shared config to locals, so each
+/// config is evaluated once no matter how many fields reference it.
+///
+/// Generic configs (`::<_>`) resolve their type per field, so their args are
+/// evaluated at each use instead.
+pub(crate) fn shared_config_bindings(
+    options: &StructOptions,
+    field_infos: &[FieldInfo],
+) -> Vec<TokenStream2> {
+    options
+        .shared
+        .iter()
+        .filter(|(name, v)| {
+            !validator_infers_type(v)
+                && field_infos.iter().any(|f| {
+                    f.validation
+                        .field_validators
+                        .iter()
+                        .chain(f.validation.element_validators.iter())
+                        .any(|fv| fv.shared.as_ref() == Some(name))
+                })
+        })
+        .flat_map(|(name, v)| {
+            v.args.iter().map(move |(arg_name, arg_value)| {
+                let local = shared_arg_ident(name, arg_name);
+                let transformed = transform_arg_value(arg_value);
+                quote! { let #local = #transformed; }
+            })
+        })
+        .collect()
+}
+
 /// Helper to generate the type for a validator
 ///
 /// Type inference behavior:
 /// - `<_>`: uses the full field type (unwrapping Option if present)
 /// - `<Vec<_>>`: substitutes `_` with the inner type from the field
 /// - `<SomeType>`: uses the explicit type directly
-/// - For `each` validation on `Vec<T>`: uses T
+/// - For `each` validation on `Vec<T>`, `VecDeque<T>` or `[T; N]`: uses T
 /// - For optional fields `Option<T>`: uses T (validation is skipped if None)
 pub(crate) fn validator_type_for_field(
     v: &ValidatorAttr,
@@ -44,16 +202,20 @@ pub(crate) fn validator_type_for_field(
             // Substitute `_` with the inner type from the field
             // e.g., Vec<_> on field Vec<String> → Vec<String>
             // e.g., HashSet<_> on field HashSet<i32> → HashSet<i32>
-            let inner_ty = first_generic_arg(field_ty).unwrap_or(field_ty);
+            let inner_ty = validate_each
+                .then(|| sequence_inner_type(field_ty))
+                .flatten()
+                .or_else(|| first_generic_arg(field_ty))
+                .unwrap_or(field_ty);
             let substituted = substitute_infer_type(explicit_ty, inner_ty);
             return quote! { #validator<#substituted> };
         }
         return quote! { #validator<#explicit_ty> };
     }
 
-    // For `each` validation, unwrap Vec<T> to get element type T
+    // For `each` validation, unwrap Vec<T>, VecDeque<T> or [T; N] to get element type T
     let after_vec = if validate_each {
-        vec_inner_type(field_ty).unwrap_or(field_ty)
+        sequence_inner_type(field_ty).unwrap_or(field_ty)
     } else {
         field_ty
     };
@@ -69,11 +231,47 @@ pub(crate) fn validator_type_for_field(
     }
 }
 
+/// Helper to generate the type of an element validator on `f`: validators in
+/// `each(...)` see the collection's element type (a map's value type),
+/// validators in `entries(...)` see a `(key, value)` tuple of the map's types.
+pub(crate) fn element_validator_type(v: &ValidatorAttr, f: &FieldInfo) -> TokenStream2 {
+    match (entry_types(f), map_types(f)) {
+        (Some((key_ty, value_ty)), _) => {
+            let entry_ty: Type = syn::parse_quote! { (#key_ty, #value_ty) };
+            validator_type_for_field(v, &entry_ty, false)
+        },
+        (None, Some((_, value_ty))) => validator_type_for_field(v, value_ty, false),
+        (None, None) => validator_type_for_field(v, &f.ty, true),
+    }
+}
+
+/// The map key and value types of a field validated with `entries(...)`.
+pub(crate) fn entry_types(f: &FieldInfo) -> Option<(&Type, &Type)> {
+    f.validates_entries()
+        .then(|| map_entry_types(&f.ty))
+        .flatten()
+}
+
+/// The map key and value types of a map field with element validators, from
+/// `entries(...)` or `each(...)`.
+pub(crate) fn map_types(f: &FieldInfo) -> Option<(&Type, &Type)> {
+    f.validates_map().then(|| map_entry_types(&f.ty)).flatten()
+}
+
+/// Type that identifies a failing element in the generated `element_errors`:
+/// the map key for map fields, the index otherwise.
+pub(crate) fn element_key_type(f: &FieldInfo) -> TokenStream2 {
+    match map_types(f) {
+        Some((key_ty, _)) => quote! { #key_ty },
+        None => quote! { usize },
+    }
+}
+
 /// Get the effective type for validation (unwrapping Option and Vec as needed)
 pub(crate) fn effective_validation_type(field_ty: &Type, validate_each: bool) -> &Type {
-    // Unwrap Vec<T> for each validation
+    // Unwrap Vec<T>, VecDeque<T> or [T; N] for each validation
     let after_vec = if validate_each {
-        vec_inner_type(field_ty).unwrap_or(field_ty)
+        sequence_inner_type(field_ty).unwrap_or(field_ty)
     } else {
         field_ty
     };
@@ -81,3 +279,361 @@ pub(crate) fn effective_validation_type(field_ty: &Type, validate_each: bool) ->
     // Unwrap Option<T> for optional field validation
     option_inner_type(after_vec).unwrap_or(after_vec)
 }
+
+/// Returns `expr` if it's a numeric literal, optionally negated (`100`, `-1.5`).
+fn numeric_literal(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(_) | syn::Lit::Float(_),
+            ..
+        }) => Some(expr),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => numeric_literal(inner).map(|_| expr),
+        Expr::Paren(paren) => numeric_literal(&paren.expr).map(|_| expr),
+        _ => None,
+    }
+}
+
+/// Generate `const` assertions that `min <= max` for validators whose bounds
+/// are both numeric literals, so inverted bounds fail to compile instead of
+/// rejecting every value at runtime.
+pub(crate) fn bound_assertions(field_infos: &[FieldInfo]) -> Vec<TokenStream2> {
+    fn literal_arg<'a>(v: &'a ValidatorAttr, name: &str) -> Option<&'a Expr> {
+        v.args
+            .iter()
+            .find(|(arg_name, _)| arg_name == name)
+            .and_then(|(_, value)| numeric_literal(value))
+    }
+
+    field_infos
+        .iter()
+        .flat_map(|f| {
+            f.validation
+                .field_validators
+                .iter()
+                .chain(&f.validation.element_validators)
+                .filter_map(move |v| {
+                    let (min, max) = (literal_arg(v, "min")?, literal_arg(v, "max")?);
+                    let message = format!(
+                        "`{}` on field `{}` has `min` ({}) greater than `max` ({})",
+                        v.name(),
+                        f.name,
+                        quote!(#min).to_string().replace(' ', ""),
+                        quote!(#max).to_string().replace(' ', ""),
+                    );
+                    Some(quote! {
+                        const _: () = ::core::assert!(#min <= #max, #message);
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Generate assertions that validated field types implement `Clone`.
+///
+/// Validators store a clone of the value they checked, so a non-`Clone` field
+/// would otherwise fail deep inside the generated `with_value` calls. The
+/// assertion is spanned to the field's type, pointing the error there. Fields
+/// whose validators all store redacted copies don't need `Clone`.
+pub(crate) fn clone_assertions(field_infos: &[FieldInfo]) -> Vec<TokenStream2> {
+    field_infos
+        .iter()
+        .filter(|f| {
+            f.validation
+                .field_validators
+                .iter()
+                .chain(&f.validation.element_validators)
+                .any(|v| !f.redacts(v))
+        })
+        .map(|f| {
+            let field_ty = &f.ty;
+            quote_spanned! {field_ty.span()=>
+                const _: fn() = || {
+                    fn assert_field_clone<T: koruma::__private::FieldClone + ?Sized>() {}
+                    assert_field_clone::<#field_ty>();
+                };
+            }
+        })
+        .collect()
+}
+
+/// Generate the `FAILURE_BIT_NAMES` constant and the `failure_bits` /
+/// `failure_bit_words` methods of a generated error struct.
+///
+/// Every `(field, validator)` pair gets a bit in declaration order, named
+/// `field.validator` (`field[].validator` for element validators). Nested and
+/// newtype fields take a single bit, named after the field, set when they have
+/// any failure.
+pub(crate) fn failure_bits(field_infos: &[FieldInfo], vis: &syn::Visibility) -> TokenStream2 {
+    let mut slots: Vec<(String, TokenStream2)> = Vec::new();
+    for f in field_infos {
+        let field_name = &f.name;
+        let field_name_str = f.external_name();
+
+        if f.is_nested() {
+            slots.push((field_name_str, quote! { self.#field_name.is_some() }));
+            continue;
+        }
+        if f.is_newtype() {
+            slots.push((
+                field_name_str,
+                quote! { !koruma::ValidationError::is_empty(&self.#field_name.inner) },
+            ));
+            continue;
+        }
+
+        for v in &f.validation.field_validators {
+            let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+            slots.push((
+                format!("{field_name_str}.{validator_snake}"),
+                quote! { self.#field_name.#validator_snake.is_some() },
+            ));
+        }
+        for v in &f.validation.element_validators {
+            let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+            slots.push((
+                format!("{field_name_str}[].{validator_snake}"),
+                quote! {
+                    self.#field_name
+                        .element_errors
+                        .iter()
+                        .any(|(_, element_error)| element_error.#validator_snake.is_some())
+                },
+            ));
+        }
+    }
+
+    let words = slots.len().div_ceil(64);
+    let names = slots.iter().map(|(name, _)| name);
+    let sets = slots.iter().enumerate().map(|(i, (_, check))| {
+        let (word, bit) = (i / 64, (i % 64) as u32);
+        quote! {
+            if #check {
+                words[#word] |= 1 << #bit;
+            }
+        }
+    });
+
+    quote! {
+        /// Names of the bits reported by [`Self::failure_bits`], indexed by bit.
+        #vis const FAILURE_BIT_NAMES: &'static [&'static str] = &[#(#names),*];
+
+        /// Returns a bitmask with bit `i` set when the slot named
+        /// `FAILURE_BIT_NAMES[i]` failed.
+        ///
+        /// Only covers the first 64 slots; use [`Self::failure_bit_words`] for
+        /// structs with more.
+        #vis fn failure_bits(&self) -> u64 {
+            self.failure_bit_words().first().copied().unwrap_or(0)
+        }
+
+        /// Returns the failure bits of every slot, 64 per word: bit `i` is
+        /// bit `i % 64` of word `i / 64`.
+        #vis fn failure_bit_words(&self) -> Vec<u64> {
+            #[allow(unused_mut)]
+            let mut words = vec![0u64; #words];
+            #(#sets)*
+            words
+        }
+    }
+}
+
+/// What a generated failure walk collects into its `failures` vec.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum FailureWalk {
+    /// `koruma::Failure`s, for `ValidationError::iter_failures`.
+    Failures,
+    /// `(koruma::Failure, String)` pairs, for `ValidationMessages::failure_messages`.
+    Messages,
+    /// `(koruma::Failure, String)` pairs rendered with `ToFluentString`, for
+    /// `LocalizedMessages::localized_failure_messages`.
+    #[cfg(feature = "fluent")]
+    FluentMessages,
+}
+
+/// Generate statements that push every failure of a generated error struct
+/// (`self`) into `failures`, in field declaration order.
+///
+/// Nested and newtype fields lift the inner failures under the field's name,
+/// and element failures carry their index (or map key) in the path.
+pub(crate) fn failure_walk(field_infos: &[FieldInfo], walk: FailureWalk) -> Vec<TokenStream2> {
+    let lift_inner = |field_name_str: &str, inner: TokenStream2| match walk {
+        FailureWalk::Failures => quote! {
+            failures.extend(
+                koruma::ValidationError::iter_failures(#inner)
+                    .map(|failure| failure.prefixed(#field_name_str)),
+            );
+        },
+        FailureWalk::Messages => quote! {
+            failures.extend(
+                koruma::ValidationMessages::failure_messages(#inner)
+                    .into_iter()
+                    .map(|(failure, message)| (failure.prefixed(#field_name_str), message)),
+            );
+        },
+        #[cfg(feature = "fluent")]
+        FailureWalk::FluentMessages => quote! {
+            failures.extend(
+                koruma::LocalizedMessages::localized_failure_messages(#inner)
+                    .into_iter()
+                    .map(|(failure, message)| (failure.prefixed(#field_name_str), message)),
+            );
+        },
+    };
+
+    let push = |v: &ValidatorAttr, slot: TokenStream2, failure: TokenStream2| match walk {
+        FailureWalk::Failures => quote! {
+            if #slot.is_some() {
+                failures.push(#failure);
+            }
+        },
+        FailureWalk::Messages => {
+            let message = message_text(v);
+            quote! {
+                if let Some(validator) = &#slot {
+                    failures.push((#failure, #message));
+                }
+            }
+        },
+        #[cfg(feature = "fluent")]
+        FailureWalk::FluentMessages => quote! {
+            if let Some(validator) = &#slot {
+                failures.push((#failure, ::es_fluent::ToFluentString::to_fluent_string(validator)));
+            }
+        },
+    };
+
+    field_infos
+        .iter()
+        .map(|f| {
+            let field_name = &f.name;
+            let field_name_str = f.external_name();
+
+            if f.is_nested() {
+                let lifted = lift_inner(&field_name_str, quote! { nested });
+                return quote! {
+                    if let Some(nested) = &self.#field_name {
+                        #lifted
+                    }
+                };
+            }
+            if f.is_newtype() {
+                return lift_inner(&field_name_str, quote! { &self.#field_name.inner });
+            }
+
+            let field_pushes: Vec<TokenStream2> = f
+                .validation
+                .field_validators
+                .iter()
+                .map(|v| {
+                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                    let validator_str = validator_snake.to_string();
+                    let with_severity = failure_severity(f.severity_of(v));
+                    let with_help = failure_help(v);
+                    let with_priority = failure_priority(v);
+                    push(
+                        v,
+                        quote! { self.#field_name.#validator_snake },
+                        quote! {
+                            koruma::Failure::new(
+                                koruma::FieldPath::field(#field_name_str),
+                                #validator_str,
+                            )#with_severity #with_help #with_priority
+                        },
+                    )
+                })
+                .collect();
+
+            let element_pushes = if f.has_element_validators() {
+                let pushes: Vec<TokenStream2> = f
+                    .validation
+                    .element_validators
+                    .iter()
+                    .map(|v| {
+                        let validator_snake =
+                            format_ident!("{}", v.name().to_string().to_snake_case());
+                        let validator_str = validator_snake.to_string();
+                        let with_severity = failure_severity(f.severity_of(v));
+                        let with_help = failure_help(v);
+                        let with_priority = failure_priority(v);
+                        let element_path = if f.validates_map() {
+                            quote! { .key(::std::string::ToString::to_string(idx)) }
+                        } else {
+                            quote! { .index(*idx) }
+                        };
+                        push(
+                            v,
+                            quote! { element_error.#validator_snake },
+                            quote! {
+                                koruma::Failure::new(
+                                    koruma::FieldPath::field(#field_name_str)#element_path,
+                                    #validator_str,
+                                )#with_severity #with_help #with_priority
+                            },
+                        )
+                    })
+                    .collect();
+                quote! {
+                    for (idx, element_error) in &self.#field_name.element_errors {
+                        #(#pushes)*
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                #(#field_pushes)*
+                #element_pushes
+            }
+        })
+        .collect()
+}
+
+/// Builder call that sets a non-default severity on a generated `Failure`.
+pub(crate) fn failure_severity(severity: Severity) -> TokenStream2 {
+    match severity {
+        Severity::Warning => quote! { .with_severity(koruma::Severity::Warning) },
+        Severity::Error => quote! {},
+    }
+}
+
+/// Builder call that sets a validator's `priority = N` on a generated `Failure`.
+fn failure_priority(v: &ValidatorAttr) -> TokenStream2 {
+    match v.priority {
+        Some(priority) => {
+            let priority = proc_macro2::Literal::i32_unsuffixed(priority);
+            quote! { .with_priority(#priority) }
+        },
+        None => quote! {},
+    }
+}
+
+/// Expression rendering the message of a failed `validator`: its `Display`
+/// output, or its `message = "..."` with `{value}` replaced by the stored value.
+/// The value is formatted with `Display`, falling back to `Debug`.
+pub(crate) fn message_text(v: &ValidatorAttr) -> TokenStream2 {
+    match &v.message {
+        Some(message) if message.value().contains("{value}") => quote! {
+            #message.replace("{value}", &{
+                use koruma::__private::{ViaDebugText as _, ViaDisplayText as _};
+                (&koruma::__private::ValueTextTag(koruma::StoredValue::stored_value(validator)))
+                    .koruma_value_text()
+            })
+        },
+        Some(message) => quote! { ::std::string::ToString::to_string(#message) },
+        None => quote! { ::std::string::ToString::to_string(validator) },
+    }
+}
+
+/// Builder call that attaches a validator's `help = ...` to a generated `Failure`.
+fn failure_help(v: &ValidatorAttr) -> TokenStream2 {
+    match &v.help {
+        Some(Help::Text(text)) => quote! { .with_help(koruma::Help::Text(#text)) },
+        Some(Help::Fluent(id)) => quote! { .with_help(koruma::Help::Fluent(#id)) },
+        None => quote! {},
+    }
+}
@@ -1,12 +1,16 @@
 use crate::expand::codegen::{
-    effective_validation_type, transform_arg_value, validator_type_for_field,
-    validator_wants_full_type,
+    FailureWalk, bound_assertions, build_validator, builder_calls, clone_assertions,
+    effective_validation_type, element_key_type, element_validator_type, entry_types, failure_bits,
+    failure_walk, map_types, observe_value, probe_validator, set_field_name,
+    shared_config_bindings, store_deferred_value, stored_value, validator_infers_type,
+    validator_type_for_field, validator_wants_full_type,
 };
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use koruma_derive_core::{
-    FieldInfo, ParseFieldResult, ValidatorAttr, contains_infer_type, first_generic_arg,
-    is_option_type, option_inner_type, parse_field, parse_struct_options, substitute_infer_type,
-    vec_inner_type,
+    ContextField, FieldInfo, ParseFieldResult, Severity, ValidatorAttr, contains_infer_type,
+    expr_as_simple_ident, find_context_field, first_generic_arg, is_option_type, map_entry_types,
+    option_inner_type, parse_field, parse_struct_options, sequence_inner_type,
+    substitute_infer_type,
 };
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
@@ -21,6 +25,16 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
 
     // Parse struct-level options like #[koruma(try_new, const_new)]
     let struct_options = parse_struct_options(&input.attrs)?;
+    // Visibility of the generated error structs and validation methods
+    let vis = struct_options.vis();
+    if matches!(input.vis, syn::Visibility::Public(_)) && !matches!(vis, syn::Visibility::Public(_))
+    {
+        return Err(syn::Error::new_spanned(
+            &input.vis,
+            "a `pub` struct exposes its error struct through `ValidateExt::Error`, \
+             so `visibility` can't restrict it; narrow the struct's visibility too",
+        ));
+    }
 
     let fields = match &input.data {
         syn::Data::Struct(data) => &data.fields,
@@ -32,16 +46,50 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         },
     };
 
-    // Parse all fields and extract validation info
+    // Parse all fields and extract validation info, resolving shared configs
     let mut field_infos: Vec<FieldInfo> = Vec::new();
     for (i, field) in fields.iter().enumerate() {
         match parse_field(field, i) {
-            ParseFieldResult::Valid(info) => field_infos.push(*info),
+            ParseFieldResult::Valid(mut info) => {
+                struct_options.resolve_shared(&mut info)?;
+                if info.validates_entries() && map_entry_types(&info.ty).is_none() {
+                    return Err(syn::Error::new_spanned(
+                        &info.ty,
+                        "`entries(...)` requires a `HashMap` or `BTreeMap` field",
+                    ));
+                }
+                // Whole-field validators take the field type as-is (e.g. any
+                // `HasLen` collection), but `each(...)` has to know the element type
+                if info.has_element_validators()
+                    && !info.validates_entries()
+                    && sequence_inner_type(&info.ty).is_none()
+                    && map_entry_types(&info.ty).is_none()
+                    && info
+                        .validation
+                        .element_validators
+                        .iter()
+                        .any(validator_infers_type)
+                {
+                    return Err(syn::Error::new_spanned(
+                        &info.ty,
+                        "`each(...)` can only infer the element type of a `Vec<T>`, `VecDeque<T>`, \
+                         array, slice, `HashMap` or `BTreeMap` field; name it explicitly, \
+                         e.g. `Validator::<T>`",
+                    ));
+                }
+                field_infos.push(*info);
+            },
             ParseFieldResult::Skip => {},
             ParseFieldResult::Error(e) => return Err(e),
         }
     }
 
+    // Context fields are inputs to other fields' validators; `try_new` may compute them
+    let context_fields = fields
+        .iter()
+        .map(find_context_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
     // Validate newtype option - must have exactly one validated field
     if struct_options.newtype && field_infos.len() != 1 {
         return Err(syn::Error::new_spanned(
@@ -53,6 +101,30 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         ));
     }
 
+    // `async` fields make `validate()` async, which the generated constructors,
+    // patches and trait impls can't call
+    let is_async = field_infos.iter().any(FieldInfo::is_async);
+    if is_async {
+        let sync_options = [
+            ("try_new", struct_options.try_new),
+            ("newtype", struct_options.newtype),
+            ("compat_validator", struct_options.compat_validator),
+            ("patch", struct_options.patch),
+            ("builder", struct_options.builder),
+            ("normalize", struct_options.normalize),
+            ("validated_wrapper", struct_options.validated_wrapper),
+        ];
+        if let Some((option, _)) = sync_options.iter().find(|(_, enabled)| *enabled) {
+            return Err(syn::Error::new_spanned(
+                &input,
+                format!(
+                    "`{}` needs synchronous validation, so it can't be combined with `async` fields",
+                    option
+                ),
+            ));
+        }
+    }
+
     // Generate per-field error structs and collect info for main error struct
     // For nested fields, we don't generate a per-field error struct - we use the nested type's error directly
     // For newtype fields, we generate a wrapper struct with Deref to the inner error
@@ -76,21 +148,21 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     /// Per-field validation error struct for a newtype field.
                     /// Stores the inner error directly for transparent, friction-free access.
                     #[derive(Clone, Debug, Default)]
-                    pub struct #field_error_struct_name {
+                    #vis struct #field_error_struct_name {
                         inner: <#inner_ty as koruma::ValidateExt>::Error,
                     }
 
                     impl #field_error_struct_name {
                         /// Returns a reference to the inner validation error.
-                        pub fn inner(&self) -> &<#inner_ty as koruma::ValidateExt>::Error {
+                        #vis fn inner(&self) -> &<#inner_ty as koruma::ValidateExt>::Error {
                             &self.inner
                         }
 
-                        pub fn is_empty(&self) -> bool {
+                        #vis fn is_empty(&self) -> bool {
                             self.inner.is_empty()
                         }
 
-                        pub fn has_errors(&self) -> bool {
+                        #vis fn has_errors(&self) -> bool {
                             !self.is_empty()
                         }
                     }
@@ -130,7 +202,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                         format_ident!("{}", v.name().to_string().to_snake_case());
                     let vtype = validator_type_for_field(v, field_ty, false);
                     quote! {
-                        pub fn #validator_snake(&self) -> Option<&#vtype> {
+                        #vis fn #validator_snake(&self) -> Option<&#vtype> {
                             self.#validator_snake.as_ref()
                         }
                     }
@@ -162,7 +234,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     .map(|v| {
                         let validator_snake =
                             format_ident!("{}", v.name().to_string().to_snake_case());
-                        let vtype = validator_type_for_field(v, field_ty, true);
+                        let vtype = element_validator_type(v, f);
                         quote! { #validator_snake: Option<#vtype> }
                     })
                     .collect();
@@ -173,9 +245,9 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     .map(|v| {
                         let validator_snake =
                             format_ident!("{}", v.name().to_string().to_snake_case());
-                        let vtype = validator_type_for_field(v, field_ty, true);
+                        let vtype = element_validator_type(v, f);
                         quote! {
-                            pub fn #validator_snake(&self) -> Option<&#vtype> {
+                            #vis fn #validator_snake(&self) -> Option<&#vtype> {
                                 self.#validator_snake.as_ref()
                             }
                         }
@@ -205,7 +277,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     .map(|v| {
                         let variant_name =
                             format_ident!("{}", v.name().to_string().to_upper_camel_case());
-                        let vtype = validator_type_for_field(v, field_ty, true);
+                        let vtype = element_validator_type(v, f);
                         quote! { #variant_name(#vtype) }
                     })
                     .collect();
@@ -230,13 +302,13 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     /// Enum of all possible element validators for this field.
                     #[derive(Clone, Debug)]
                     #[allow(dead_code)]
-                    pub enum #element_enum_name {
+                    #vis enum #element_enum_name {
                         #(#element_enum_variants),*
                     }
 
                     /// Per-element validation error struct.
                     #[derive(Clone, Debug, Default)]
-                    pub struct #element_error_struct_name {
+                    #vis struct #element_error_struct_name {
                         #(#element_validator_fields),*
                     }
 
@@ -244,17 +316,17 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                         #(#element_validator_getters)*
 
                         /// Returns all failed element validators.
-                        pub fn all(&self) -> Vec<#element_enum_name> {
+                        #vis fn all(&self) -> Vec<#element_enum_name> {
                             let mut result = Vec::new();
                             #(#element_all_pushes)*
                             result
                         }
 
-                        pub fn is_empty(&self) -> bool {
+                        #vis fn is_empty(&self) -> bool {
                             #(#element_is_empty_checks)&&*
                         }
 
-                        pub fn has_errors(&self) -> bool {
+                        #vis fn has_errors(&self) -> bool {
                             !self.is_empty()
                         }
                     }
@@ -270,7 +342,8 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     struct_name,
                     field_name.to_string().to_upper_camel_case()
                 );
-                quote! { element_errors: Vec<(usize, #element_error_struct_name)> }
+                let element_key_ty = element_key_type(f);
+                quote! { element_errors: Vec<(#element_key_ty, #element_error_struct_name)> }
             } else {
                 quote! {}
             };
@@ -282,11 +355,38 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     struct_name,
                     field_name.to_string().to_upper_camel_case()
                 );
+                let element_key_ty = element_key_type(f);
+                let doc = if f.validates_entries() {
+                    quote! { #[doc = " Returns all entry validation errors with their map keys."] }
+                } else if f.validates_map() {
+                    quote! { #[doc = " Returns all value validation errors with their map keys."] }
+                } else {
+                    quote! { #[doc = " Returns all element validation errors with their indices."] }
+                };
+                let by_key = if f.validates_map() {
+                    quote! {
+                        /// Returns the validation errors of the map entry with `key`, if it failed.
+                        #vis fn by_key<Q>(&self, key: &Q) -> Option<&#element_error_struct_name>
+                        where
+                            #element_key_ty: ::std::borrow::Borrow<Q>,
+                            Q: ?Sized + PartialEq,
+                        {
+                            self.element_errors
+                                .iter()
+                                .find(|(k, _)| ::std::borrow::Borrow::<Q>::borrow(k) == key)
+                                .map(|(_, e)| e)
+                        }
+                    }
+                } else {
+                    quote! {}
+                };
                 quote! {
-                    /// Returns all element validation errors with their indices.
-                    pub fn element_errors(&self) -> &[(usize, #element_error_struct_name)] {
+                    #doc
+                    #vis fn element_errors(&self) -> &[(#element_key_ty, #element_error_struct_name)] {
                         &self.element_errors
                     }
+
+                    #by_key
                 }
             } else {
                 quote! {}
@@ -342,7 +442,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     /// Enum of all possible validators for this field.
                     #[derive(Clone, Debug)]
                     #[allow(dead_code)]
-                    pub enum #enum_name {
+                    #vis enum #enum_name {
                         #(#enum_variants),*
                     }
                 }
@@ -353,7 +453,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
             } else {
                 quote! {
                     /// Returns all failed field-level validators.
-                    pub fn all(&self) -> Vec<#enum_name> {
+                    #vis fn all(&self) -> Vec<#enum_name> {
                         let mut result = Vec::new();
                         #(#all_pushes)*
                         result
@@ -376,9 +476,10 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     struct_name,
                     field_name.to_string().to_upper_camel_case()
                 );
+                let element_key_ty = element_key_type(f);
                 quote! {
                     #(#field_validator_fields,)*
-                    element_errors: Vec<(usize, #element_error_struct_name)>
+                    element_errors: Vec<(#element_key_ty, #element_error_struct_name)>
                 }
             } else if f.has_element_validators() {
                 // Only element errors
@@ -387,8 +488,9 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     struct_name,
                     field_name.to_string().to_upper_camel_case()
                 );
+                let element_key_ty = element_key_type(f);
                 quote! {
-                    element_errors: Vec<(usize, #element_error_struct_name)>
+                    element_errors: Vec<(#element_key_ty, #element_error_struct_name)>
                 }
             } else {
                 // Only field validators
@@ -403,7 +505,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                 #enum_and_all
 
                 #[derive(Clone, Debug, Default)]
-                pub struct #field_error_struct_name {
+                #vis struct #field_error_struct_name {
                     #struct_fields
                 }
 
@@ -414,11 +516,11 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
 
                     #all_method
 
-                    pub fn is_empty(&self) -> bool {
+                    #vis fn is_empty(&self) -> bool {
                         #is_empty_body
                     }
 
-                    pub fn has_errors(&self) -> bool {
+                    #vis fn has_errors(&self) -> bool {
                         !self.is_empty()
                     }
                 }
@@ -429,7 +531,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
     // Generate main error struct fields (one per validated field)
     // Now all fields just have their field error struct (element errors are nested inside)
     // For nested fields, we use Option<NestedTypeKorumaValidationError> directly
-    let error_fields: Vec<TokenStream2> = field_infos
+    let mut error_fields: Vec<TokenStream2> = field_infos
         .iter()
         .map(|f| {
             let field_name = &f.name;
@@ -461,7 +563,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                 let field_ty = &f.ty;
                 let inner_ty = option_inner_type(field_ty).unwrap_or(field_ty);
                 quote! {
-                    pub fn #field_name(&self) -> Option<&<#inner_ty as koruma::ValidateExt>::Error> {
+                    #vis fn #field_name(&self) -> Option<&<#inner_ty as koruma::ValidateExt>::Error> {
                         self.#field_name.as_ref()
                     }
                 }
@@ -471,10 +573,44 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                 let field_ty = &f.ty;
                 let inner_ty = option_inner_type(field_ty).unwrap_or(field_ty);
                 quote! {
-                    pub fn #field_name(&self) -> &<#inner_ty as koruma::ValidateExt>::Error {
+                    #vis fn #field_name(&self) -> &<#inner_ty as koruma::ValidateExt>::Error {
                         &self.#field_name.inner
                     }
                 }
+            } else if struct_options.typed_errors
+                && !f.validation.field_validators.is_empty()
+                && !f.has_element_validators()
+            {
+                // With `typed_errors`, return the first failed validator as an
+                // enum so callers can match on it exhaustively
+                let camel = field_name.to_string().to_upper_camel_case();
+                let field_error_struct_name =
+                    format_ident!("{}{}KorumaValidationError", struct_name, camel);
+                let enum_name = format_ident!("{}{}KorumaValidator", struct_name, camel);
+                let errors_name = format_ident!("{}_errors", field_name);
+                let first_failed = f.validation.field_validators.iter().map(|v| {
+                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                    let variant_name =
+                        format_ident!("{}", v.name().to_string().to_upper_camel_case());
+                    quote! {
+                        if let Some(v) = &self.#field_name.#validator_snake {
+                            return Err(#enum_name::#variant_name(v.clone()));
+                        }
+                    }
+                });
+                quote! {
+                    /// Returns `Ok(())` if this field passed, or else its first
+                    /// failed validator in declaration order.
+                    #vis fn #field_name(&self) -> Result<(), #enum_name> {
+                        #(#first_failed)*
+                        Ok(())
+                    }
+
+                    /// Returns every validator failure of this field.
+                    #vis fn #errors_name(&self) -> &#field_error_struct_name {
+                        &self.#field_name
+                    }
+                }
             } else {
                 let field_error_struct_name = format_ident!(
                     "{}{}KorumaValidationError",
@@ -482,7 +618,7 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                     field_name.to_string().to_upper_camel_case()
                 );
                 quote! {
-                    pub fn #field_name(&self) -> &#field_error_struct_name {
+                    #vis fn #field_name(&self) -> &#field_error_struct_name {
                         &self.#field_name
                     }
                 }
@@ -490,8 +626,130 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         })
         .collect();
 
+    // Generate take methods that move a field's errors out, leaving the slot empty
+    let take_methods: Vec<TokenStream2> = field_infos
+        .iter()
+        .map(|f| {
+            let field_name = &f.name;
+            let take_name = format_ident!("take_{}", field_name);
+            let field_ty = &f.ty;
+            let inner_ty = option_inner_type(field_ty).unwrap_or(field_ty);
+            if f.is_nested() {
+                quote! {
+                    /// Moves this field's errors out, leaving the slot empty.
+                    #vis fn #take_name(&mut self) -> Option<<#inner_ty as koruma::ValidateExt>::Error> {
+                        self.#field_name.take()
+                    }
+                }
+            } else if f.is_newtype() {
+                quote! {
+                    /// Moves this field's errors out, leaving the slot empty.
+                    #vis fn #take_name(&mut self) -> <#inner_ty as koruma::ValidateExt>::Error {
+                        std::mem::take(&mut self.#field_name.inner)
+                    }
+                }
+            } else {
+                let field_error_struct_name = format_ident!(
+                    "{}{}KorumaValidationError",
+                    struct_name,
+                    field_name.to_string().to_upper_camel_case()
+                );
+                quote! {
+                    /// Moves this field's errors out, leaving the slot empty.
+                    #vis fn #take_name(&mut self) -> #field_error_struct_name {
+                        std::mem::take(&mut self.#field_name)
+                    }
+                }
+            }
+        })
+        .collect();
+
+    // Generate statements resetting every slot for clear(), keeping element-error capacity
+    let mut clear_stmts: Vec<TokenStream2> = field_infos
+        .iter()
+        .map(|f| {
+            let field_name = &f.name;
+            if f.is_nested() {
+                return quote! { self.#field_name = None; };
+            }
+            if f.is_newtype() {
+                return quote! { self.#field_name.inner = Default::default(); };
+            }
+            let resets = f.validation.field_validators.iter().map(|v| {
+                let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                quote! { self.#field_name.#validator_snake = None; }
+            });
+            let element_reset = f
+                .has_element_validators()
+                .then(|| quote! { self.#field_name.element_errors.clear(); });
+            quote! {
+                #(#resets)*
+                #element_reset
+            }
+        })
+        .collect();
+
+    // Generate statements folding `other`'s failures into `self` for merge()
+    let mut merge_stmts: Vec<TokenStream2> = field_infos
+        .iter()
+        .map(|f| {
+            let field_name = &f.name;
+            if f.is_nested() {
+                return quote! {
+                    self.#field_name = match (self.#field_name.take(), other.#field_name) {
+                        (Some(mut nested), Some(other_nested)) => {
+                            nested.merge(other_nested);
+                            Some(nested)
+                        },
+                        (nested, other_nested) => nested.or(other_nested),
+                    };
+                };
+            }
+            if f.is_newtype() {
+                return quote! { self.#field_name.inner.merge(other.#field_name.inner); };
+            }
+            let slot_merges = f.validation.field_validators.iter().map(|v| {
+                let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                quote! {
+                    if self.#field_name.#validator_snake.is_none() {
+                        self.#field_name.#validator_snake = other.#field_name.#validator_snake;
+                    }
+                }
+            });
+            let element_merge = f.has_element_validators().then(|| {
+                let element_slot_merges = f.validation.element_validators.iter().map(|v| {
+                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                    quote! {
+                        if existing.#validator_snake.is_none() {
+                            existing.#validator_snake = other_element.#validator_snake;
+                        }
+                    }
+                });
+                quote! {
+                    for (key, other_element) in other.#field_name.element_errors {
+                        match self
+                            .#field_name
+                            .element_errors
+                            .iter_mut()
+                            .find(|(existing_key, _)| *existing_key == key)
+                        {
+                            Some((_, existing)) => {
+                                #(#element_slot_merges)*
+                            },
+                            None => self.#field_name.element_errors.push((key, other_element)),
+                        }
+                    }
+                }
+            });
+            quote! {
+                #(#slot_merges)*
+                #element_merge
+            }
+        })
+        .collect();
+
     // Generate is_empty check (all field error structs are empty)
-    let is_empty_checks: Vec<TokenStream2> = field_infos
+    let mut is_empty_checks: Vec<TokenStream2> = field_infos
         .iter()
         .map(|f| {
             let field_name = &f.name;
@@ -504,15 +762,75 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         })
         .collect();
 
-    // Generate is_empty body - either `true` or the checks
-    let is_empty_body = if is_empty_checks.is_empty() {
-        quote! { true }
+    let validated_field_names: Vec<String> =
+        field_infos.iter().map(FieldInfo::external_name).collect();
+
+    // Generate failure reporting for iter_failures(), in field declaration order
+    let failure_pushes = failure_walk(&field_infos, FailureWalk::Failures);
+
+    let iter_failures_body = if failure_pushes.is_empty() {
+        quote! { std::iter::empty() }
     } else {
-        quote! { #(#is_empty_checks)&&* }
+        quote! {
+            let mut failures = Vec::new();
+            #(#failure_pushes)*
+            failures.into_iter()
+        }
     };
 
+    // Generate has_blocking_errors checks (error-severity failures only)
+    let mut blocking_checks: Vec<TokenStream2> = field_infos
+        .iter()
+        .flat_map(|f| {
+            let field_name = &f.name;
+            if f.is_nested() {
+                return vec![quote! {
+                    self.#field_name
+                        .as_ref()
+                        .is_some_and(koruma::ValidationError::has_blocking_errors)
+                }];
+            }
+            if f.is_newtype() {
+                return vec![quote! {
+                    koruma::ValidationError::has_blocking_errors(&self.#field_name.inner)
+                }];
+            }
+
+            let mut checks: Vec<TokenStream2> = f
+                .validation
+                .field_validators
+                .iter()
+                .filter(|v| f.severity_of(v) == Severity::Error)
+                .map(|v| {
+                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                    quote! { self.#field_name.#validator_snake.is_some() }
+                })
+                .collect();
+
+            let element_checks: Vec<TokenStream2> = f
+                .validation
+                .element_validators
+                .iter()
+                .filter(|v| f.severity_of(v) == Severity::Error)
+                .map(|v| {
+                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                    quote! { element_error.#validator_snake.is_some() }
+                })
+                .collect();
+            if !element_checks.is_empty() {
+                checks.push(quote! {
+                    self.#field_name
+                        .element_errors
+                        .iter()
+                        .any(|(_, element_error)| #(#element_checks)||*)
+                });
+            }
+            checks
+        })
+        .collect();
+
     // Generate default values for main error struct initialization
-    let error_defaults: Vec<TokenStream2> = field_infos
+    let mut error_defaults: Vec<TokenStream2> = field_infos
         .iter()
         .map(|f| {
             let field_name = &f.name;
@@ -573,190 +891,375 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         })
         .collect();
 
-    // Generate validation logic - supports both field validators, element validators, and nested structs
-    let validation_checks: Vec<TokenStream2> = field_infos
-        .iter()
-        .map(|f| {
-            let field_name = &f.name;
-            let field_member = &f.member;
-            let field_ty = &f.ty;
+    // Struct-level `cross_field(...)` checks report into a keyed map on the error
+    // struct, apart from the per-field failures
+    let cross_field = &struct_options.cross_field;
+    let (cross_field_methods, cross_field_checks, iter_cross_field_errors_fn) = if cross_field
+        .is_empty()
+    {
+        (quote! {}, quote! {}, quote! {})
+    } else {
+        error_fields.push(quote! {
+            cross_field_errors: std::collections::BTreeMap<&'static str, koruma::KeyedError>
+        });
+        error_defaults.push(quote! { cross_field_errors: std::collections::BTreeMap::new() });
+        clear_stmts.push(quote! { self.cross_field_errors.clear(); });
+        merge_stmts.push(quote! {
+            for (key, cross_field_error) in other.cross_field_errors {
+                self.cross_field_errors.entry(key).or_insert(cross_field_error);
+            }
+        });
+        is_empty_checks.push(quote! { self.cross_field_errors.is_empty() });
+        blocking_checks.push(quote! { !self.cross_field_errors.is_empty() });
+        (
+            quote! {
+                /// Returns the failed cross-field check stored under `key`, if any.
+                #vis fn cross_field(&self, key: &str) -> Option<&koruma::KeyedError> {
+                    self.cross_field_errors.get(key)
+                }
 
-            // Handle nested fields - call validate() on the nested struct
-            if f.is_nested() {
-                let field_is_optional = is_option_type(field_ty);
-                if field_is_optional {
-                    // For Option<NestedType>, only validate if Some
-                    return quote! {
-                        if let Some(ref __nested_value) = self.#field_member {
-                            if let Err(nested_err) = __nested_value.validate() {
-                                error.#field_name = Some(nested_err);
-                                has_error = true;
-                            }
-                        }
-                    };
-                } else {
-                    // For non-optional nested field, always validate
-                    return quote! {
-                        if let Err(nested_err) = self.#field_member.validate() {
-                            error.#field_name = Some(nested_err);
-                            has_error = true;
-                        }
-                    };
+                /// Returns every failed cross-field check, keyed by the
+                /// [`KeyedError::key`](koruma::KeyedError::key) it returned.
+                #vis fn cross_field_errors(
+                    &self,
+                ) -> &std::collections::BTreeMap<&'static str, koruma::KeyedError> {
+                    &self.cross_field_errors
+                }
+            },
+            quote! {
+                #(
+                    if let Err(cross_field_error) = (#cross_field)(self) {
+                        error
+                            .cross_field_errors
+                            .insert(koruma::KeyedError::key(&cross_field_error), cross_field_error);
+                    }
+                )*
+            },
+            quote! {
+                fn iter_cross_field_errors(
+                    &self,
+                ) -> impl Iterator<Item = &koruma::KeyedError> {
+                    self.cross_field_errors.values()
                 }
+            },
+        )
+    };
+
+    // Cross-field failures are always blocking, so `is_valid` stops at the first
+    let fast_cross_field_checks = quote! {
+        #(
+            if (#cross_field)(self).is_err() {
+                return false;
             }
+        )*
+    };
 
-            // Handle newtype fields - similar to nested but stores in wrapper struct
-            if f.is_newtype() {
-                let field_is_optional = is_option_type(field_ty);
-                if field_is_optional {
-                    // For Option<NewtypeType>, only validate if Some
-                    return quote! {
-                        if let Some(ref __newtype_value) = self.#field_member {
-                            if let Err(newtype_err) = __newtype_value.validate() {
-                                error.#field_name.inner = newtype_err;
-                                has_error = true;
-                            }
-                        }
-                    };
-                } else {
-                    // For non-optional newtype field, always validate
-                    return quote! {
-                        if let Err(newtype_err) = self.#field_member.validate() {
-                            error.#field_name.inner = newtype_err;
-                            has_error = true;
+    // Generate is_empty body - either `true` or the checks
+    let is_empty_body = if is_empty_checks.is_empty() {
+        quote! { true }
+    } else {
+        quote! { #(#is_empty_checks)&&* }
+    };
+
+    let has_blocking_errors_body = if blocking_checks.is_empty() {
+        quote! { false }
+    } else {
+        quote! { #(#blocking_checks)||* }
+    };
+
+    // Generate validation logic - supports both field validators, element validators, and nested structs
+    let shared_bindings = shared_config_bindings(&struct_options, &field_infos);
+
+    // Validation of a single field, reading its value from `access`
+    // (`self.field` when validating the struct itself). `fast` checks only
+    // error-severity validators and returns `false` at the first failure,
+    // for `is_valid`, instead of recording failures in `error`
+    let field_validation = |f: &FieldInfo, access: TokenStream2, fast: bool| -> TokenStream2 {
+        let field_name = &f.name;
+        let field_ty = &f.ty;
+
+        if fast && (f.is_nested() || f.is_newtype()) {
+            return if is_option_type(field_ty) {
+                quote! {
+                    if let Some(ref __nested_value) = #access {
+                        if !koruma::ValidateExt::is_valid(__nested_value) {
+                            return false;
                         }
-                    };
+                    }
                 }
-            }
+            } else {
+                quote! {
+                    if !koruma::ValidateExt::is_valid(&#access) {
+                        return false;
+                    }
+                }
+            };
+        }
 
-            let has_element_validators = f.has_element_validators();
+        // Handle nested fields - call validate() on the nested struct
+        if f.is_nested() {
+            let field_is_optional = is_option_type(field_ty);
+            if field_is_optional {
+                // For Option<NestedType>, only validate if Some
+                return quote! {
+                    if let Some(ref __nested_value) = #access {
+                        let nested_err = koruma::ValidateExt::validation_report(__nested_value);
+                        if !koruma::ValidationError::is_empty(&nested_err) {
+                            error.#field_name = Some(nested_err);
+                        }
+                    }
+                };
+            } else {
+                // For non-optional nested field, always validate
+                return quote! {
+                    let nested_err = koruma::ValidateExt::validation_report(&#access);
+                    if !koruma::ValidationError::is_empty(&nested_err) {
+                        error.#field_name = Some(nested_err);
+                    }
+                };
+            }
+        }
 
-            // Split field validators into those that want the full type vs those that want
-            // the unwrapped type (for Option fields)
-            let (full_type_validators, unwrapped_validators): (Vec<_>, Vec<_>) = f
-                .validation
-                .field_validators
-                .iter()
-                .partition(|v| validator_wants_full_type(v));
+        // Handle newtype fields - similar to nested but stores in wrapper struct
+        if f.is_newtype() {
+            let field_is_optional = is_option_type(field_ty);
+            if field_is_optional {
+                // For Option<NewtypeType>, only validate if Some
+                return quote! {
+                    if let Some(ref __newtype_value) = #access {
+                        error.#field_name.inner =
+                            koruma::ValidateExt::validation_report(__newtype_value);
+                    }
+                };
+            } else {
+                // For non-optional newtype field, always validate
+                return quote! {
+                    error.#field_name.inner =
+                        koruma::ValidateExt::validation_report(&#access);
+                };
+            }
+        }
 
-            // Helper to generate validator check code
-            let generate_validator_check =
-                |v: &ValidatorAttr, value_expr: TokenStream2, needs_ref: bool| -> TokenStream2 {
-                    let validator = &v.validator;
-                    let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
-                    let effective_ty = effective_validation_type(field_ty, false);
+        // Warnings never make validation fail, so `fast` skips them
+        let blocks = |v: &&ValidatorAttr| !fast || f.severity_of(v) == Severity::Error;
+        let element_validators: Vec<&ValidatorAttr> = f
+            .validation
+            .element_validators
+            .iter()
+            .filter(blocks)
+            .collect();
+        let has_element_validators = !element_validators.is_empty();
+
+        // `async` fields run `AsyncValidate` validators and await them
+        let (validate_trait, validate_bound, validate_output, awaited) = if f.is_async() {
+            (
+                quote! { koruma::AsyncValidate },
+                quote! { koruma::AsyncValidate<T> },
+                quote! { impl ::std::future::Future<Output = bool> + Send },
+                quote! { .await },
+            )
+        } else {
+            (
+                quote! { koruma::Validate },
+                quote! { koruma::Validate<T> },
+                quote! { bool },
+                quote! {},
+            )
+        };
+
+        // Split field validators into those that want the full type vs those that want
+        // the unwrapped type (for Option fields)
+        let (full_type_validators, unwrapped_validators): (Vec<_>, Vec<_>) = f
+            .validation
+            .field_validators
+            .iter()
+            .filter(blocks)
+            .partition(|v| validator_wants_full_type(v));
+
+        // Helper to generate validator check code
+        let generate_validator_check =
+            |v: &ValidatorAttr, value_expr: TokenStream2, needs_ref: bool| -> TokenStream2 {
+                let validator = &v.validator;
+                let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                let effective_ty = effective_validation_type(field_ty, false);
+
+                let builder_calls = builder_calls(v);
+                let stored_value = stored_value(&value_expr, f.redacts(v));
+                let set_field_name = set_field_name(f);
+
+                // The reference expression for validate()
+                let ref_expr = if needs_ref {
+                    quote! { &#value_expr }
+                } else {
+                    quote! { #value_expr }
+                };
 
-                    let builder_calls: Vec<TokenStream2> = v
-                        .args
-                        .iter()
-                        .map(|(arg_name, arg_value)| {
-                            let transformed = transform_arg_value(arg_value);
-                            quote! { .#arg_name(#transformed) }
-                        })
-                        .collect();
-
-                    // The reference expression for validate()
-                    let ref_expr = if needs_ref {
-                        quote! { &#value_expr }
+                let observe_value = observe_value(&ref_expr, f.redacts(v));
+                let store_deferred_value = store_deferred_value();
+                let build = |builder: TokenStream2| {
+                    if fast {
+                        probe_validator(&builder, &stored_value)
                     } else {
-                        quote! { #value_expr }
-                    };
+                        build_validator(&builder, &stored_value)
+                    }
+                };
+                let on_failure = if fast {
+                    quote! { return false; }
+                } else {
+                    quote! {
+                        #store_deferred_value
+                        #observe_value
+                        #set_field_name
+                        error.#field_name.#validator_snake = Some(validator);
+                    }
+                };
 
-                    // Determine the validator type
-                    let uses_infer =
-                        v.infer_type || v.explicit_type.as_ref().is_some_and(contains_infer_type);
-
-                    if uses_infer {
-                        let validator_ty = if let Some(ref explicit_ty) = v.explicit_type {
-                            if contains_infer_type(explicit_ty) {
-                                // For Option<_>, first_generic_arg gets the inner type
-                                let inner_ty = first_generic_arg(field_ty).unwrap_or(field_ty);
-                                let substituted = substitute_infer_type(explicit_ty, inner_ty);
-                                quote! { #substituted }
-                            } else {
-                                quote! { #explicit_ty }
-                            }
+                // Determine the validator type
+                let uses_infer = validator_infers_type(v);
+
+                if uses_infer {
+                    let validator_ty = if let Some(ref explicit_ty) = v.explicit_type {
+                        if contains_infer_type(explicit_ty) {
+                            // For Option<_>, first_generic_arg gets the inner type
+                            let inner_ty = first_generic_arg(field_ty).unwrap_or(field_ty);
+                            let substituted = substitute_infer_type(explicit_ty, inner_ty);
+                            quote! { #substituted }
                         } else {
-                            quote! { #effective_ty }
-                        };
-                        let assert_fn = format_ident!(
-                            "__koruma_assert_validate_{}_{}_field",
-                            field_name,
-                            validator_snake
-                        );
-                        quote! {
-                            fn #assert_fn<V: koruma::Validate<T>, T>(v: &V, t: &T) -> bool {
-                                v.validate(t)
-                            }
-                            let validator = #validator::<#validator_ty>::builder()
-                                #(#builder_calls)*
-                                .with_value(#value_expr.clone())
-                                .build();
-                            if !#assert_fn(&validator, #ref_expr) {
-                                error.#field_name.#validator_snake = Some(validator);
-                                has_error = true;
-                            }
+                            quote! { #explicit_ty }
                         }
                     } else {
-                        quote! {
-                            let validator = #validator::builder()
-                                #(#builder_calls)*
-                                .with_value(#value_expr.clone())
-                                .build();
-                            if !validator.validate(#ref_expr) {
-                                error.#field_name.#validator_snake = Some(validator);
-                                has_error = true;
-                            }
+                        quote! { #effective_ty }
+                    };
+                    let assert_fn = format_ident!(
+                        "__koruma_assert_validate_{}_{}_field",
+                        field_name,
+                        validator_snake
+                    );
+                    let build_validator =
+                        build(quote! { #validator::<#validator_ty>::builder() #(#builder_calls)* });
+                    quote! {
+                        fn #assert_fn<V: #validate_bound, T>(v: &V, t: &T) -> #validate_output {
+                            v.validate(t)
+                        }
+                        #build_validator
+                        if !#assert_fn(&validator, #ref_expr)#awaited {
+                            #on_failure
                         }
                     }
-                };
+                } else {
+                    let build_validator =
+                        build(quote! { #validator::builder() #(#builder_calls)* });
+                    quote! {
+                        #build_validator
+                        if !#validate_trait::validate(&validator, #ref_expr)#awaited {
+                            #on_failure
+                        }
+                    }
+                }
+            };
 
-            // Generate checks for full-type validators (use field directly, no reference)
-            // Note: we pass the field expression without &, the closure adds .clone() for with_value
-            // and &... for validate()
-            let full_type_checks: Vec<TokenStream2> = full_type_validators
-                .iter()
-                .map(|v| generate_validator_check(v, quote! { self.#field_member }, true))
-                .collect();
+        // Generate checks for full-type validators (use field directly, no reference)
+        // Note: we pass the field expression without &, the closure adds .clone() for with_value
+        // and &... for validate()
+        let full_type_checks: Vec<TokenStream2> = full_type_validators
+            .iter()
+            .map(|v| generate_validator_check(v, access.clone(), true))
+            .collect();
 
-            // Generate checks for unwrapped validators (use __field_value which is already a ref)
-            let unwrapped_checks: Vec<TokenStream2> = unwrapped_validators
-                .iter()
-                .map(|v| generate_validator_check(v, quote! { __field_value }, false))
-                .collect();
+        // Generate checks for unwrapped validators (use __field_value which is already a ref)
+        let unwrapped_checks: Vec<TokenStream2> = unwrapped_validators
+            .iter()
+            .map(|v| generate_validator_check(v, quote! { __field_value }, false))
+            .collect();
 
-            // Generate element-level validation checks if we have element validators
-            let element_validation = if has_element_validators {
-                let element_error_struct_name = format_ident!(
-                    "{}{}ElementKorumaValidationError",
-                    struct_name,
-                    field_name.to_string().to_upper_camel_case()
-                );
+        // Generate element-level validation checks if we have element validators
+        let element_validation = if has_element_validators {
+            let element_error_struct_name = format_ident!(
+                "{}{}ElementKorumaValidationError",
+                struct_name,
+                field_name.to_string().to_upper_camel_case()
+            );
 
-                let element_ty = vec_inner_type(field_ty).unwrap_or(field_ty);
-                let element_is_optional = is_option_type(element_ty);
-                let effective_element_ty = effective_validation_type(field_ty, true);
+            // `entries(...)` validates `(key, value)` tuples of the map,
+            // `each(...)` on a map validates its values
+            let entry_types = entry_types(f);
+            let map_value_ty = map_types(f).map(|(_, value_ty)| value_ty);
+            let entry_ty: Option<syn::Type> =
+                entry_types.map(|(key_ty, value_ty)| syn::parse_quote! { (#key_ty, #value_ty) });
+            let element_ty = match (&entry_ty, map_value_ty) {
+                (Some(entry_ty), _) => entry_ty,
+                (None, Some(value_ty)) => value_ty,
+                (None, None) => sequence_inner_type(field_ty).unwrap_or(field_ty),
+            };
+            let element_is_optional = entry_ty.is_none() && is_option_type(element_ty);
+            let effective_element_ty = match (&entry_ty, map_value_ty) {
+                (Some(entry_ty), _) => entry_ty,
+                (None, Some(value_ty)) => option_inner_type(value_ty).unwrap_or(value_ty),
+                (None, None) => effective_validation_type(field_ty, true),
+            };
 
-                let element_validator_checks: Vec<TokenStream2> = f
-                    .validation
-                    .element_validators
+            let element_validator_checks: Vec<TokenStream2> = element_validators
                     .iter()
                     .map(|v| {
                         let validator = &v.validator;
                         let validator_snake =
                             format_ident!("{}", v.name().to_string().to_snake_case());
 
-                        let builder_calls: Vec<TokenStream2> = v
-                            .args
-                            .iter()
-                            .map(|(arg_name, arg_value)| {
-                                let transformed = transform_arg_value(arg_value);
-                                quote! { .#arg_name(#transformed) }
-                            })
-                            .collect();
+                        let builder_calls = builder_calls(v);
+                        // Entries are built from the key and value; everything else goes
+                        // through `build_validator`, which may defer the clone
+                        let build = |builder: TokenStream2| {
+                            if entry_types.is_some() && !f.redacts(v) {
+                                let mutability = (!fast).then(|| quote! { mut });
+                                (
+                                    quote! {
+                                        let #mutability validator = #builder
+                                            .with_values(__entry_key.clone(), __entry_value.clone())
+                                            .build();
+                                    },
+                                    quote! {},
+                                )
+                            } else {
+                                let stored_value =
+                                    stored_value(&quote! { __item_value }, f.redacts(v));
+                                if fast {
+                                    (probe_validator(&builder, &stored_value), quote! {})
+                                } else {
+                                    (build_validator(&builder, &stored_value), store_deferred_value())
+                                }
+                            }
+                        };
+                        let set_field_name = set_field_name(f);
+                        let observe_value =
+                            observe_value(&quote! { __item_value }, f.redacts(v));
+                        // Only validators implementing `ElementIndex` record the index;
+                        // map entries are identified by their key instead
+                        let set_index = if map_value_ty.is_some() {
+                            quote! {}
+                        } else {
+                            quote! {
+                                {
+                                    use koruma::__private::{ViaElementIndex as _, ViaNoElementIndex as _};
+                                    (&mut koruma::__private::IndexTag(&mut validator)).koruma_set_index(idx);
+                                }
+                            }
+                        };
 
-                        if v.infer_type || v.explicit_type.as_ref().is_some_and(contains_infer_type)
-                        {
+                        let on_failure = |store_deferred_value: TokenStream2| {
+                            if fast {
+                                quote! { return false; }
+                            } else {
+                                quote! {
+                                    #store_deferred_value
+                                    #set_index
+                                    #observe_value
+                                    #set_field_name
+                                    element_error.#validator_snake = Some(validator);
+                                }
+                            }
+                        };
+
+                        if validator_infers_type(v) {
                             let validator_ty = if let Some(ref explicit_ty) = v.explicit_type {
                                 if contains_infer_type(explicit_ty) {
                                     let inner_ty =
@@ -774,153 +1277,358 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
                                 field_name,
                                 validator_snake
                             );
+                            let (build_validator, store_deferred_value) = build(
+                                quote! { #validator::<#validator_ty>::builder() #(#builder_calls)* },
+                            );
+                            let on_failure = on_failure(store_deferred_value);
                             quote! {
-                                fn #assert_fn<V: koruma::Validate<T>, T>(v: &V, t: &T) -> bool {
+                                fn #assert_fn<V: #validate_bound, T>(v: &V, t: &T) -> #validate_output {
                                     v.validate(t)
                                 }
-                                let validator = #validator::<#validator_ty>::builder()
-                                    #(#builder_calls)*
-                                    .with_value(__item_value.clone())
-                                    .build();
-                                if !#assert_fn(&validator, __item_value) {
-                                    element_error.#validator_snake = Some(validator);
-                                    element_has_error = true;
+                                #build_validator
+                                if !#assert_fn(&validator, __item_value)#awaited {
+                                    #on_failure
                                 }
                             }
                         } else {
+                            let (build_validator, store_deferred_value) =
+                                build(quote! { #validator::builder() #(#builder_calls)* });
+                            let on_failure = on_failure(store_deferred_value);
                             quote! {
-                                let validator = #validator::builder()
-                                    #(#builder_calls)*
-                                    .with_value(__item_value.clone())
-                                    .build();
-                                if !validator.validate(__item_value) {
-                                    element_error.#validator_snake = Some(validator);
-                                    element_has_error = true;
+                                #build_validator
+                                if !#validate_trait::validate(&validator, __item_value)#awaited {
+                                    #on_failure
                                 }
                             }
                         }
                     })
                     .collect();
 
-                let element_validator_defaults: Vec<TokenStream2> = f
-                    .validation
-                    .element_validators
-                    .iter()
-                    .map(|v| {
-                        let validator_snake =
-                            format_ident!("{}", v.name().to_string().to_snake_case());
-                        quote! { #validator_snake: None }
-                    })
-                    .collect();
-
-                let inner_element_validation = quote! {
-                    let mut element_error = #element_error_struct_name {
-                        #(#element_validator_defaults),*
-                    };
-                    let mut element_has_error = false;
+            let inner_element_validation = if fast {
+                quote! { #(#element_validator_checks)* }
+            } else {
+                quote! {
+                    let mut element_error = #element_error_struct_name::default();
 
                     #(#element_validator_checks)*
 
-                    if element_has_error {
+                    if element_error.has_errors() {
                         error.#field_name.element_errors.push((idx, element_error));
-                        has_error = true;
-                    }
-                };
-
-                if element_is_optional {
-                    // For Vec<Option<T>>, skip None items
-                    quote! {
-                        for (idx, item) in self.#field_member.iter().enumerate() {
-                            if let Some(ref __item_value) = item {
-                                #inner_element_validation
-                            }
-                        }
-                    }
-                } else {
-                    // For Vec<T>, validate each item directly
-                    quote! {
-                        for (idx, __item_value) in self.#field_member.iter().enumerate() {
-                            #inner_element_validation
-                        }
                     }
                 }
-            } else {
-                quote! {}
             };
 
-            // Combine field validation and element validation
-            let field_is_optional = is_option_type(field_ty);
-            let has_full_type_validators = !full_type_validators.is_empty();
-            let has_unwrapped_validators = !unwrapped_validators.is_empty();
-
-            // Full-type validators run on the field directly (no Option unwrapping)
-            // Unwrapped validators run on the inner value (inside if let Some for Option fields)
-            if has_full_type_validators && has_unwrapped_validators && field_is_optional {
-                // Both full-type and unwrapped validators, optional field
+            if entry_types.is_some() {
+                // For maps, validate each `(key, value)` entry, keyed by the map key
                 quote! {
-                    #(#full_type_checks)*
-                    if let Some(ref __field_value) = self.#field_member {
-                        #(#unwrapped_checks)*
+                    for (__entry_key, __entry_value) in #access.iter() {
+                        let idx = __entry_key.clone();
+                        let __item_value = &(__entry_key.clone(), __entry_value.clone());
+                        #inner_element_validation
                     }
-                    #element_validation
                 }
-            } else if has_full_type_validators && has_unwrapped_validators {
-                // Both types, non-optional field
+            } else if map_value_ty.is_some() && element_is_optional {
+                // For maps with `each(...)` over `Option<T>` values, skip None values
                 quote! {
-                    #(#full_type_checks)*
-                    let __field_value = &self.#field_member;
-                    #(#unwrapped_checks)*
-                    #element_validation
+                    for (__entry_key, __map_value) in #access.iter() {
+                        if let Some(ref __item_value) = __map_value {
+                            let idx = __entry_key.clone();
+                            #inner_element_validation
+                        }
+                    }
                 }
-            } else if has_full_type_validators {
-                // Only full-type validators
+            } else if map_value_ty.is_some() {
+                // For maps with `each(...)`, validate each value, keyed by the map key
                 quote! {
-                    #(#full_type_checks)*
-                    #element_validation
+                    for (__entry_key, __item_value) in #access.iter() {
+                        let idx = __entry_key.clone();
+                        #inner_element_validation
+                    }
                 }
-            } else if has_unwrapped_validators && field_is_optional {
-                // Only unwrapped validators, optional field
+            } else if element_is_optional {
+                // For Vec<Option<T>>, skip None items
                 quote! {
-                    if let Some(ref __field_value) = self.#field_member {
-                        #(#unwrapped_checks)*
+                    for (idx, item) in #access.iter().enumerate() {
+                        if let Some(ref __item_value) = item {
+                            #inner_element_validation
+                        }
                     }
-                    #element_validation
                 }
-            } else if has_unwrapped_validators {
-                // Only unwrapped validators, non-optional field
+            } else {
+                // For Vec<T>, validate each item directly
                 quote! {
-                    let __field_value = &self.#field_member;
+                    for (idx, __item_value) in #access.iter().enumerate() {
+                        #inner_element_validation
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        // Combine field validation and element validation
+        let field_is_optional = is_option_type(field_ty);
+        let has_full_type_validators = !full_type_validators.is_empty();
+        let has_unwrapped_validators = !unwrapped_validators.is_empty();
+
+        // Full-type validators run on the field directly (no Option unwrapping)
+        // Unwrapped validators run on the inner value (inside if let Some for Option fields)
+        if has_full_type_validators && has_unwrapped_validators && field_is_optional {
+            // Both full-type and unwrapped validators, optional field
+            quote! {
+                #(#full_type_checks)*
+                if let Some(ref __field_value) = #access {
                     #(#unwrapped_checks)*
-                    #element_validation
                 }
+                #element_validation
+            }
+        } else if has_full_type_validators && has_unwrapped_validators {
+            // Both types, non-optional field
+            quote! {
+                #(#full_type_checks)*
+                let __field_value = &#access;
+                #(#unwrapped_checks)*
+                #element_validation
+            }
+        } else if has_full_type_validators {
+            // Only full-type validators
+            quote! {
+                #(#full_type_checks)*
+                #element_validation
+            }
+        } else if has_unwrapped_validators && field_is_optional {
+            // Only unwrapped validators, optional field
+            quote! {
+                if let Some(ref __field_value) = #access {
+                    #(#unwrapped_checks)*
+                }
+                #element_validation
+            }
+        } else if has_unwrapped_validators {
+            // Only unwrapped validators, non-optional field
+            quote! {
+                let __field_value = &#access;
+                #(#unwrapped_checks)*
+                #element_validation
+            }
+        } else {
+            // No field validators, only element validators
+            element_validation
+        }
+    };
+
+    // Checks of a field on `self`, guarded by its `when = ...` predicate
+    let field_check = |f: &FieldInfo, fast: bool| -> TokenStream2 {
+        let field_member = &f.member;
+        let check = field_validation(f, quote! { self.#field_member }, fast);
+        match f.validation.when {
+            // Typed as a fn pointer so closure parameters infer `&Self`
+            Some(ref when) => quote! {
+                let __koruma_when: fn(&Self) -> bool = #when;
+                if __koruma_when(self) {
+                    #check
+                }
+            },
+            None => check,
+        }
+    };
+
+    let validation_checks: Vec<TokenStream2> =
+        field_infos.iter().map(|f| field_check(f, false)).collect();
+    let fast_checks: Vec<TokenStream2> = field_infos.iter().map(|f| field_check(f, true)).collect();
+
+    // One lazily-run step per validator, or per nested/newtype field, for
+    // `validation_steps`. Each runs the field's checks with only that validator
+    let validation_steps: Vec<TokenStream2> = field_infos
+        .iter()
+        .flat_map(|f| {
+            let mut steps: Vec<(FieldInfo, Option<String>)> = Vec::new();
+            if f.is_nested() || f.is_newtype() {
+                steps.push((f.clone(), None));
             } else {
-                // No field validators, only element validators
-                element_validation
+                for v in &f.validation.field_validators {
+                    let mut single = f.clone();
+                    single.validation.field_validators = vec![v.clone()];
+                    single.validation.element_validators.clear();
+                    steps.push((single, Some(v.name().to_string().to_snake_case())));
+                }
+                for v in &f.validation.element_validators {
+                    let mut single = f.clone();
+                    single.validation.field_validators.clear();
+                    single.validation.element_validators = vec![v.clone()];
+                    steps.push((single, Some(v.name().to_string().to_snake_case())));
+                }
+            }
+            steps
+        })
+        .map(|(f, validator)| {
+            let field_name_str = f.external_name();
+            let validator = match validator {
+                Some(validator) => quote! { Some(#validator) },
+                None => quote! { None },
+            };
+            let bindings = shared_config_bindings(&struct_options, std::slice::from_ref(&f));
+            let check = field_check(&f, false);
+            quote! {
+                Box::new(move || {
+                    let mut error = #error_struct_name::default();
+                    #(#bindings)*
+                    #check
+                    koruma::StepResult::new(#field_name_str, #validator, error)
+                })
             }
         })
         .collect();
 
-    // Generate try_new function if requested
-    let try_new_fn = if struct_options.try_new {
-        // Collect all struct fields (not just validated ones) for constructor parameters
-        let all_field_params: Vec<TokenStream2> = fields
+    // Generate the patch struct and `validate_patch` if requested
+    let patch_impl = if struct_options.patch {
+        let syn::Fields::Named(named) = fields else {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "`patch` requires a struct with named fields",
+            ));
+        };
+        let patch_name = format_ident!("{}Patch", struct_name);
+        let patch_fields: Vec<TokenStream2> = named
+            .named
             .iter()
             .map(|f| {
-                let name = f.ident.as_ref().unwrap();
+                let name = &f.ident;
                 let ty = &f.ty;
-                quote! { #name: #ty }
+                quote! { #vis #name: Option<#ty> }
+            })
+            .collect();
+
+        // Validators with an argument naming another field (`max = limit`)
+        // can't run when that field may be absent, so patches skip them.
+        // `when` predicates read the whole struct, so those fields are
+        // skipped too
+        let patch_field_infos: Vec<FieldInfo> = field_infos
+            .iter()
+            .filter(|f| f.validation.when.is_none())
+            .filter_map(|f| {
+                let mut f = f.clone();
+                let reads_fields = |v: &ValidatorAttr| {
+                    v.args
+                        .iter()
+                        .any(|(_, e)| expr_as_simple_ident(e).is_some())
+                };
+                f.validation.field_validators.retain(|v| !reads_fields(v));
+                f.validation.element_validators.retain(|v| !reads_fields(v));
+                (f.is_nested() || f.is_newtype() || f.has_validators()).then_some(f)
+            })
+            .collect();
+        let patch_shared_bindings = shared_config_bindings(&struct_options, &patch_field_infos);
+        let patch_checks: Vec<TokenStream2> = patch_field_infos
+            .iter()
+            .map(|f| {
+                let field_member = &f.member;
+                let check = field_validation(f, quote! { (*__patch_value) }, false);
+                quote! {
+                    if let Some(ref __patch_value) = self.#field_member {
+                        #check
+                    }
+                }
             })
             .collect();
 
-        let all_field_names: Vec<&syn::Ident> =
-            fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+        let patch_doc = format!(
+            " Partial update of [`{struct_name}`]: every field is optional, and only the fields that are set get validated."
+        );
 
+        quote! {
+            #[doc = #patch_doc]
+            #[derive(Default)]
+            #vis struct #patch_name {
+                #(#patch_fields),*
+            }
+
+            impl #patch_name {
+                /// Validates the fields set in this patch, treating absent
+                /// fields as valid.
+                ///
+                /// Validators whose arguments read another field are skipped.
+                #vis fn validation_report(&self) -> #error_struct_name {
+                    let mut error = #error_struct_name {
+                        #(#error_defaults),*
+                    };
+
+                    #(#patch_shared_bindings)*
+
+                    #(#patch_checks)*
+
+                    error
+                }
+
+                /// Validates the fields set in this patch.
+                ///
+                /// Warning-severity failures alone don't make validation fail.
+                #vis fn validate(&self) -> Result<(), #error_struct_name> {
+                    let error = self.validation_report();
+                    if koruma::ValidationError::has_blocking_errors(&error) {
+                        Err(error)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl #struct_name {
+                /// Validates only the fields set in `patch`, for PATCH-style updates.
+                #vis fn validate_patch(patch: &#patch_name) -> Result<(), #error_struct_name> {
+                    patch.validate()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Generate try_new function if requested
+    if struct_options.builder && !matches!(fields, syn::Fields::Named(_)) {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "`builder` requires a struct with named fields",
+        ));
+    }
+
+    // Every named struct field is a constructor parameter of `try_new` and the
+    // builder, except context fields computed with `#[koruma(context = expr)]`
+    let all_field_params: Vec<TokenStream2> = fields
+        .iter()
+        .zip(&context_fields)
+        .filter(|(_, context)| !matches!(context, Some(ContextField::Computed(_))))
+        .filter_map(|(f, _)| {
+            let name = f.ident.as_ref()?;
+            let ty = &f.ty;
+            Some(quote! { #name: #ty })
+        })
+        .collect();
+
+    let computed_context: Vec<TokenStream2> = fields
+        .iter()
+        .zip(&context_fields)
+        .filter_map(|(f, context)| match context {
+            Some(ContextField::Computed(expr)) => {
+                let name = f.ident.as_ref()?;
+                let ty = &f.ty;
+                Some(quote! { let #name: #ty = #expr; })
+            },
+            _ => None,
+        })
+        .collect();
+
+    let all_field_names: Vec<&syn::Ident> =
+        fields.iter().filter_map(|f| f.ident.as_ref()).collect();
+
+    let try_new_fn = if struct_options.try_new {
         quote! {
             /// Creates a new instance and validates it.
             ///
             /// Returns `Ok(instance)` if all validations pass, or `Err(error)` where
             /// `error` contains the validation failures for each field.
-            pub fn try_new(#(#all_field_params),*) -> Result<Self, #error_struct_name> {
+            #vis fn try_new(#(#all_field_params),*) -> Result<Self, #error_struct_name> {
+                #(#computed_context)*
                 let instance = Self {
                     #(#all_field_names),*
                 };
@@ -932,6 +1640,92 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         quote! {}
     };
 
+    // Fields' `normalize = ...` functions only run once the whole struct validates
+    let normalizing_fields: Vec<&FieldInfo> = field_infos
+        .iter()
+        .filter(|f| f.validation.normalize.is_some())
+        .collect();
+    if !struct_options.normalize
+        && let Some(f) = normalizing_fields.first()
+    {
+        return Err(syn::Error::new_spanned(
+            f.validation.normalize.as_ref(),
+            format!(
+                "`normalize` on field `{}` needs the struct-level `#[koruma(normalize)]` option",
+                f.name
+            ),
+        ));
+    }
+    let validate_and_normalize_fn = if struct_options.normalize {
+        let normalize_calls = normalizing_fields.iter().map(|f| {
+            let member = &f.member;
+            let normalizer = f.validation.normalize.as_ref();
+            quote! { (#normalizer)(&mut self.#member); }
+        });
+        quote! {
+            /// Validates all fields and, if validation succeeds, normalizes
+            /// them in place.
+            ///
+            /// Each field's `normalize` function runs in field declaration
+            /// order, after every field has been validated. If validation
+            /// fails, `self` is left unchanged.
+            #vis fn validate_and_normalize(&mut self) -> Result<(), #error_struct_name> {
+                self.validate()?;
+                #(#normalize_calls)*
+                Ok(())
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let into_validated_fn = if struct_options.validated_wrapper {
+        quote! {
+            /// Validates the struct and, if it passes, wraps it in
+            /// [`koruma::Validated`] as proof of validation.
+            ///
+            /// Warning-severity failures alone don't make validation fail.
+            #vis fn into_validated(self) -> Result<koruma::Validated<Self>, #error_struct_name> {
+                koruma::__private::validated(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Generate a bon builder whose finishing function validates
+    let builder_impl = if struct_options.builder {
+        let builder_name = format_ident!("{}Builder", struct_name);
+        quote! {
+            #[koruma::bon::bon]
+            impl #struct_name {
+                /// Creates a builder for this struct.
+                ///
+                /// `build_validated()` constructs the instance and validates it,
+                /// returning `Err(error)` with the validation failures if any.
+                /// `Option` fields may be left unset, and `String` setters accept
+                /// anything convertible into a `String`.
+                #[builder(
+                    start_fn = builder,
+                    finish_fn = build_validated,
+                    builder_type = #builder_name,
+                    on(String, into)
+                )]
+                #[doc(hidden)]
+                #vis fn __koruma_build(#(#all_field_params),*) -> Result<Self, #error_struct_name> {
+                    #(#computed_context)*
+                    let instance = Self {
+                        #(#all_field_names),*
+                    };
+                    instance.validate()?;
+                    Ok(instance)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate NewtypeValidation marker trait impl for struct-level newtypes
     let newtype_marker_impl = if struct_options.newtype {
         quote! {
@@ -941,6 +1735,20 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         quote! {}
     };
 
+    // Generate validator::Validate impl for validator crate interop
+    let compat_validator_impl = if struct_options.compat_validator {
+        quote! {
+            impl koruma::validator_compat::Validate for #struct_name {
+                fn validate(&self) -> Result<(), koruma::validator_compat::ValidationErrors> {
+                    #struct_name::validate(self)
+                        .map_err(|error| koruma::validator_compat::to_validation_errors(&error))
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate Deref impl for newtype error structs
     let newtype_deref_impl = if struct_options.newtype {
         let field_info = &field_infos[0];
@@ -992,63 +1800,251 @@ pub fn expand_koruma(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
         quote! {}
     };
 
+    let bound_assertions = bound_assertions(&field_infos);
+    let clone_assertions = clone_assertions(&field_infos);
+    let failure_bits = failure_bits(&field_infos, &vis);
+
+    // `async` fields make the validation methods async. They can't back the
+    // synchronous `ValidateExt`, so that impl is left out
+    let (validate_fns, validate_ext_impl) = if is_async {
+        (
+            quote! {
+                /// Validates all fields, awaiting the `async` fields' validators,
+                /// and returns an error struct containing all validation failures.
+                ///
+                /// Returns `Ok(())` if all validations pass, or `Err(error)` where
+                /// `error` contains the validation failures for each field.
+                /// Warning-severity failures alone don't make validation fail.
+                #vis async fn validate(&self) -> Result<(), #error_struct_name> {
+                    let error = self.validation_report().await;
+                    if koruma::ValidationError::has_blocking_errors(&error) {
+                        Err(error)
+                    } else {
+                        Ok(())
+                    }
+                }
+
+                /// Runs every validator and returns all failures, including
+                /// warnings. The returned error is empty if nothing failed.
+                #vis async fn validation_report(&self) -> #error_struct_name {
+                    let mut error = #error_struct_name {
+                        #(#error_defaults),*
+                    };
+                    self.validate_into(&mut error).await;
+                    error
+                }
+
+                /// Validates into a caller-provided error, which is cleared first.
+                ///
+                /// Returns `true` if there are no blocking errors; warnings are
+                /// still recorded in `error`.
+                #vis async fn validate_into(&self, error: &mut #error_struct_name) -> bool {
+                    error.clear();
+
+                    #(#shared_bindings)*
+
+                    #(#validation_checks)*
+
+                    #cross_field_checks
+
+                    !koruma::ValidationError::has_blocking_errors(error)
+                }
+            },
+            quote! {},
+        )
+    } else {
+        (
+            quote! {
+                    /// Validates all fields and returns an error struct containing
+                    /// all validation failures.
+                    ///
+                    /// Returns `Ok(())` if all validations pass, or `Err(error)` where
+                    /// `error` contains the validation failures for each field.
+                    /// Warning-severity failures alone don't make validation fail.
+                    #vis fn validate(&self) -> Result<(), #error_struct_name> {
+                        let error = self.validation_report();
+                        if koruma::ValidationError::has_blocking_errors(&error) {
+                            Err(error)
+                        } else {
+                            Ok(())
+                        }
+                    }
+
+                    /// Runs every validator and returns all failures, including
+                    /// warnings. The returned error is empty if nothing failed.
+                    #vis fn validation_report(&self) -> #error_struct_name {
+                        let mut error = #error_struct_name {
+                            #(#error_defaults),*
+                        };
+                        self.validate_into(&mut error);
+                        error
+                    }
+
+                    /// Returns `true` if [`validate`](Self::validate) would pass,
+                    /// stopping at the first blocking failure.
+                    ///
+                    /// No error struct is built and warning-severity validators
+                    /// don't run, so this is the cheaper check when only the
+                    /// outcome matters.
+                    #[allow(unused_variables)]
+                    #vis fn is_valid(&self) -> bool {
+                        #(#shared_bindings)*
+
+                        #(#fast_checks)*
+
+                        #fast_cross_field_checks
+
+                        true
+                    }
+
+                    /// Returns an iterator running one validator per item, so
+                    /// validation can be stepped through or stopped early by dropping
+                    /// the iterator.
+                    ///
+                    /// Nested and newtype fields are a single step each, and every
+                    /// step's error holds only its own failures. Struct-level
+                    /// `cross_field` checks only run in [`validate`](Self::validate).
+                    #[allow(unused_mut, clippy::type_complexity)]
+                    #vis fn validation_steps(
+                        &self,
+                    ) -> impl Iterator<Item = koruma::StepResult<#error_struct_name>> + '_ {
+                        let mut steps: Vec<Box<dyn FnOnce() -> koruma::StepResult<#error_struct_name> + '_>> =
+                            Vec::new();
+                        #(steps.push(#validation_steps);)*
+                        steps.into_iter().map(|step| step())
+                    }
+
+                    /// Validates into a caller-provided error, which is cleared first.
+                    ///
+                    /// Reusing one error across calls avoids allocating a new error
+                    /// struct per validation. Returns `true` if there are no blocking
+                    /// errors; warnings are still recorded in `error`.
+                    #vis fn validate_into(&self, error: &mut #error_struct_name) -> bool {
+                        error.clear();
+
+                        #(#shared_bindings)*
+
+                        #(#validation_checks)*
+
+                        #cross_field_checks
+
+                        !koruma::ValidationError::has_blocking_errors(error)
+                    }
+            },
+            quote! {
+                impl koruma::ValidateExt for #struct_name {
+                    type Error = #error_struct_name;
+
+                    fn validate(&self) -> Result<(), #error_struct_name> {
+                        #struct_name::validate(self)
+                    }
+
+                    fn validation_report(&self) -> #error_struct_name {
+                        #struct_name::validation_report(self)
+                    }
+
+                    fn is_valid(&self) -> bool {
+                        #struct_name::is_valid(self)
+                    }
+                }
+            },
+        )
+    };
+
     Ok(quote! {
         // Per-field error structs
         #(#field_error_structs)*
 
+        #(#bound_assertions)*
+
+        #(#clone_assertions)*
+
         /// Auto-generated validation error struct for [`#struct_name`].
         ///
         /// Each field contains a nested error struct with `Option<Validator>` for each
         /// validator. Access errors via chained calls like `error.field().validator()`.
         #[derive(Clone, Debug, Default)]
-        pub struct #error_struct_name {
+        #vis struct #error_struct_name {
             #(#error_fields),*
         }
 
         impl #error_struct_name {
             #(#getter_methods)*
+
+            #cross_field_methods
+
+            #(#take_methods)*
+
+            /// Removes every failure, keeping the element-error buffers'
+            /// capacity so the struct can be refilled by `validate_into`.
+            #vis fn clear(&mut self) {
+                #(#clear_stmts)*
+            }
+
+            /// Adds `other`'s failures to this error, e.g. to combine the
+            /// reports of separate validation passes.
+            ///
+            /// A slot failed on both sides keeps this error's validator. A
+            /// slot's severity is fixed by its `#[koruma(...)]` attribute, so
+            /// the merged error is blocking if either side was.
+            #[allow(unused_variables)]
+            #vis fn merge(&mut self, other: Self) {
+                #(#merge_stmts)*
+            }
+
+            /// Consuming form of [`merge`](Self::merge).
+            #vis fn merge_with(mut self, other: Self) -> Self {
+                self.merge(other);
+                self
+            }
+
+            #failure_bits
         }
 
         #newtype_deref_impl
 
+        #patch_impl
+
+        #builder_impl
+
         impl koruma::ValidationError for #error_struct_name {
             fn is_empty(&self) -> bool {
                 #is_empty_body
             }
-        }
 
-        impl #struct_name {
-            #try_new_fn
+            fn has_blocking_errors(&self) -> bool {
+                #has_blocking_errors_body
+            }
 
-            /// Validates all fields and returns an error struct containing
-            /// all validation failures.
-            ///
-            /// Returns `Ok(())` if all validations pass, or `Err(error)` where
-            /// `error` contains the validation failures for each field.
-            pub fn validate(&self) -> Result<(), #error_struct_name> {
-                let mut error = #error_struct_name {
-                    #(#error_defaults),*
-                };
-                let mut has_error = false;
+            fn iter_failures(&self) -> impl Iterator<Item = koruma::Failure> {
+                #iter_failures_body
+            }
 
-                #(#validation_checks)*
+            #iter_cross_field_errors_fn
 
-                if has_error {
-                    Err(error)
-                } else {
-                    Ok(())
-                }
+            fn field_names(&self) -> &'static [&'static str] {
+                &[#(#validated_field_names),*]
+            }
+
+            fn failure_slot_names(&self) -> &'static [&'static str] {
+                Self::FAILURE_BIT_NAMES
             }
         }
 
-        impl koruma::ValidateExt for #struct_name {
-            type Error = #error_struct_name;
+        impl #struct_name {
+            #try_new_fn
 
-            fn validate(&self) -> Result<(), #error_struct_name> {
-                #struct_name::validate(self)
-            }
+            #validate_and_normalize_fn
+
+            #into_validated_fn
+
+            #validate_fns
         }
 
+        #validate_ext_impl
+
         #newtype_marker_impl
+
+        #compat_validator_impl
     })
 }
@@ -1,5 +1,8 @@
+use crate::expand::codegen::{FailureWalk, failure_walk, message_text};
 use heck::ToUpperCamelCase;
-use koruma_derive_core::{FieldInfo, ParseFieldResult, ValidatorAttr, parse_field};
+use koruma_derive_core::{
+    FieldInfo, ParseFieldResult, ValidatorAttr, parse_field, parse_struct_options,
+};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use syn::DeriveInput;
@@ -7,7 +10,10 @@ use syn::DeriveInput;
 /// Core expansion logic for the `#[derive(KorumaAllDisplay)]` derive macro.
 ///
 /// Generates `Display` implementations for the `{Struct}{Field}KorumaValidator` enums
-/// returned by the `all()` method. Each variant delegates to its inner validator's Display.
+/// returned by the `all()` method. Each variant delegates to its inner validator's Display,
+/// unless the validator has a `message = "..."` override.
+/// Also implements `koruma::ValidationMessages`, `Display` and `std::error::Error` for the
+/// generated error struct.
 pub fn expand_koruma_all_display(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
     let struct_name = &input.ident;
 
@@ -21,11 +27,16 @@ pub fn expand_koruma_all_display(input: DeriveInput) -> Result<TokenStream2, syn
         },
     };
 
-    // Parse all fields and extract validation info
+    let struct_options = parse_struct_options(&input.attrs)?;
+
+    // Parse all fields and extract validation info, resolving shared configs
     let mut field_infos: Vec<FieldInfo> = Vec::new();
     for (i, field) in fields.iter().enumerate() {
         match parse_field(field, i) {
-            ParseFieldResult::Valid(info) => field_infos.push(*info),
+            ParseFieldResult::Valid(mut info) => {
+                struct_options.resolve_shared(&mut info)?;
+                field_infos.push(*info);
+            },
             ParseFieldResult::Skip => {},
             ParseFieldResult::Error(e) => return Err(e),
         }
@@ -50,8 +61,16 @@ pub fn expand_koruma_all_display(input: DeriveInput) -> Result<TokenStream2, syn
                 .map(|v: &ValidatorAttr| {
                     let variant_name =
                         format_ident!("{}", v.name().to_string().to_upper_camel_case());
-                    quote! {
-                        #enum_name::#variant_name(v) => ::std::fmt::Display::fmt(v, f)
+                    match v.message {
+                        Some(_) => {
+                            let message = message_text(v);
+                            quote! {
+                                #enum_name::#variant_name(validator) => f.write_str(&#message)
+                            }
+                        },
+                        None => quote! {
+                            #enum_name::#variant_name(v) => ::std::fmt::Display::fmt(v, f)
+                        },
                     }
                 })
                 .collect();
@@ -87,8 +106,16 @@ pub fn expand_koruma_all_display(input: DeriveInput) -> Result<TokenStream2, syn
                 .map(|v: &ValidatorAttr| {
                     let variant_name =
                         format_ident!("{}", v.name().to_string().to_upper_camel_case());
-                    quote! {
-                        #enum_name::#variant_name(v) => ::std::fmt::Display::fmt(v, f)
+                    match v.message {
+                        Some(_) => {
+                            let message = message_text(v);
+                            quote! {
+                                #enum_name::#variant_name(validator) => f.write_str(&#message)
+                            }
+                        },
+                        None => quote! {
+                            #enum_name::#variant_name(v) => ::std::fmt::Display::fmt(v, f)
+                        },
                     }
                 })
                 .collect();
@@ -105,8 +132,88 @@ pub fn expand_koruma_all_display(input: DeriveInput) -> Result<TokenStream2, syn
         })
         .collect();
 
+    // Render a message for every failure of the generated error struct
+    let error_struct_name = format_ident!("{}KorumaValidationError", struct_name);
+    let message_pushes = failure_walk(&field_infos, FailureWalk::Messages);
+    let failure_messages_body = if message_pushes.is_empty() {
+        quote! { Vec::new() }
+    } else {
+        quote! {
+            let mut failures = Vec::new();
+            #(#message_pushes)*
+            failures
+        }
+    };
+
+    // Cross-field errors follow the field failures in the error's Display
+    let cross_field_lines = if struct_options.cross_field.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            for (key, cross_field_error) in self.cross_field_errors() {
+                if !first {
+                    f.write_str("\n")?;
+                }
+                first = false;
+                write!(f, "{}: {}", key, cross_field_error)?;
+            }
+        }
+    };
+
+    // The first failed nested or newtype field is the error's source
+    let source_checks: Vec<TokenStream2> = field_infos
+        .iter()
+        .filter_map(|f| {
+            let field_name = &f.name;
+            if f.is_nested() {
+                Some(quote! {
+                    if let Some(nested) = &self.#field_name {
+                        return Some(nested);
+                    }
+                })
+            } else if f.is_newtype() {
+                Some(quote! {
+                    if !koruma::ValidationError::is_empty(&self.#field_name.inner) {
+                        return Some(&self.#field_name.inner);
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
     Ok(quote! {
         #(#display_impls)*
         #(#element_display_impls)*
+
+        /// One `path: message` line per failure, in failure order.
+        impl ::std::fmt::Display for #error_struct_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let mut first = true;
+                for (failure, message) in koruma::ValidationMessages::failure_messages(self) {
+                    if !first {
+                        f.write_str("\n")?;
+                    }
+                    first = false;
+                    write!(f, "{}: {}", failure.path(), message)?;
+                }
+                #cross_field_lines
+                Ok(())
+            }
+        }
+
+        impl ::std::error::Error for #error_struct_name {
+            fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                #(#source_checks)*
+                None
+            }
+        }
+
+        impl koruma::ValidationMessages for #error_struct_name {
+            fn failure_messages(&self) -> Vec<(koruma::Failure, String)> {
+                #failure_messages_body
+            }
+        }
     })
 }
@@ -1,5 +1,8 @@
+use crate::expand::codegen::{FailureWalk, failure_walk};
 use heck::ToUpperCamelCase;
-use koruma_derive_core::{FieldInfo, ParseFieldResult, ValidatorAttr, parse_field};
+use koruma_derive_core::{
+    FieldInfo, ParseFieldResult, ValidatorAttr, parse_field, parse_struct_options,
+};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use syn::DeriveInput;
@@ -8,6 +11,8 @@ use syn::DeriveInput;
 ///
 /// Generates `ToFluentString` implementations for the `{Struct}{Field}KorumaValidator` enums
 /// returned by the `all()` method. Each variant delegates to its inner validator's ToFluentString.
+/// Also implements `koruma::LocalizedMessages` for the generated error struct and adds
+/// its `localized_summary` method.
 #[cfg(feature = "fluent")]
 pub fn expand_koruma_all_fluent(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
     let struct_name = &input.ident;
@@ -22,11 +27,16 @@ pub fn expand_koruma_all_fluent(input: DeriveInput) -> Result<TokenStream2, syn:
         },
     };
 
-    // Parse all fields and extract validation info
+    let struct_options = parse_struct_options(&input.attrs)?;
+
+    // Parse all fields and extract validation info, resolving shared configs
     let mut field_infos: Vec<FieldInfo> = Vec::new();
     for (i, field) in fields.iter().enumerate() {
         match parse_field(field, i) {
-            ParseFieldResult::Valid(info) => field_infos.push(*info),
+            ParseFieldResult::Valid(mut info) => {
+                struct_options.resolve_shared(&mut info)?;
+                field_infos.push(*info);
+            },
             ParseFieldResult::Skip => {},
             ParseFieldResult::Error(e) => return Err(e),
         }
@@ -108,8 +118,43 @@ pub fn expand_koruma_all_fluent(input: DeriveInput) -> Result<TokenStream2, syn:
         })
         .collect();
 
+    // Render a localized message for every failure of the generated error struct
+    let error_struct_name = format_ident!("{}KorumaValidationError", struct_name);
+    let message_pushes = failure_walk(&field_infos, FailureWalk::FluentMessages);
+    let localized_messages_body = if message_pushes.is_empty() {
+        quote! { Vec::new() }
+    } else {
+        quote! {
+            let mut failures = Vec::new();
+            #(#message_pushes)*
+            failures
+        }
+    };
+
     Ok(quote! {
         #(#fluent_impls)*
         #(#element_fluent_impls)*
+
+        impl koruma::LocalizedMessages for #error_struct_name {
+            fn localized_failure_messages(&self) -> Vec<(koruma::Failure, String)> {
+                #localized_messages_body
+            }
+        }
+
+        impl #error_struct_name {
+            /// Returns every failure message rendered in `lang`, sorted by
+            /// descending priority, then declaration order.
+            ///
+            /// Renders through `koruma::with_locale`, so es-fluent's global
+            /// language and other threads are left alone. A message missing
+            /// from `lang` goes through the fallback chain, then the global
+            /// language.
+            pub fn localized_summary(
+                &self,
+                lang: &::es_fluent::unic_langid::LanguageIdentifier,
+            ) -> Result<Vec<String>, koruma::LocalizerTaken> {
+                koruma::with_locale(lang, || koruma::LocalizedMessages::prioritized_messages(self))
+            }
+        }
     })
 }
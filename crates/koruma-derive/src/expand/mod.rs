@@ -7,13 +7,17 @@ pub(crate) mod derive;
 pub(crate) mod display;
 #[cfg(feature = "fluent")]
 pub(crate) mod fluent;
+#[cfg(feature = "serde")]
+pub(crate) mod serialize;
 pub(crate) mod validator;
 
 pub use derive::expand_koruma;
 pub use display::expand_koruma_all_display;
 #[cfg(feature = "fluent")]
 pub use fluent::expand_koruma_all_fluent;
-pub use validator::expand_validator;
+#[cfg(feature = "serde")]
+pub use serialize::expand_koruma_serialize;
+pub use validator::{ValidatorArgs, expand_validator};
 
 // Re-exports for tests and internal usage
 #[allow(unused_imports)]
@@ -31,13 +35,14 @@ pub(crate) use koruma_derive_core::ShowcaseAttr;
 pub(crate) use koruma_derive_core::find_showcase_attr;
 #[allow(unused_imports)]
 pub(crate) use koruma_derive_core::{
-    FieldInfo, KorumaAttr, ParseFieldResult, StructOptions, ValidatorAttr, find_value_field,
-    parse_field, parse_struct_options,
+    FieldInfo, KorumaAttr, ParseFieldResult, StructOptions, ValidatorAttr, find_index_field,
+    find_value_field, parse_field, parse_struct_options,
 };
 
 // Re-export utility functions from koruma-derive-core
 #[allow(unused_imports)]
 pub(crate) use koruma_derive_core::{
     contains_infer_type, expr_as_simple_ident, first_generic_arg, is_option_infer_type,
-    is_option_type, option_inner_type, substitute_infer_type, vec_inner_type,
+    is_option_type, map_entry_types, option_inner_type, sequence_inner_type, substitute_infer_type,
+    vec_inner_type,
 };
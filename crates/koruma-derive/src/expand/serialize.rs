@@ -0,0 +1,206 @@
+use crate::expand::codegen::message_text;
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use koruma_derive_core::{
+    FieldInfo, ParseFieldResult, ValidatorAttr, parse_field, parse_struct_options,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+/// Expansion of the `Serialize` impls that `#[derive(Koruma)]` adds with the
+/// `serde` feature.
+///
+/// Kept apart from [`expand_koruma`](crate::expand::expand_koruma) so that its
+/// output doesn't depend on the feature.
+pub fn expand_koruma_serialize(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let struct_name = &input.ident;
+
+    let fields = match &input.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "Koruma can only be derived for structs",
+            ));
+        },
+    };
+
+    let struct_options = parse_struct_options(&input.attrs)?;
+
+    // Parse all fields and extract validation info, resolving shared configs
+    let mut field_infos: Vec<FieldInfo> = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        match parse_field(field, i) {
+            ParseFieldResult::Valid(mut info) => {
+                struct_options.resolve_shared(&mut info)?;
+                field_infos.push(*info);
+            },
+            ParseFieldResult::Skip => {},
+            ParseFieldResult::Error(e) => return Err(e),
+        }
+    }
+
+    Ok(serialize_impls(
+        struct_name,
+        &field_infos,
+        !struct_options.cross_field.is_empty(),
+    ))
+}
+
+/// `Serialize` impls for the error struct and its per-field error structs.
+///
+/// Each failed validator becomes `{"<validator_snake>": {...params, "message": ...}}`,
+/// with parameters only for validators that implement `Serialize` and a message only
+/// for those that implement `Display` or set `message = "..."`. Element failures go
+/// in an `elements` array tagged with their `index` (or map `key`), nested and newtype
+/// fields serialize as their inner error, and cross-field failures go under
+/// `cross_field`. Fields without failures are omitted.
+fn serialize_impls(
+    struct_name: &syn::Ident,
+    field_infos: &[FieldInfo],
+    has_cross_field: bool,
+) -> TokenStream2 {
+    let failure_inserts = |validators: &[ValidatorAttr], slot: TokenStream2| -> Vec<TokenStream2> {
+        validators
+            .iter()
+            .map(|v| {
+                let validator_snake = format_ident!("{}", v.name().to_string().to_snake_case());
+                let validator_str = validator_snake.to_string();
+                let message = failure_message(v);
+                quote! {
+                    if let Some(validator) = &#slot.#validator_snake {
+                        failures.insert(
+                            #validator_str.to_owned(),
+                            (&koruma::__private::ParamsTag(validator)).koruma_failure_json(#message),
+                        );
+                    }
+                }
+            })
+            .collect()
+    };
+
+    let serialize_map = |name: &syn::Ident, body: TokenStream2| {
+        quote! {
+            impl koruma::__private::serde::Serialize for #name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: koruma::__private::serde::Serializer,
+                {
+                    #[allow(unused_imports)]
+                    use koruma::__private::{
+                        ViaDisplayMessage, ViaNoDisplayMessage, ViaNoSerializeParams,
+                        ViaSerializeParams,
+                    };
+                    let mut failures = koruma::__private::serde_json::Map::new();
+                    #body
+                    koruma::__private::serde::Serialize::serialize(&failures, serializer)
+                }
+            }
+        }
+    };
+
+    let mut impls = Vec::new();
+    let mut field_entries = Vec::new();
+    for f in field_infos {
+        let field_name = &f.name;
+        let field_name_str = f.external_name();
+        let camel = field_name.to_string().to_upper_camel_case();
+
+        let failure = if f.is_nested() {
+            quote! { self.#field_name.as_ref() }
+        } else if f.is_newtype() {
+            quote! {
+                Some(&self.#field_name.inner)
+                    .filter(|inner| !koruma::ValidationError::is_empty(*inner))
+            }
+        } else {
+            let field_error_struct_name =
+                format_ident!("{}{}KorumaValidationError", struct_name, camel);
+            let field_inserts = failure_inserts(&f.validation.field_validators, quote! { self });
+            let elements = if f.has_element_validators() {
+                let element_error_struct_name =
+                    format_ident!("{}{}ElementKorumaValidationError", struct_name, camel);
+                let element_inserts =
+                    failure_inserts(&f.validation.element_validators, quote! { self });
+                impls.push(serialize_map(
+                    &element_error_struct_name,
+                    quote! { #(#element_inserts)* },
+                ));
+                let position = if f.validates_map() {
+                    quote! { "key", ::std::string::ToString::to_string(idx).into() }
+                } else {
+                    quote! { "index", (*idx).into() }
+                };
+                quote! {
+                    if !self.element_errors.is_empty() {
+                        let mut elements = Vec::new();
+                        for (idx, element_error) in &self.element_errors {
+                            elements.push(
+                                koruma::__private::element_json(#position, element_error)
+                                    .map_err(koruma::__private::serde::ser::Error::custom)?,
+                            );
+                        }
+                        failures.insert("elements".to_owned(), elements.into());
+                    }
+                }
+            } else {
+                quote! {}
+            };
+            impls.push(serialize_map(
+                &field_error_struct_name,
+                quote! {
+                    #(#field_inserts)*
+                    #elements
+                },
+            ));
+            quote! {
+                Some(&self.#field_name).filter(|field_error| !field_error.is_empty())
+            }
+        };
+
+        field_entries.push(quote! {
+            if let Some(field_error) = #failure {
+                failures.insert(
+                    #field_name_str.to_owned(),
+                    koruma::__private::serde_json::to_value(field_error)
+                        .map_err(koruma::__private::serde::ser::Error::custom)?,
+                );
+            }
+        });
+    }
+
+    if has_cross_field {
+        field_entries.push(quote! {
+            if !self.cross_field_errors().is_empty() {
+                let cross_field: koruma::__private::serde_json::Map<String, _> = self
+                    .cross_field_errors()
+                    .iter()
+                    .map(|(key, cross_field_error)| {
+                        (key.to_string(), cross_field_error.to_string().into())
+                    })
+                    .collect();
+                failures.insert("cross_field".to_owned(), cross_field.into());
+            }
+        });
+    }
+
+    let error_struct_name = format_ident!("{}KorumaValidationError", struct_name);
+    impls.push(serialize_map(
+        &error_struct_name,
+        quote! { #(#field_entries)* },
+    ));
+
+    quote! { #(#impls)* }
+}
+
+/// Expression rendering the `message` of a failed `validator`, if it has one:
+/// its `message = "..."`, or its `Display` output when it implements `Display`.
+fn failure_message(v: &ValidatorAttr) -> TokenStream2 {
+    match &v.message {
+        Some(_) => {
+            let message = message_text(v);
+            quote! { Some(#message) }
+        },
+        None => quote! { (&koruma::__private::MessageTag(validator)).koruma_message() },
+    }
+}
@@ -1,15 +1,52 @@
 use heck::{ToSnakeCase, ToUpperCamelCase};
+use koruma_derive_core::{find_index_field, find_value_field, is_option_type, option_inner_type};
 #[cfg(feature = "showcase")]
-use koruma_derive_core::find_showcase_attr;
-use koruma_derive_core::{find_value_field, option_inner_type};
+use koruma_derive_core::{find_showcase_attr, showcase_params};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use syn::{Fields, GenericParam, Ident, ItemStruct, parse_quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Fields, GenericParam, Ident, ItemStruct, Token, parse_quote};
+
+/// Arguments of the `#[validator(...)]` attribute.
+#[derive(Clone, Debug, Default)]
+pub struct ValidatorArgs {
+    /// `deferred_value`: implement `koruma::DeferredValue`
+    pub deferred_value: bool,
+}
+
+impl Parse for ValidatorArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = ValidatorArgs::default();
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            match ident.to_string().as_str() {
+                "deferred_value" => args.deferred_value = true,
+                other => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!(
+                            "unknown koruma::validator argument `{}`. Expected `deferred_value`",
+                            other
+                        ),
+                    ));
+                },
+            }
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
 
 /// Core expansion logic for the `#[validator]` attribute macro.
 ///
-/// Takes a parsed struct and returns the expanded TokenStream.
-pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Error> {
+/// Takes the attribute's arguments and a parsed struct and returns the
+/// expanded TokenStream.
+pub fn expand_validator(
+    args: ValidatorArgs,
+    mut input: ItemStruct,
+) -> Result<TokenStream2, syn::Error> {
     let struct_name = &input.ident;
     let builder_name = format_ident!("{}Builder", struct_name);
 
@@ -19,6 +56,9 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
     // Parse showcase attribute if present (only when feature enabled)
     #[cfg(feature = "showcase")]
     let showcase_attr = find_showcase_attr(&input);
+    // Parameters are read before the `#[koruma(...)]` field markers are removed
+    #[cfg(feature = "showcase")]
+    let showcase_params = showcase_params(&input);
 
     // Find the field marked with #[koruma(value)]
     let (value_field_name, value_field_type) = find_value_field(&input).ok_or_else(|| {
@@ -31,8 +71,43 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
         )
     })?;
 
+    // Find the optional field marked with #[koruma(index)]
+    let index_field = find_index_field(&input);
+    if let Some((_, ref index_ty)) = index_field
+        && !is_option_type(index_ty)
+    {
+        return Err(syn::Error::new_spanned(
+            index_ty,
+            "the #[koruma(index)] field must be an `Option<usize>`",
+        ));
+    }
+
+    // An optional `field_name` field receives the name of the validated field
+    let field_name_field = match input.fields {
+        Fields::Named(ref fields) => fields.named.iter().find(|field| {
+            field
+                .ident
+                .as_ref()
+                .is_some_and(|ident| ident == "field_name")
+        }),
+        _ => None,
+    };
+    if let Some(field) = field_name_field
+        && !is_option_type(&field.ty)
+    {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "the `field_name` field must be an `Option<&'static str>`",
+        ));
+    }
+    let has_field_name = field_name_field.is_some();
+
     // Extract the inner type from Option<T>
     let inner_type = option_inner_type(&value_field_type).unwrap_or(&value_field_type);
+    // A `#[builder(with = |value: Ty| ...)]` closure on the value field changes
+    // what `with_value` takes
+    let value_input_type =
+        builder_with_input_type(&input, &value_field_name)?.unwrap_or_else(|| inner_type.clone());
 
     // Add #[derive(bon::Builder)] to the existing attributes
     let builder_attr: syn::Attribute = parse_quote!(#[derive(koruma::bon::Builder)]);
@@ -41,14 +116,29 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
     // Remove #[koruma(value)] and #[showcase(...)] from attributes
     input.attrs.retain(|attr| !attr.path().is_ident("showcase"));
 
-    // Remove #[koruma(value)] from the field so bon doesn't see it
+    // Remove #[koruma(value)] and #[koruma(index)] from the fields so bon doesn't see them.
+    // The index is set after building, so it's skipped by the builder.
     if let Fields::Named(ref mut fields) = input.fields {
         for field in &mut fields.named {
+            if index_field
+                .as_ref()
+                .is_some_and(|(name, _)| field.ident.as_ref() == Some(name))
+            {
+                field.attrs.push(parse_quote!(#[builder(skip)]));
+            }
+            if has_field_name
+                && field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| ident == "field_name")
+            {
+                field.attrs.push(parse_quote!(#[builder(skip)]));
+            }
             field.attrs.retain(|attr| {
                 if attr.path().is_ident("koruma")
                     && let Ok(ident) = attr.parse_args::<Ident>()
                 {
-                    return ident != "value";
+                    return ident != "value" && ident != "index";
                 }
 
                 true
@@ -56,6 +146,62 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
         }
     }
 
+    let element_index_impl = if let Some((ref index_field_name, _)) = index_field {
+        let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics koruma::ElementIndex for #struct_name #type_generics #where_clause {
+                fn set_index(&mut self, index: usize) {
+                    self.#index_field_name = Some(index);
+                }
+
+                fn index(&self) -> Option<usize> {
+                    self.#index_field_name
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let stored_value_impl = {
+        let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics koruma::StoredValue for #struct_name #type_generics #where_clause {
+                type Value = #value_field_type;
+
+                fn stored_value(&self) -> &Self::Value {
+                    &self.#value_field_name
+                }
+            }
+        }
+    };
+
+    let field_name_impl = if has_field_name {
+        let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics koruma::HasFieldName for #struct_name #type_generics #where_clause {
+                fn set_field_name(&mut self, name: &'static str) {
+                    self.field_name = Some(name);
+                }
+
+                fn field_name(&self) -> Option<&'static str> {
+                    self.field_name
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let deferred_value_impl = if args.deferred_value {
+        let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics koruma::DeferredValue for #struct_name #type_generics #where_clause {}
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate the module name that bon creates (snake_case of struct name + _builder)
     let module_name = format_ident!("{}_builder", struct_name.to_string().to_snake_case());
 
@@ -64,6 +210,21 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
     let value_assoc_type = format_ident!("{}", value_pascal);
     let set_value_type = format_ident!("Set{}", value_pascal);
 
+    // Validators over `(key, value)` pairs also get a two-argument `with_values`,
+    // used by `entries(...)` to hand over a map entry.
+    let with_values_fn = |set_state: TokenStream2| match inner_type {
+        syn::Type::Tuple(tuple) if tuple.elems.len() == 2 => {
+            let (first, second) = (&tuple.elems[0], &tuple.elems[1]);
+            quote! {
+                /// Sets the value field from its two halves. This is auto-generated by `#[koruma::validator]`.
+                pub fn with_values(self, first: #first, second: #second) -> #set_state {
+                    self.#value_field_name((first, second))
+                }
+            }
+        },
+        _ => quote! {},
+    };
+
     let with_value_impl = if has_generics {
         // For generic validators, the builder is Builder<T, S> (type param first, then state)
         // Use the actual field type (inner_type) for the value parameter
@@ -111,27 +272,38 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
             predicates
         };
 
+        let with_values = with_values_fn(
+            quote! { #builder_name<#(#type_param_names,)* #module_name::#set_value_type<S>> },
+        );
+
         quote! {
             impl<#(#type_param_names,)* S: #module_name::State> #builder_name<#(#type_param_names,)* S>
             where
                 #(#where_predicates),*
             {
                 /// Sets the value field. This is auto-generated by `#[koruma::validator]`.
-                pub fn with_value(self, value: #inner_type) -> #builder_name<#(#type_param_names,)* #module_name::#set_value_type<S>> {
+                pub fn with_value(self, value: #value_input_type) -> #builder_name<#(#type_param_names,)* #module_name::#set_value_type<S>> {
                     self.#value_field_name(value)
                 }
+
+                #with_values
             }
         }
     } else {
+        let with_values =
+            with_values_fn(quote! { #builder_name<#module_name::#set_value_type<S>> });
+
         quote! {
             impl<S: #module_name::State> #builder_name<S>
             where
                 S::#value_assoc_type: koruma::bon::IsUnset,
             {
                 /// Sets the value field. This is auto-generated by `#[koruma::validator]`.
-                pub fn with_value(self, value: #inner_type) -> #builder_name<#module_name::#set_value_type<S>> {
+                pub fn with_value(self, value: #value_input_type) -> #builder_name<#module_name::#set_value_type<S>> {
                     self.#value_field_name(value)
                 }
+
+                #with_values
             }
         }
     };
@@ -142,10 +314,32 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
         let name = &showcase.name;
         let description = &showcase.description;
         let create_closure = &showcase.create;
-        let input_type_tokens = if let Some(ref it) = showcase.input_type {
-            quote! { ::koruma::showcase::InputType::#it }
+        let create_body = if showcase.fallible {
+            quote! {
+                (#create_closure)(input)
+                    .map(|validator| Box::new(validator) as Box<dyn ::koruma::showcase::DynValidator>)
+            }
+        } else {
+            quote! { Ok(Box::new((#create_closure)(input))) }
+        };
+        let parameters = showcase_params.iter().map(|param| {
+            let (name, type_name) = (&param.name, &param.type_name);
+            let default = match param.default {
+                Some(ref default) => quote! { Some(#default) },
+                None => quote! { None },
+            };
+            quote! {
+                ::koruma::showcase::ShowcaseParam {
+                    name: #name,
+                    type_name: #type_name,
+                    default: #default,
+                }
+            }
+        });
+        let input_kind_tokens = if let Some(ref kind) = showcase.input {
+            quote! { ::koruma::showcase::InputKind::#kind }
         } else {
-            quote! { ::koruma::showcase::InputType::Text }
+            quote! { ::koruma::showcase::InputKind::String }
         };
 
         // Extract generics from the struct
@@ -207,9 +401,14 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
                 ::koruma::showcase::ValidatorShowcase {
                     name: #name,
                     description: #description,
-                    input_type: #input_type_tokens,
-                    create_validator: |input: &str| -> Box<dyn ::koruma::showcase::DynValidator> {
-                        Box::new((#create_closure)(input))
+                    input_kind: #input_kind_tokens,
+                    module_path: ::core::module_path!(),
+                    parameters: &[#(#parameters),*],
+                    create_validator: |input: &str| -> ::std::result::Result<
+                        Box<dyn ::koruma::showcase::DynValidator>,
+                        ::std::string::String,
+                    > {
+                        #create_body
                     },
                 }
             }
@@ -226,6 +425,63 @@ pub fn expand_validator(mut input: ItemStruct) -> Result<TokenStream2, syn::Erro
 
         #with_value_impl
 
+        #stored_value_impl
+
+        #element_index_impl
+
+        #field_name_impl
+
+        #deferred_value_impl
+
         #showcase_registration
     })
 }
+
+/// Returns the argument type of a `#[builder(with = |value: Ty| ...)]` closure
+/// on `field_name`, if it has one.
+fn builder_with_input_type(
+    input: &ItemStruct,
+    field_name: &Ident,
+) -> Result<Option<syn::Type>, syn::Error> {
+    let Fields::Named(ref fields) = input.fields else {
+        return Ok(None);
+    };
+    let Some(field) = fields
+        .named
+        .iter()
+        .find(|field| field.ident.as_ref() == Some(field_name))
+    else {
+        return Ok(None);
+    };
+
+    let mut input_type = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("builder"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                let closure: syn::ExprClosure = meta.value()?.parse()?;
+                match closure.inputs.first() {
+                    Some(syn::Pat::Type(arg)) if closure.inputs.len() == 1 => {
+                        input_type = Some((*arg.ty).clone());
+                    },
+                    _ => {
+                        return Err(meta.error(
+                            "the value field's `with` closure must take one typed argument, \
+                             e.g. `|value: T| ...`",
+                        ));
+                    },
+                }
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(input_type)
+}
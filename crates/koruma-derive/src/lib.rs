@@ -3,12 +3,14 @@ mod expand;
 mod tests;
 
 use proc_macro::TokenStream;
-use proc_macro_error2::{abort, proc_macro_error};
+use proc_macro_error2::proc_macro_error;
 use syn::{DeriveInput, ItemStruct, parse_macro_input};
 
 #[cfg(feature = "fluent")]
 use expand::expand_koruma_all_fluent;
-use expand::{expand_koruma, expand_koruma_all_display, expand_validator};
+#[cfg(feature = "serde")]
+use expand::expand_koruma_serialize;
+use expand::{ValidatorArgs, expand_koruma, expand_koruma_all_display, expand_validator};
 
 /// Attribute macro for validator structs.
 ///
@@ -17,6 +19,14 @@ use expand::{expand_koruma, expand_koruma_all_display, expand_validator};
 /// - Generates a `with_value` method on the builder that delegates to the field
 ///   marked with `#[koruma(value)]`
 ///
+/// If the value field has a `#[builder(with = |value: T| ...)]` closure,
+/// `with_value` takes the closure's argument type, so a validator can store
+/// something derived from the value instead of the value itself.
+///
+/// `#[koruma::validator(deferred_value)]` also implements `koruma::DeferredValue`,
+/// letting `#[derive(Koruma)]` clone the value only when validation fails. Use it
+/// only when `validate` reads just the configuration and the value it's given.
+///
 /// # Example (non-generic)
 ///
 /// ```ignore
@@ -59,15 +69,10 @@ use expand::{expand_koruma, expand_koruma_all_display, expand_validator};
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn validator(attr: TokenStream, item: TokenStream) -> TokenStream {
-    // Ensure no arguments
-    if !attr.is_empty() {
-        let attr2 = proc_macro2::TokenStream::from(attr);
-        abort!(attr2, "koruma::validator does not accept arguments");
-    }
-
+    let args = parse_macro_input!(attr as ValidatorArgs);
     let input = parse_macro_input!(item as ItemStruct);
 
-    match expand_validator(input) {
+    match expand_validator(args, input) {
         Ok(tokens) => TokenStream::from(tokens),
         Err(e) => TokenStream::from(e.to_compile_error()),
     }
@@ -95,6 +100,7 @@ pub fn validator(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `ItemValidationError` struct with `Option<ValidatorType>` for each validated field
 /// - Getter methods returning `Option<&ValidatorType>` for each field
 /// - `validate(&self) -> Result<(), ItemValidationError>` method on `Item`
+/// - with the `serde` feature, `Serialize` impls for the error structs
 ///
 /// The macro always generates `.with_value(self.field.clone())` for validators.
 #[proc_macro_error]
@@ -102,7 +108,15 @@ pub fn validator(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn derive_koruma(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    match expand_koruma(input) {
+    #[cfg(feature = "serde")]
+    let expanded = expand_koruma(input.clone()).and_then(|tokens| {
+        let serialize_impls = expand_koruma_serialize(input)?;
+        Ok(quote::quote! { #tokens #serialize_impls })
+    });
+    #[cfg(not(feature = "serde"))]
+    let expanded = expand_koruma(input);
+
+    match expanded {
         Ok(tokens) => TokenStream::from(tokens),
         Err(e) => TokenStream::from(e.to_compile_error()),
     }
@@ -114,6 +128,10 @@ pub fn derive_koruma(input: TokenStream) -> TokenStream {
 /// for the `{Struct}{Field}KorumaValidator` enums returned by the `all()` method.
 /// Each variant delegates to its inner validator's `Display` implementation.
 ///
+/// It also implements `koruma::ValidationMessages` for the generated error struct,
+/// pairing every failure with its message. Types used as `nested` or `newtype`
+/// fields must derive `KorumaAllDisplay` as well.
+///
 /// # Example
 ///
 /// ```ignore
@@ -147,6 +165,11 @@ pub fn derive_koruma_all_display(input: TokenStream) -> TokenStream {
 /// for the `{Struct}{Field}KorumaValidator` enums returned by the `all()` method.
 /// Each variant delegates to its inner validator's `ToFluentString` implementation.
 ///
+/// Also implements `koruma::LocalizedMessages` for the generated error struct and
+/// adds a `localized_summary(lang)` method returning its messages in `lang`,
+/// sorted by descending `priority = N` then declaration order. It renders
+/// through `koruma::with_locale`, without changing es-fluent's global language.
+///
 /// Requires the `fluent` feature to be enabled.
 ///
 /// # Example
@@ -164,6 +187,9 @@ pub fn derive_koruma_all_display(input: TokenStream) -> TokenStream {
 /// // for err in errors.sku().all() {
 /// //     println!("{}", err.to_fluent_string());  // Uses i18n
 /// // }
+/// //
+/// // Or render every failure at once:
+/// // let messages = errors.localized_summary(&langid!("fr"))?;
 /// ```
 #[cfg(feature = "fluent")]
 #[proc_macro_error]
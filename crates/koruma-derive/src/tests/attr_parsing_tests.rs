@@ -176,3 +176,249 @@ fn test_struct_options_parse_unknown_error() {
         err
     );
 }
+
+#[test]
+fn test_validator_attr_parse_severity() {
+    // `severity` is reserved and not passed on as a builder arg
+    let attr: ValidatorAttr =
+        syn::parse_quote!(RangeValidation(min = 0, max = 100, severity = "warning"));
+    assert_eq!(attr.args.len(), 2);
+    assert_eq!(attr.severity, Some(Severity::Warning));
+}
+
+#[test]
+fn test_validator_attr_parse_unknown_severity_error() {
+    let result: Result<ValidatorAttr, _> = syn::parse_str(r#"RangeValidation(severity = "fatal")"#);
+    assert!(result.is_err(), "expected error for unknown severity");
+    let err = result.err().unwrap().to_string();
+    assert!(err.contains("unknown severity"), "got: {}", err);
+}
+
+#[test]
+fn test_validator_attr_parse_help() {
+    // `help` is reserved and not passed on as a builder arg
+    let attr: ValidatorAttr =
+        syn::parse_quote!(RangeValidation(min = 0, max = 100, help = "use 0 to 100"));
+    assert_eq!(attr.args.len(), 2);
+    assert!(matches!(&attr.help, Some(Help::Text(text)) if text.value() == "use 0 to 100"));
+
+    let attr: ValidatorAttr = syn::parse_quote!(RangeValidation(help = fluent("range-help")));
+    assert!(attr.args.is_empty());
+    assert!(matches!(&attr.help, Some(Help::Fluent(id)) if id.value() == "range-help"));
+}
+
+#[test]
+fn test_validator_attr_parse_invalid_help_error() {
+    let result: Result<ValidatorAttr, _> = syn::parse_str(r#"RangeValidation(help = 42)"#);
+    assert!(result.is_err(), "expected error for non-string help");
+    let err = result.err().unwrap().to_string();
+    assert!(
+        err.contains("help must be a string literal"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_validator_attr_parse_priority() {
+    // `priority` is reserved and accepts negative literals
+    let attr: ValidatorAttr = syn::parse_quote!(RangeValidation(min = 0, priority = 10));
+    assert_eq!(attr.args.len(), 1);
+    assert_eq!(attr.priority, Some(10));
+
+    let attr: ValidatorAttr = syn::parse_quote!(RangeValidation(priority = -2));
+    assert_eq!(attr.priority, Some(-2));
+
+    let result: Result<ValidatorAttr, _> = syn::parse_str(r#"RangeValidation(priority = "high")"#);
+    let err = result.err().unwrap().to_string();
+    assert!(
+        err.contains("priority must be an integer literal"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_validator_attr_parse_message() {
+    // `message` is reserved and must be a string literal
+    let attr: ValidatorAttr =
+        syn::parse_quote!(LenValidation(min = 1, message = "{value} is too short"));
+    assert_eq!(attr.args.len(), 1);
+    assert_eq!(attr.message.unwrap().value(), "{value} is too short");
+
+    let result: Result<ValidatorAttr, _> = syn::parse_str("LenValidation(message = too_short)");
+    let err = result.err().unwrap().to_string();
+    assert!(
+        err.contains("message must be a string literal"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_field_severity() {
+    let attr: KorumaAttr = syn::parse_quote!(
+        severity = "warning",
+        ValidatorA(x = 1),
+        ValidatorB(severity = "error")
+    );
+    assert_eq!(attr.severity, Some(Severity::Warning));
+    assert_eq!(attr.field_validators.len(), 2);
+    assert_eq!(attr.field_validators[1].severity, Some(Severity::Error));
+}
+
+#[test]
+fn test_field_severity_overridden_per_validator() {
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(severity = "warning", ValidatorA(x = 1), ValidatorB(severity = "error"))]
+        pub value: i32
+    };
+    let info = parse_field(&field, 0).valid().expect("field should parse");
+    let validators = &info.validation.field_validators;
+    assert_eq!(info.severity_of(&validators[0]), Severity::Warning);
+    assert_eq!(info.severity_of(&validators[1]), Severity::Error);
+}
+
+#[test]
+fn test_koruma_attr_parse_entries() {
+    let attr: KorumaAttr = syn::parse_quote!(LenValidation(max = 5), entries(PairValidation));
+    assert_eq!(attr.field_validators.len(), 1);
+    assert_eq!(attr.element_validators.len(), 1);
+    assert!(attr.entries);
+
+    let each: KorumaAttr = syn::parse_quote!(each(ItemValidation));
+    assert!(!each.entries);
+}
+
+#[test]
+fn test_koruma_attr_parse_each_and_entries_error() {
+    let result: syn::Result<KorumaAttr> =
+        syn::parse2(quote::quote!(each(ItemValidation), entries(PairValidation)));
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("can't be combined"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_normalize() {
+    let attr: KorumaAttr = syn::parse_quote!(
+        LenValidation(max = 254),
+        normalize = str::make_ascii_lowercase
+    );
+    assert_eq!(attr.field_validators.len(), 1);
+    assert!(attr.normalize.is_some());
+
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(normalize = str::make_ascii_lowercase)]
+        pub email: String
+    };
+    let err = parse_field(&field, 0)
+        .error()
+        .expect("field should not parse");
+    assert!(
+        err.to_string().contains("needs at least one validator"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_required() {
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(required, LenValidation::<_>(min = 1, max = 50))]
+        pub name: Option<String>
+    };
+    let info = parse_field(&field, 0).valid().expect("field should parse");
+    let names: Vec<String> = info
+        .validation
+        .field_validators
+        .iter()
+        .map(|v| v.name().to_string())
+        .collect();
+    assert_eq!(names, vec!["RequiredValidation", "LenValidation"]);
+    assert!(info.validation.field_validators[0].explicit_type.is_some());
+
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(required, LenValidation::<_>(min = 1, max = 50))]
+        pub name: String
+    };
+    let err = parse_field(&field, 0)
+        .error()
+        .expect("field should not parse");
+    assert!(
+        err.to_string().contains("only applies to `Option` fields"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_rename() {
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(rename = "zipCode", LenValidation::<_>(min = 5, max = 5))]
+        pub zip_code: String
+    };
+    let info = parse_field(&field, 0).valid().expect("field should parse");
+    assert_eq!(info.validation.field_validators.len(), 1);
+    assert_eq!(info.name.to_string(), "zip_code");
+    assert_eq!(info.external_name(), "zipCode");
+
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(rename = "zipCode")]
+        pub zip_code: String
+    };
+    let err = parse_field(&field, 0)
+        .error()
+        .expect("field should not parse");
+    assert!(
+        err.to_string().contains("needs at least one validator"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_async() {
+    let attr: KorumaAttr = syn::parse_quote!(async, UniqueUsernameValidation);
+    assert_eq!(attr.field_validators.len(), 1);
+    assert!(attr.is_async);
+
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(async)]
+        pub username: String
+    };
+    let err = parse_field(&field, 0)
+        .error()
+        .expect("field should not parse");
+    assert!(
+        err.to_string().contains("needs at least one validator"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_attr_parse_when() {
+    let attr: KorumaAttr = syn::parse_quote!(
+        when = |this: &Self| this.country == "US",
+        LenValidation(min = 9, max = 9)
+    );
+    assert_eq!(attr.field_validators.len(), 1);
+    assert!(attr.when.is_some());
+
+    let field: syn::Field = syn::parse_quote! {
+        #[koruma(when = |this: &Self| this.country == "US")]
+        pub tax_id: String
+    };
+    let err = parse_field(&field, 0)
+        .error()
+        .expect("field should not parse");
+    assert!(
+        err.to_string().contains("needs at least one validator"),
+        "got: {}",
+        err
+    );
+}
@@ -13,7 +13,7 @@ fn test_validator_error_missing_value_field() {
         }
     };
 
-    let result = expand_validator(input);
+    let result = expand_validator(ValidatorArgs::default(), input);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(err.to_string().contains("koruma(value)"));
@@ -108,3 +108,286 @@ fn test_koruma_error_on_duplicate_element_validator() {
         err
     );
 }
+
+#[test]
+fn test_koruma_error_on_shared_config_duplicating_validator() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(shared(percent = RangeValidation(min = 0, max = 100)))]
+        pub struct SharedDuplicate {
+            #[koruma(percent, RangeValidation(min = 10, max = 50))]
+            pub value: i32,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("duplicate validator"),
+        "expected 'duplicate validator' error, got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_on_shared_config_with_args() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(shared(percent = RangeValidation(min = 0, max = 100)))]
+        pub struct SharedWithArgs {
+            #[koruma(percent(max = 50))]
+            pub value: i32,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("doesn't take arguments"),
+        "expected 'doesn't take arguments' error, got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_validator_error_non_option_index_field() {
+    let input: ItemStruct = syn::parse_quote! {
+        pub struct BadIndex {
+            #[koruma(value)]
+            pub actual: i32,
+            #[koruma(index)]
+            pub index: usize,
+        }
+    };
+
+    let result = expand_validator(ValidatorArgs::default(), input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("Option<usize>"));
+}
+
+#[test]
+fn test_validator_error_unknown_argument() {
+    let err = syn::parse_str::<ValidatorArgs>("deferred_value, lazy").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown koruma::validator argument `lazy`. Expected `deferred_value`"
+    );
+}
+
+#[test]
+fn test_koruma_error_context_field_with_validators() {
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Comment {
+            #[koruma(context)]
+            #[koruma(RangeValidation(min = 0, max = 10))]
+            pub max_len: usize,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("`context` fields can't have validators"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_entries_on_non_map() {
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Config {
+            #[koruma(entries(DistinctEntryValidation))]
+            pub vars: Vec<String>,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("requires a `HashMap` or `BTreeMap`"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_each_infers_on_non_vec() {
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Party {
+            #[koruma(each(LenValidation::<_>(min = 1, max = 12)))]
+            pub playlist: Playlist,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("can only infer the element type of a `Vec<T>`"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_patch_on_tuple_struct() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(patch)]
+        pub struct Score(#[koruma(RangeValidation::<_>(min = 0, max = 10))] u8);
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("`patch` requires a struct with named fields"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_builder_on_tuple_struct() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(builder)]
+        pub struct Score(#[koruma(RangeValidation::<_>(min = 0, max = 10))] u8);
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("`builder` requires a struct with named fields"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_invalid_visibility() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(visibility = "crate-only")]
+        pub(crate) struct Score {
+            #[koruma(RangeValidation::<_>(min = 0, max = 10))]
+            pub value: u8,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("invalid visibility `crate-only`"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_visibility_narrower_than_pub_struct() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(visibility = "pub(crate)")]
+        pub struct Score {
+            #[koruma(RangeValidation::<_>(min = 0, max = 10))]
+            pub value: u8,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("`visibility` can't restrict it"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_validator_error_field_name_not_option() {
+    let input: ItemStruct = syn::parse_quote! {
+        pub struct NonEmptyValidation {
+            #[koruma(value)]
+            pub actual: String,
+            pub field_name: &'static str,
+        }
+    };
+
+    let result = expand_validator(ValidatorArgs::default(), input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("Option<&'static str>"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_normalize_without_struct_option() {
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 3, max = 254), normalize = str::make_ascii_lowercase)]
+            pub email: String,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("`#[koruma(normalize)]`"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_koruma_error_async_with_try_new() {
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(try_new)]
+        pub struct Signup {
+            #[koruma(async, UniqueUsernameValidation)]
+            pub username: String,
+        }
+    };
+
+    let result = expand_koruma(input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("`try_new` needs synchronous validation"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_validator_error_value_with_closure_untyped() {
+    let input: ItemStruct = syn::parse_quote! {
+        pub struct ExcerptValidation {
+            #[koruma(value)]
+            #[builder(with = |value| value)]
+            pub actual: String,
+        }
+    };
+
+    let result = expand_validator(ValidatorArgs::default(), input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string().contains("one typed argument"),
+        "got: {}",
+        err
+    );
+}
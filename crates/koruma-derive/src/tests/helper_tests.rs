@@ -81,6 +81,39 @@ fn test_vec_inner_type_returns_none_for_non_vec() {
     assert!(vec_inner_type(&ty).is_none());
 }
 
+#[test]
+fn test_sequence_inner_type_extracts_inner() {
+    for ty in [
+        syn::parse_quote!(Vec<u8>),
+        syn::parse_quote!(VecDeque<u8>),
+        syn::parse_quote!(std::collections::VecDeque<u8>),
+        syn::parse_quote!([u8; 4]),
+        syn::parse_quote!([u8]),
+        syn::parse_quote!(&'a [u8]),
+    ] {
+        let ty: syn::Type = ty;
+        let inner = sequence_inner_type(&ty);
+        assert_eq!(
+            quote!(#inner).to_string(),
+            "u8",
+            "Expected u8 for {}",
+            quote!(#ty)
+        );
+    }
+}
+
+#[test]
+fn test_sequence_inner_type_returns_none_for_non_sequence() {
+    let ty: syn::Type = syn::parse_quote!(i32);
+    assert!(sequence_inner_type(&ty).is_none());
+
+    let ty: syn::Type = syn::parse_quote!(&'a str);
+    assert!(sequence_inner_type(&ty).is_none());
+
+    let ty: syn::Type = syn::parse_quote!(HashMap<String, i32>);
+    assert!(sequence_inner_type(&ty).is_none());
+}
+
 #[test]
 fn test_find_value_field_finds_marked_field() {
     let input: ItemStruct = syn::parse_quote! {
@@ -26,7 +26,7 @@ fn test_validator_expansion_simple() {
         }
     };
 
-    let expanded = expand_validator(input).unwrap();
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
     assert_snapshot!(pretty_print(expanded));
 }
 
@@ -42,7 +42,7 @@ fn test_validator_expansion_generic() {
         }
     };
 
-    let expanded = expand_validator(input).unwrap();
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
     assert_snapshot!(pretty_print(expanded));
 }
 
@@ -126,7 +126,7 @@ fn test_validator_expansion_non_option_value() {
         }
     };
 
-    let expanded = expand_validator(input).unwrap();
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
     assert_snapshot!(pretty_print(expanded));
 }
 
@@ -254,3 +254,466 @@ fn test_koruma_expansion_try_new() {
     let expanded = expand_koruma(input).unwrap();
     assert_snapshot!(pretty_print(expanded));
 }
+
+#[test]
+fn test_koruma_expansion_try_new_context() {
+    // Context fields are skipped by validation; computed ones aren't try_new params
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(try_new)]
+        pub struct Comment {
+            pub premium: bool,
+            #[koruma(context = limits::max_len(premium))]
+            pub max_len: usize,
+            #[koruma(context)]
+            pub min_len: usize,
+            #[koruma(LenValidation::<_>(min = min_len, max = max_len))]
+            pub body: String,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_compat_validator() {
+    // Struct with #[koruma(compat_validator)] implements validator::Validate
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(compat_validator)]
+        pub struct Signup {
+            #[koruma(RangeValidation(min = 18, max = 150))]
+            pub age: i32,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_severity() {
+    // Field-level warning severity with a per-validator error override
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Profile {
+            #[koruma(
+                severity = "warning",
+                RangeValidation(min = 0, max = 150, severity = "error"),
+                EvenValidation
+            )]
+            pub age: i32,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_shared_config() {
+    // Non-generic shared configs bind their args once; generic ones resolve per field
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(shared(
+            name_len = StringLengthValidation(min = 1, max = 50),
+            bounded = RangeValidation::<_>(min = 0, max = 10),
+        ))]
+        pub struct Person {
+            #[koruma(name_len)]
+            pub first_name: String,
+            #[koruma(name_len)]
+            pub last_name: String,
+            #[koruma(bounded)]
+            pub score: u8,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_literal_bounds() {
+    // Literal min/max pairs get a const assertion; runtime bounds don't
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Reading {
+            #[koruma(RangeValidation(min = 100, max = 0))]
+            pub inverted: i32,
+            #[koruma(each(RangeValidation::<_>(min = -1.5, max = 2.5)))]
+            pub samples: Vec<f64>,
+            #[koruma(RangeValidation(min = 0, max = limits::MAX))]
+            pub runtime: i32,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_help() {
+    // Literal and fluent help attached to field and element failures
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 3, help = "at least 3 characters"), EvenValidation)]
+            pub name: String,
+            #[koruma(each(RangeValidation(min = 0, max = 10, help = fluent("score-help"))))]
+            pub scores: Vec<i32>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_priority() {
+    // Priorities attached to field and element failures
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 3, priority = 5), EvenValidation)]
+            pub name: String,
+            #[koruma(each(RangeValidation(min = 0, max = 10, priority = -1)))]
+            pub scores: Vec<i32>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_redact() {
+    // Field-level and validator-level redaction of the stored value
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Credentials {
+            #[koruma(redact, LenValidation::<_>(min = 8))]
+            pub password: String,
+            #[koruma(RangeValidation(min = 0, max = 9999, redact), EvenValidation)]
+            pub pin: i32,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_validator_expansion_index_field() {
+    // #[koruma(index)] is skipped by the builder and implements ElementIndex
+    let input: ItemStruct = syn::parse_quote! {
+        #[derive(Clone, Debug)]
+        pub struct LenValidation<T: HasLen> {
+            pub max: usize,
+            #[koruma(value)]
+            pub actual: T,
+            #[koruma(index)]
+            pub index: Option<usize>,
+        }
+    };
+
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_entries() {
+    // Map entries validated as (key, value) pairs, errors keyed by map key
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Config {
+            #[koruma(LenValidation::<_>(max = 10), entries(DistinctEntryValidation))]
+            pub vars: HashMap<String, String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_each_map() {
+    // `each` on a map validates its values, errors keyed by map key
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Inventory {
+            #[koruma(each(RangeValidation::<_>(min = 0, max = 100)))]
+            pub stock: BTreeMap<String, Option<i32>>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_patch() {
+    // Patch struct validating only set fields; `max = limit` reads a field, so it's skipped
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(patch)]
+        pub struct Profile {
+            #[koruma(RangeValidation::<_>(min = 0, max = 100))]
+            pub age: i32,
+
+            #[koruma(LenValidation::<_>(max = limit))]
+            pub name: String,
+
+            pub limit: usize,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_builder() {
+    // bon builder whose `build_validated()` constructs and validates
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(builder)]
+        pub struct Profile {
+            #[koruma(RangeValidation::<_>(min = 0, max = 100))]
+            pub age: i32,
+
+            #[koruma(LenValidation::<_>(min = 1, max = 10))]
+            pub nickname: Option<String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_visibility() {
+    // Generated error structs and methods are crate-private
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(visibility = "pub(crate)")]
+        pub(crate) struct Profile {
+            #[koruma(RangeValidation::<_>(min = 0, max = 100))]
+            pub age: i32,
+
+            #[koruma(each(LenValidation::<_>(min = 1, max = 10)))]
+            pub tags: Vec<String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_typed_errors() {
+    // Getters of fields with only field-level validators return `Result`
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(typed_errors)]
+        pub struct Profile {
+            #[koruma(RangeValidation::<_>(min = 0, max = 100), EvenValidation::<_>)]
+            pub age: i32,
+
+            #[koruma(each(LenValidation::<_>(min = 1, max = 10)))]
+            pub tags: Vec<String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_normalize() {
+    // `validate_and_normalize` applies the fields' normalizers after validating
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(normalize)]
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 3, max = 254), normalize = str::make_ascii_lowercase)]
+            pub email: String,
+
+            #[koruma(RangeValidation::<_>(min = 0, max = 150))]
+            pub age: u8,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_validated_wrapper() {
+    // `into_validated` consumes the struct and wraps it once it validates
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(validated_wrapper)]
+        pub struct Account {
+            #[koruma(LenValidation::<_>(min = 3, max = 20))]
+            pub username: String,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_cross_field() {
+    // Cross-field checks run after the field validators and fill a keyed map
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(cross_field(|this: &Self| {
+            if this.password == this.confirm_password {
+                Ok(())
+            } else {
+                Err(koruma::KeyedError::new("passwords_match", "passwords don't match"))
+            }
+        }))]
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 8, max = 64))]
+            pub password: String,
+
+            pub confirm_password: String,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_koruma_serialize_expansion() {
+    // Element failures go in an `elements` array and cross-field ones under `cross_field`
+    let input: DeriveInput = syn::parse_quote! {
+        #[koruma(cross_field(|this: &Self| {
+            if this.password == this.confirm_password {
+                Ok(())
+            } else {
+                Err(koruma::KeyedError::new("passwords_match", "passwords don't match"))
+            }
+        }))]
+        pub struct Signup {
+            #[koruma(LenValidation::<_>(min = 8, max = 64, message = "too short"))]
+            pub password: String,
+
+            pub confirm_password: String,
+
+            #[koruma(each(LenValidation::<_>(min = 1, max = 10)))]
+            pub tags: Vec<String>,
+
+            #[koruma(nested)]
+            pub address: Address,
+        }
+    };
+
+    let expanded = expand_koruma_serialize(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_when() {
+    // `when` wraps the field's checks in the predicate
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Address {
+            #[koruma(LenValidation::<_>(min = 2, max = 2))]
+            pub country: String,
+
+            #[koruma(when = |this: &Self| this.country == "US", LenValidation::<_>(min = 9, max = 9))]
+            pub tax_id: Option<String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_required() {
+    // `required` runs `RequiredValidation` on the whole `Option`, and the
+    // other validators on the inner value
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Profile {
+            #[koruma(required, LenValidation::<_>(min = 1, max = 50))]
+            pub name: Option<String>,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_koruma_expansion_async() {
+    // `async` fields await `AsyncValidate` validators, making the validation
+    // methods async and dropping the `ValidateExt` impl
+    let input: DeriveInput = syn::parse_quote! {
+        pub struct Signup {
+            #[koruma(async, UniqueUsernameValidation)]
+            pub username: String,
+
+            #[koruma(LenValidation::<_>(min = 8, max = 64))]
+            pub password: String,
+        }
+    };
+
+    let expanded = expand_koruma(input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_validator_expansion_pair_value() {
+    // A tuple value also gets a two-argument `with_values`
+    let input: ItemStruct = syn::parse_quote! {
+        #[derive(Clone, Debug)]
+        pub struct DistinctEntryValidation {
+            #[koruma(value)]
+            pub entry: (String, String),
+        }
+    };
+
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_validator_expansion_field_name() {
+    // A `field_name` field is skipped by the builder and set through `HasFieldName`
+    let input: ItemStruct = syn::parse_quote! {
+        #[derive(Clone, Debug)]
+        pub struct NonEmptyValidation {
+            #[koruma(value)]
+            pub actual: String,
+            pub field_name: Option<&'static str>,
+        }
+    };
+
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_validator_expansion_deferred_value() {
+    // `deferred_value` implements `DeferredValue`, so the derive clones only on failure
+    let input: ItemStruct = syn::parse_quote! {
+        #[derive(Clone, Debug)]
+        pub struct MaxLenValidation<T: AsRef<str>> {
+            pub max: usize,
+            #[koruma(value)]
+            pub actual: T,
+        }
+    };
+
+    let args: ValidatorArgs = syn::parse_quote! { deferred_value };
+    let expanded = expand_validator(args, input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
+
+#[test]
+fn test_validator_expansion_value_with_closure() {
+    // A `with` closure on the value field sets what `with_value` takes
+    let input: ItemStruct = syn::parse_quote! {
+        #[derive(Clone, Debug)]
+        pub struct ExcerptValidation<T: AsRef<str>> {
+            #[koruma(value)]
+            #[builder(with = |value: T| value.as_ref().chars().take(8).collect())]
+            pub actual: String,
+            #[builder(skip)]
+            _value: std::marker::PhantomData<fn(T)>,
+        }
+    };
+
+    let expanded = expand_validator(ValidatorArgs::default(), input).unwrap();
+    assert_snapshot!(pretty_print(expanded));
+}
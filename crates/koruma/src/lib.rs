@@ -9,7 +9,11 @@ pub mod bon {
 }
 
 pub use koruma_core::{
-    BuilderWithValue, NewtypeValidation, Validate, ValidateExt, ValidationError,
+    AllOfValidation, AnyOfValidation, AsyncValidate, BoxedValidator, BuilderWithValue, Cached,
+    DeferredValue, ElementIndex, Failure, FieldPath, HasFieldName, Help, KeyedError, LineFailure,
+    LocalizedMessages, LogEntry, NewtypeValidation, NotValidation, ObserveValue, PathSegment,
+    Redact, Severity, StepResult, StoredValue, StreamValidation, Validate, ValidateAll,
+    ValidateExt, Validated, ValidationError, ValidationLog, ValidationMessages, validate_each,
 };
 
 #[cfg(feature = "derive")]
@@ -18,9 +22,21 @@ pub use koruma_derive::{Koruma, KorumaAllDisplay, validator};
 #[cfg(all(feature = "derive", feature = "fluent"))]
 pub use koruma_derive::KorumaAllFluent;
 
+#[cfg(feature = "fluent")]
+pub use koruma_core::{LocalizerTaken, ToFluentStringIn, with_locale};
+
+#[cfg(feature = "miette")]
+pub use koruma_core::{FieldDiagnostic, ValidationDiagnostic};
+
 #[cfg(feature = "showcase")]
 pub use koruma_core::showcase;
 
+#[cfg(feature = "validator-compat")]
+pub use koruma_core::validator_compat;
+
+#[doc(hidden)]
+pub use koruma_core::__private;
+
 #[cfg(feature = "showcase")]
 #[doc(hidden)]
 pub use inventory;
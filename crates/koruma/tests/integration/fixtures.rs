@@ -1,8 +1,15 @@
-use koruma::{Koruma, Validate};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use koruma::{
+    AllOfValidation, AnyOfValidation, BoxedValidator, KeyedError, Koruma, KorumaAllDisplay,
+    NotValidation,
+};
 
 use super::validators::{
-    EvenNumberValidation, GenericRangeValidation, NumberRangeValidation, StringLengthValidation,
-    VecLenValidation,
+    CountedText, CountedTextLenValidation, DistinctCharsValidation, DistinctEntryValidation,
+    EvenNumberValidation, GenericRangeValidation, NamedNonEmptyValidation, NumberRangeValidation,
+    StringLengthValidation, UniqueUsernameValidation, VecLenValidation,
 };
 
 /// Example struct demonstrating validation with non-generic validators.
@@ -85,7 +92,7 @@ pub struct OrderWithLenCheck {
 
 /// Example struct demonstrating nested validation.
 /// Address is a nested struct that also derives Koruma.
-#[derive(Clone, Koruma)]
+#[derive(Clone, Koruma, KorumaAllDisplay)]
 pub struct Address {
     #[koruma(StringLengthValidation(min = 1, max = 100))]
     pub street: String,
@@ -168,3 +175,494 @@ pub struct ContainsNewtype {
     #[koruma(newtype)]
     pub number: PositiveNumber,
 }
+
+/// Example struct implementing `validator::Validate` for framework interop.
+#[derive(Koruma)]
+#[koruma(compat_validator)]
+pub struct CompatSignup {
+    #[koruma(StringLengthValidation(min = 1, max = 50))]
+    pub username: String,
+
+    #[koruma(nested)]
+    pub address: Address,
+}
+
+/// Example struct whose cross-field check is reported to the `validator` crate.
+#[derive(Koruma)]
+#[koruma(compat_validator)]
+#[koruma(cross_field(|this: &Self| {
+    if this.password == this.confirm_password {
+        Ok(())
+    } else {
+        Err(KeyedError::new("passwords_match", "passwords don't match"))
+    }
+}))]
+pub struct CompatPasswordChange {
+    #[koruma(StringLengthValidation(min = 8, max = 64))]
+    pub password: String,
+
+    pub confirm_password: String,
+}
+
+/// Example struct demonstrating warning-severity validators.
+/// Warnings are reported by `validation_report()` but don't fail `validate()`.
+#[derive(Koruma)]
+pub struct SeverityProfile {
+    // Every validator on this field is a warning
+    #[koruma(severity = "warning", StringLengthValidation(min = 3, max = 20))]
+    pub nickname: String,
+
+    // The range check overrides the field-level warning default
+    #[koruma(
+        severity = "warning",
+        NumberRangeValidation(min = 0, max = 120, severity = "error"),
+        EvenNumberValidation
+    )]
+    pub age: i32,
+}
+
+/// Example struct mixing warnings and errors, with a message per failure.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct BannerProfile {
+    #[koruma(severity = "warning", StringLengthValidation(min = 3, max = 20))]
+    pub nickname: String,
+
+    #[koruma(StringLengthValidation(min = 1, max = 10))]
+    pub handle: String,
+}
+
+/// Example struct nesting a struct that has warning-severity validators.
+#[derive(Koruma)]
+pub struct SeverityAccount {
+    #[koruma(nested)]
+    pub profile: SeverityProfile,
+}
+
+/// Number of times the `name_len` shared config's `max` has been evaluated.
+pub static SHARED_NAME_MAX_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn shared_name_max() -> usize {
+    SHARED_NAME_MAX_EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+    20
+}
+
+/// Example struct reusing named validator configs across fields.
+#[derive(Koruma)]
+#[koruma(shared(
+    name_len = StringLengthValidation(min = 1, max = shared_name_max()),
+    score_range = GenericRangeValidation::<_>(min = 0, max = 10),
+))]
+pub struct SharedConfigPerson {
+    #[koruma(name_len)]
+    pub first_name: String,
+
+    #[koruma(name_len)]
+    pub last_name: String,
+
+    // A reference can still override the config's severity
+    #[koruma(name_len(severity = "warning"))]
+    pub nickname: String,
+
+    // Generic configs resolve their type per field
+    #[koruma(score_range)]
+    pub score: u8,
+
+    #[koruma(score_range)]
+    pub rating: i64,
+}
+
+/// Example struct storing redacted copies of sensitive values in its errors.
+#[derive(Koruma)]
+pub struct RedactedCredentials {
+    #[koruma(redact, StringLengthValidation(min = 8, max = 64))]
+    pub password: String,
+
+    // Only the range check redacts; the parity check keeps the raw value
+    #[koruma(
+        NumberRangeValidation(min = 0, max = 9999, redact),
+        EvenNumberValidation
+    )]
+    pub pin: i32,
+
+    #[koruma(each(StringLengthValidation(min = 4, max = 32, redact)))]
+    pub recovery_codes: Vec<String>,
+}
+
+/// Example struct whose redacted validators compute details from the real value.
+#[derive(Koruma)]
+pub struct RedactedPassphrase {
+    #[koruma(redact, DistinctCharsValidation(min = 6))]
+    pub passphrase: String,
+
+    #[koruma(each(DistinctCharsValidation(min = 3, redact)))]
+    pub hints: Vec<String>,
+}
+
+/// Example struct attaching help to its validators' failures.
+#[derive(Koruma)]
+pub struct HelpfulSignup {
+    #[koruma(StringLengthValidation(
+        min = 3,
+        max = 20,
+        help = "pick a name between 3 and 20 characters"
+    ))]
+    pub username: String,
+
+    // Fluent message ids are reported as-is, for the application to localize
+    #[koruma(
+        NumberRangeValidation(min = 13, max = 130, help = fluent("signup-age-help")),
+        EvenNumberValidation
+    )]
+    pub age: i32,
+
+    #[koruma(each(StringLengthValidation(min = 1, max = 10, help = "keep tags short")))]
+    pub tags: Vec<String>,
+}
+
+/// Runtime limits looked up by `Comment::try_new`.
+pub fn max_comment_len(premium: bool) -> usize {
+    if premium { 500 } else { 20 }
+}
+
+/// Example struct whose validator bound comes from a context-only field.
+#[derive(Debug, Koruma)]
+#[koruma(try_new)]
+pub struct Comment {
+    pub premium: bool,
+
+    // Computed by `try_new` from the `premium` parameter
+    #[koruma(context = max_comment_len(premium))]
+    pub max_len: usize,
+
+    #[koruma(StringLengthValidation(min = 1, max = max_len))]
+    pub body: String,
+}
+
+/// Example struct whose context-only field is passed to `try_new`.
+#[derive(Debug, Koruma)]
+#[koruma(try_new)]
+pub struct BoundedScore {
+    #[koruma(context)]
+    pub limit: i32,
+
+    #[koruma(GenericRangeValidation::<_>(min = 0, max = limit))]
+    pub score: i32,
+}
+
+/// Example struct rendering a message for every failure.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct MessageSignup {
+    #[koruma(StringLengthValidation(min = 3, max = 20))]
+    pub username: String,
+
+    #[koruma(each(StringLengthValidation(min = 1, max = 10)))]
+    pub tags: Vec<String>,
+
+    #[koruma(nested)]
+    pub address: Address,
+}
+
+/// Example struct reporting its fields under their API names.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct ApiSignup {
+    #[koruma(rename = "zipCode", StringLengthValidation(min = 5, max = 5))]
+    pub zip_code: String,
+
+    #[koruma(nested)]
+    #[koruma(rename = "billingAddress")]
+    pub billing_address: Address,
+}
+
+/// Number of validators of [`SteppedForm`] that have been built.
+pub static STEPPED_FORM_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn stepped_form_max() -> usize {
+    STEPPED_FORM_EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+    20
+}
+
+/// Example struct counting how many of its checks ran, for stepping through
+/// validation.
+#[derive(Koruma)]
+pub struct SteppedForm {
+    #[koruma(StringLengthValidation(min = 1, max = stepped_form_max()))]
+    pub first_name: String,
+
+    #[koruma(StringLengthValidation(min = 1, max = stepped_form_max()))]
+    pub last_name: String,
+
+    #[koruma(NumberRangeValidation(min = 0, max = 150), EvenNumberValidation)]
+    pub age: i32,
+
+    #[koruma(nested)]
+    pub address: Address,
+}
+
+/// Example struct replacing validator messages with its own.
+#[derive(Koruma, KorumaAllDisplay)]
+#[koruma(cross_field(|this: &Self| {
+    if this.tags.len() <= 3 {
+        Ok(())
+    } else {
+        Err(KeyedError::new("too_many_tags", "at most 3 tags are allowed"))
+    }
+}))]
+pub struct CustomMessageSignup {
+    #[koruma(StringLengthValidation(min = 1, max = 50, message = "Name is required"))]
+    pub name: String,
+
+    #[koruma(NumberRangeValidation(min = 0, max = 150, message = "{value} is not a valid age"))]
+    pub age: i32,
+
+    #[koruma(each(StringLengthValidation(
+        min = 1,
+        max = 10,
+        message = "tag `{value}` is too long"
+    )))]
+    pub tags: Vec<String>,
+}
+
+/// Example struct whose `{value}` has no `Display`, so it shows as `Debug`.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct CustomMessageBasket {
+    #[koruma(VecLenValidation::<i32>(min = 1, max = 2, message = "{value} has too many items"))]
+    pub items: Vec<i32>,
+}
+
+/// Example struct validating every `(key, value)` entry of a map.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct EnvConfig {
+    #[koruma(entries(DistinctEntryValidation))]
+    pub vars: HashMap<String, String>,
+}
+
+/// Example struct validating every value of a map with `each(...)`.
+#[derive(Koruma)]
+pub struct Inventory {
+    #[koruma(each(NumberRangeValidation(min = 0, max = 100)))]
+    pub stock: HashMap<String, i32>,
+}
+
+/// Example struct validating the elements of an array and a `VecDeque`.
+#[derive(Koruma)]
+pub struct Playlist {
+    #[koruma(each(NumberRangeValidation(min = 1, max = 5)))]
+    pub ratings: [i32; 3],
+
+    #[koruma(each(StringLengthValidation(min = 1, max = 10)))]
+    pub queue: VecDeque<String>,
+}
+
+/// Example struct mixing nested, map-entry, multi-validator and element
+/// fields, for checking the sorted failure order.
+#[derive(Koruma)]
+pub struct SortedSignup {
+    #[koruma(nested)]
+    pub address: Address,
+
+    #[koruma(entries(DistinctEntryValidation))]
+    pub labels: HashMap<String, String>,
+
+    #[koruma(StringLengthValidation(min = 3, max = 20), NamedNonEmptyValidation)]
+    pub username: String,
+
+    #[koruma(each(StringLengthValidation(min = 1, max = 10), NamedNonEmptyValidation))]
+    pub tags: Vec<String>,
+}
+
+/// Example struct whose validator names the field it failed on.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct NamedContact {
+    #[koruma(NamedNonEmptyValidation)]
+    pub first_name: String,
+
+    #[koruma(each(NamedNonEmptyValidation))]
+    pub phones: Vec<String>,
+}
+
+/// Example struct that also generates `ProfileUpdatePatch` for partial updates.
+#[derive(Koruma)]
+#[koruma(patch)]
+pub struct ProfileUpdate {
+    #[koruma(NumberRangeValidation(min = 0, max = 100))]
+    pub age: i32,
+
+    #[koruma(StringLengthValidation(min = 1, max = 67))]
+    pub name: String,
+}
+
+/// Example struct with a bon builder that validates in `build_validated()`.
+#[derive(Debug, Koruma)]
+#[koruma(builder)]
+pub struct BuiltItem {
+    #[koruma(NumberRangeValidation(min = 0, max = 100))]
+    pub age: i32,
+
+    #[koruma(StringLengthValidation(min = 1, max = 67))]
+    pub name: String,
+
+    #[koruma(StringLengthValidation(min = 1, max = 67))]
+    pub nickname: Option<String>,
+}
+
+/// Example struct whose generated validation API is crate-private.
+#[derive(Koruma)]
+#[koruma(visibility = "pub(crate)")]
+pub(crate) struct InternalItem {
+    #[koruma(NumberRangeValidation(min = 0, max = 100))]
+    pub age: i32,
+}
+
+/// Example struct whose field getters return the first failed validator.
+#[derive(Koruma)]
+#[koruma(typed_errors)]
+pub struct TypedItem {
+    #[koruma(NumberRangeValidation(min = 0, max = 100), EvenNumberValidation)]
+    pub value: i32,
+
+    // Element validators keep the per-field error struct getter
+    #[koruma(each(StringLengthValidation(min = 1, max = 10)))]
+    pub tags: Vec<String>,
+}
+
+/// Example struct normalizing its fields in place once it validates.
+#[derive(Koruma)]
+#[koruma(normalize)]
+pub struct NormalizedSignup {
+    #[koruma(StringLengthValidation(min = 3, max = 254), normalize = str::make_ascii_lowercase)]
+    pub email: String,
+
+    #[koruma(NumberRangeValidation(min = 0, max = 150))]
+    pub age: i32,
+}
+
+/// Example struct that can be wrapped in `koruma::Validated` once it passes.
+#[derive(Debug, Koruma)]
+#[koruma(validated_wrapper)]
+pub struct VerifiedAccount {
+    #[koruma(StringLengthValidation(min = 3, max = 20))]
+    pub username: String,
+
+    #[koruma(NumberRangeValidation(min = 0, max = 150))]
+    pub age: i32,
+}
+
+/// Example struct with a field that is only validated for some countries.
+#[derive(Koruma)]
+pub struct TaxedAddress {
+    #[koruma(StringLengthValidation(min = 2, max = 2))]
+    pub country: String,
+
+    #[koruma(when = |this: &Self| this.country == "US", StringLengthValidation(min = 9, max = 9))]
+    pub tax_id: Option<String>,
+}
+
+/// Example struct with checks spanning several fields.
+#[derive(Koruma)]
+#[koruma(cross_field(
+    |this: &Self| {
+        if this.password == this.confirm_password {
+            Ok(())
+        } else {
+            Err(KeyedError::new("passwords_match", "passwords don't match"))
+        }
+    },
+    |this: &Self| {
+        if this.end_day > this.start_day {
+            Ok(())
+        } else {
+            Err(KeyedError::new("end_after_start", "end day must be after start day"))
+        }
+    },
+))]
+pub struct Booking {
+    #[koruma(StringLengthValidation(min = 8, max = 64))]
+    pub password: String,
+
+    pub confirm_password: String,
+
+    #[koruma(NumberRangeValidation(min = 1, max = 365))]
+    pub start_day: i32,
+
+    pub end_day: i32,
+}
+
+/// Example struct mixing `async` fields, whose validators need IO, with a
+/// synchronous field.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct AsyncSignup {
+    #[koruma(async, UniqueUsernameValidation)]
+    pub username: String,
+
+    #[koruma(async, each(UniqueUsernameValidation))]
+    pub aliases: Vec<String>,
+
+    #[koruma(StringLengthValidation(min = 8, max = 64))]
+    pub password: String,
+}
+
+/// Checks that every [`ComposedScore::score`] must pass. Children only check
+/// values, so the values they are built with are placeholders.
+fn score_checks() -> Vec<BoxedValidator<i32>> {
+    vec![
+        Box::new(
+            NumberRangeValidation::builder()
+                .min(0)
+                .max(100)
+                .with_value(0)
+                .build(),
+        ),
+        Box::new(EvenNumberValidation::builder().with_value(0).build()),
+    ]
+}
+
+/// Alternative ways [`ComposedScore::bonus`] may pass.
+fn bonus_checks() -> Vec<BoxedValidator<i32>> {
+    vec![
+        Box::new(
+            NumberRangeValidation::builder()
+                .min(0)
+                .max(10)
+                .with_value(0)
+                .build(),
+        ),
+        Box::new(
+            NumberRangeValidation::builder()
+                .min(90)
+                .max(100)
+                .with_value(0)
+                .build(),
+        ),
+    ]
+}
+
+/// Example struct composing existing validators with `AllOfValidation` and
+/// `AnyOfValidation`.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct ComposedScore {
+    #[koruma(AllOfValidation::<_>(validators = score_checks()))]
+    pub score: i32,
+
+    #[koruma(AnyOfValidation::<_>(validators = bonus_checks()))]
+    pub bonus: i32,
+}
+
+/// Example struct inverting an existing validator with `NotValidation`.
+#[derive(Koruma, KorumaAllDisplay)]
+pub struct LongCode {
+    #[koruma(NotValidation::<StringLengthValidation>(
+        validator = StringLengthValidation::builder().min(0).max(3).with_value(String::new()).build()
+    ))]
+    pub code: String,
+}
+
+/// Example struct whose values count their clones, checked by a validator
+/// implementing `DeferredValue`.
+#[derive(Koruma)]
+pub struct Article {
+    #[koruma(CountedTextLenValidation(max = 10))]
+    pub title: CountedText,
+
+    #[koruma(each(CountedTextLenValidation(max = 5)))]
+    pub tags: Vec<CountedText>,
+}
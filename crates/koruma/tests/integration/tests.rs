@@ -1,13 +1,22 @@
 //! Test cases for koruma validation.
 
-use koruma::{Validate, ValidationError};
+use std::collections::{HashMap, VecDeque};
+
+use koruma::{Help, Severity, Validate, ValidationError, ValidationMessages};
 
 use super::fixtures::{
-    Address, AddressWrapper, Company, ContainsNewtype, Customer, CustomerWithOptionalAddress,
-    Employee, GenericItem, Item, MultiAttrItem, MultiValidatorItem, Order, OrderWithLenCheck,
-    PositiveNumber, UserProfile,
+    Address, AddressWrapper, ApiSignup, Article, AsyncSignup, BannerProfile, Booking, BoundedScore,
+    BuiltItem, Comment, Company, CompatPasswordChange, CompatSignup, ComposedScore,
+    ContainsNewtype, CustomMessageBasket, CustomMessageSignup, Customer,
+    CustomerWithOptionalAddress, Employee, EnvConfig, GenericItem, HelpfulSignup, InternalItem,
+    Inventory, Item, LongCode, MessageSignup, MultiAttrItem, MultiValidatorItem, NamedContact,
+    NormalizedSignup, Order, OrderWithLenCheck, Playlist, PositiveNumber, ProfileUpdate,
+    ProfileUpdatePatch, RedactedCredentials, RedactedPassphrase, SHARED_NAME_MAX_EVALUATIONS,
+    STEPPED_FORM_EVALUATIONS, SeverityAccount, SeverityProfile, SharedConfigPerson, SortedSignup,
+    SteppedForm, TaxedAddress, TypedItem, TypedItemValueKorumaValidator, UserProfile,
+    VerifiedAccount,
 };
-use super::validators::GenericRangeValidation;
+use super::validators::{COUNTED_TEXT_CLONES, CountedText, GenericRangeValidation};
 
 #[test]
 fn test_valid_item() {
@@ -641,6 +650,77 @@ fn test_deeply_nested_multiple_levels_invalid() {
     assert!(address_err.street().string_length_validation().is_some());
 }
 
+#[test]
+fn test_validate_each_slice() {
+    let address = |street: &str, zip_code: &str| Address {
+        street: street.to_string(),
+        city: "Springfield".to_string(),
+        zip_code: zip_code.to_string(),
+    };
+    let addresses = vec![
+        address("1 Main St", "12345"),
+        address("", "12345"),
+        address("2 Elm St", "12345"),
+        address("3 Oak St", "1"),
+    ];
+
+    let errors = koruma::validate_each(&addresses).unwrap_err();
+    let indices: Vec<usize> = errors.iter().map(|(idx, _)| *idx).collect();
+    assert_eq!(indices, vec![1, 3]);
+    assert!(errors[0].1.street().string_length_validation().is_some());
+    assert!(errors[1].1.zip_code().string_length_validation().is_some());
+
+    assert!(koruma::validate_each(&addresses[..1]).is_ok());
+    assert!(koruma::validate_each::<Address>(&[]).is_ok());
+}
+
+#[test]
+fn test_validate_all_on_vec() {
+    use koruma::ValidateAll;
+
+    let address = |street: &str| Address {
+        street: street.to_string(),
+        city: "Springfield".to_string(),
+        zip_code: "12345".to_string(),
+    };
+    let mut addresses = vec![address(""), address("1 Main St"), address("")];
+
+    let errors = addresses.validate_all().unwrap_err();
+    let indices: Vec<usize> = errors.iter().map(|(idx, _)| *idx).collect();
+    assert_eq!(indices, vec![0, 2]);
+    assert!(errors[1].1.street().string_length_validation().is_some());
+
+    addresses.retain(|address| !address.street.is_empty());
+    assert!(addresses.validate_all().is_ok());
+}
+
+#[test]
+fn test_validate_or_else_maps_error() {
+    use koruma::ValidateExt as _;
+
+    #[derive(Debug)]
+    enum AppError {
+        Validation(super::fixtures::AddressKorumaValidationError),
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn save(address: &Address) -> Result<(), AppError> {
+        address.validate_or_else(AppError::Validation)?;
+        Ok(())
+    }
+
+    let mut address = Address {
+        street: "1 Main St".to_string(),
+        city: "Springfield".to_string(),
+        zip_code: "12345".to_string(),
+    };
+    assert!(save(&address).is_ok());
+
+    address.zip_code = "1".to_string();
+    let AppError::Validation(err) = save(&address).unwrap_err();
+    assert!(err.zip_code().string_length_validation().is_some());
+}
+
 // ============================================================================
 // Newtype struct validation tests
 // ============================================================================
@@ -718,3 +798,1872 @@ fn test_field_level_newtype_invalid() {
     assert!(err.number().all().len() == 1);
     assert!(err.number().number_range_validation().is_some());
 }
+
+// ============================================================================
+// Failure iteration and structural comparison tests
+// ============================================================================
+
+#[test]
+fn test_iter_failures_reports_paths() {
+    let employee = Employee {
+        employee_name: "".to_string(),
+        employer: Company {
+            company_name: "Acme".to_string(),
+            headquarters: Address {
+                street: "".to_string(),
+                city: "Metropolis".to_string(),
+                zip_code: "11111".to_string(),
+            },
+        },
+    };
+    let err = employee.validate().unwrap_err();
+
+    let failures: Vec<String> = err
+        .iter_failures()
+        .map(|failure| format!("{}:{}", failure.path(), failure.validator()))
+        .collect();
+    assert_eq!(
+        failures,
+        vec![
+            "employee_name:string_length_validation",
+            "employer.headquarters.street:string_length_validation",
+        ]
+    );
+}
+
+#[test]
+fn test_iter_failures_reports_element_indices() {
+    let order = OrderWithLenCheck {
+        scores: vec![50.0, 150.0, -1.0],
+    };
+    let err = order.validate().unwrap_err();
+
+    let paths: Vec<String> = err
+        .iter_failures()
+        .map(|failure| failure.path().to_string())
+        .collect();
+    assert_eq!(paths, vec!["scores[1]", "scores[2]"]);
+}
+
+#[test]
+fn test_same_failures_ignores_values() {
+    let first = Item {
+        age: 150,
+        name: "".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+    let second = Item {
+        age: -5,
+        name: "".to_string(),
+        internal_id: 2,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert!(first.same_failures(&second));
+}
+
+#[test]
+fn test_same_failures_detects_different_fields() {
+    let age_only = Item {
+        age: 150,
+        name: "Alice".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+    let name_only = Item {
+        age: 30,
+        name: "".to_string(),
+        internal_id: 2,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert!(!age_only.same_failures(&name_only));
+}
+
+#[test]
+fn test_same_failures_compares_element_indices() {
+    let first = Order {
+        scores: vec![150.0, 50.0],
+    }
+    .validate()
+    .unwrap_err();
+    let same_index = Order {
+        scores: vec![-3.0, 10.0],
+    }
+    .validate()
+    .unwrap_err();
+    let other_index = Order {
+        scores: vec![10.0, 150.0],
+    }
+    .validate()
+    .unwrap_err();
+
+    assert!(first.same_failures(&same_index));
+    assert!(!first.same_failures(&other_index));
+}
+
+#[test]
+fn test_assert_field_valid_passes_for_valid_field() {
+    let err = Item {
+        age: 30,
+        name: "".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(err.field_names(), &["age", "name"]);
+    err.assert_field_valid("age");
+}
+
+#[test]
+#[should_panic(
+    expected = "expected field `age` to be valid, but it has 1 failure(s):\n  age: number_range_validation (error)"
+)]
+fn test_assert_field_valid_lists_failures() {
+    let err = Item {
+        age: 150,
+        name: "Alice".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+
+    err.assert_field_valid("age");
+}
+
+#[test]
+#[should_panic(expected = "unknown field `agee`, expected one of: age, name")]
+fn test_assert_field_valid_rejects_unknown_field() {
+    let err = Item {
+        age: 150,
+        name: "Alice".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+
+    err.assert_field_valid("agee");
+}
+
+// ============================================================================
+// validator crate compatibility tests
+// ============================================================================
+
+fn validate_with_validator_crate<T: koruma::validator_compat::Validate>(
+    value: &T,
+) -> Result<(), koruma::validator_compat::ValidationErrors> {
+    value.validate()
+}
+
+#[test]
+fn test_compat_validator_valid() {
+    let signup = CompatSignup {
+        username: "alice".to_string(),
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: "Springfield".to_string(),
+            zip_code: "12345".to_string(),
+        },
+    };
+
+    assert!(validate_with_validator_crate(&signup).is_ok());
+    // koruma's own validate() is still the inherent method
+    assert!(signup.validate().is_ok());
+}
+
+#[test]
+fn test_compat_validator_converts_failures() {
+    let signup = CompatSignup {
+        username: "".to_string(),
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: "".to_string(),
+            zip_code: "12345".to_string(),
+        },
+    };
+
+    let errors = validate_with_validator_crate(&signup).unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors.len(), 2);
+    assert_eq!(field_errors["username"][0].code, "string_length_validation");
+    assert_eq!(
+        field_errors["address.city"][0].code,
+        "string_length_validation"
+    );
+}
+
+#[test]
+fn test_compat_validator_converts_cross_field_failures() {
+    let change = CompatPasswordChange {
+        password: "correct horse".to_string(),
+        confirm_password: "correct hose".to_string(),
+    };
+
+    let errors = validate_with_validator_crate(&change).unwrap_err();
+    let field_errors = errors.field_errors();
+    assert_eq!(field_errors.len(), 1);
+    let all = &field_errors["__all__"];
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].code, "passwords_match");
+    assert_eq!(all[0].message.as_deref(), Some("passwords don't match"));
+}
+
+// ============================================================================
+// Taking field errors tests
+// ============================================================================
+
+#[test]
+fn test_take_field_empties_slot() {
+    let mut err = Item {
+        age: 150,
+        name: "".to_string(),
+        internal_id: 1,
+    }
+    .validate()
+    .unwrap_err();
+
+    let name_err = err.take_name();
+    assert!(name_err.string_length_validation().is_some());
+    assert!(err.name().is_empty());
+
+    // The remaining field still reports its error
+    assert!(err.age().number_range_validation().is_some());
+    assert!(err.has_errors());
+
+    err.take_age();
+    assert!(!err.has_errors());
+}
+
+#[test]
+fn test_take_nested_field_returns_option() {
+    let mut err = Customer {
+        name: "Alice".to_string(),
+        address: Address {
+            street: "".to_string(),
+            city: "Springfield".to_string(),
+            zip_code: "12345".to_string(),
+        },
+    }
+    .validate()
+    .unwrap_err();
+
+    let address_err = err.take_address().expect("should have address error");
+    assert!(address_err.street().string_length_validation().is_some());
+    assert!(err.address().is_none());
+    assert!(err.take_address().is_none());
+    assert!(!err.has_errors());
+}
+
+#[test]
+fn test_take_newtype_field() {
+    let mut err = ContainsNewtype {
+        name: "Test".to_string(),
+        number: PositiveNumber { value: -10 },
+    }
+    .validate()
+    .unwrap_err();
+
+    let number_err = err.take_number();
+    assert!(number_err.number_range_validation().is_some());
+    assert!(!err.has_errors());
+}
+
+// ============================================================================
+// Severity tests
+// ============================================================================
+
+#[test]
+fn test_field_level_warning_does_not_block_validate() {
+    let profile = SeverityProfile {
+        nickname: "Al".to_string(), // Too short, but only a warning
+        age: 30,
+    };
+    assert!(profile.validate().is_ok());
+
+    let report = profile.validation_report();
+    assert!(report.has_errors());
+    assert!(!report.has_blocking_errors());
+    assert!(report.nickname().string_length_validation().is_some());
+
+    let failures: Vec<_> = report.iter_failures().collect();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].severity(), Severity::Warning);
+}
+
+#[test]
+fn test_per_validator_severity_overrides_field_default() {
+    let odd_age = SeverityProfile {
+        nickname: "Alice".to_string(),
+        age: 31, // Odd: warning from the field-level default
+    };
+    assert!(odd_age.validate().is_ok());
+
+    let out_of_range = SeverityProfile {
+        nickname: "Alice".to_string(),
+        age: 130, // Out of range: error from the per-validator override
+    };
+    let err = out_of_range.validate().unwrap_err();
+    assert!(err.has_blocking_errors());
+    assert!(err.age().number_range_validation().is_some());
+}
+
+#[test]
+fn test_nested_warnings_are_reported() {
+    let account = SeverityAccount {
+        profile: SeverityProfile {
+            nickname: "Al".to_string(),
+            age: 30,
+        },
+    };
+    assert!(account.validate().is_ok());
+
+    let report = account.validation_report();
+    let failures: Vec<String> = report
+        .iter_failures()
+        .map(|failure| format!("{}:{}", failure.path(), failure.severity()))
+        .collect();
+    assert_eq!(failures, vec!["profile.nickname:warning"]);
+}
+
+#[test]
+fn test_validation_report_is_empty_when_valid() {
+    let profile = SeverityProfile {
+        nickname: "Alice".to_string(),
+        age: 30,
+    };
+    assert!(profile.validation_report().is_empty());
+}
+
+#[test]
+fn test_first_blocking_or_warning() {
+    let valid = BannerProfile {
+        nickname: "alice".to_string(),
+        handle: "alice".to_string(),
+    };
+    assert_eq!(valid.validation_report().first_blocking_or_warning(), None);
+
+    let warnings_only = BannerProfile {
+        nickname: "al".to_string(),
+        handle: "alice".to_string(),
+    };
+    assert_eq!(
+        warnings_only
+            .validation_report()
+            .first_blocking_or_warning(),
+        Some((
+            Severity::Warning,
+            "length must be between 3 and 20".to_string()
+        ))
+    );
+
+    // The error wins even though the warning comes first
+    let mixed = BannerProfile {
+        nickname: "al".to_string(),
+        handle: "a-very-long-handle".to_string(),
+    };
+    assert_eq!(
+        mixed.validation_report().first_blocking_or_warning(),
+        Some((
+            Severity::Error,
+            "length must be between 1 and 10".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_count_by_severity() {
+    // One blocking error (age out of range) and one warning (short nickname)
+    let profile = SeverityProfile {
+        nickname: "Al".to_string(),
+        age: 130,
+    };
+    assert_eq!(profile.validation_report().count_by_severity(), (1, 1));
+
+    // Odd age adds a second warning
+    let profile = SeverityProfile {
+        nickname: "Al".to_string(),
+        age: 131,
+    };
+    assert_eq!(profile.validation_report().count_by_severity(), (1, 2));
+}
+
+#[test]
+fn test_count_by_severity_includes_nested() {
+    let account = SeverityAccount {
+        profile: SeverityProfile {
+            nickname: "Al".to_string(),
+            age: 131,
+        },
+    };
+    assert_eq!(account.validation_report().count_by_severity(), (1, 2));
+}
+
+#[test]
+fn test_contains_validator_includes_nested_and_elements() {
+    let account = SeverityAccount {
+        profile: SeverityProfile {
+            nickname: "Alice".to_string(),
+            age: 131,
+        },
+    };
+    let report = account.validation_report();
+    assert!(report.contains_validator("number_range_validation"));
+    assert!(report.contains_validator("even_number_validation"));
+    assert!(!report.contains_validator("string_length_validation"));
+
+    let signup = HelpfulSignup {
+        username: "alice".to_string(),
+        age: 20,
+        tags: vec!["a-very-long-tag".to_string()],
+    };
+    let err = signup.validate().unwrap_err();
+    assert!(err.contains_validator("string_length_validation"));
+    assert!(!err.contains_validator("number_range_validation"));
+}
+
+// =============================================================================
+// Shared validator configs
+// =============================================================================
+
+#[test]
+fn test_shared_config_applies_to_every_field() {
+    let person = SharedConfigPerson {
+        first_name: String::new(),
+        last_name: "x".repeat(21),
+        nickname: String::new(),
+        score: 11,
+        rating: -1,
+    };
+
+    let before = SHARED_NAME_MAX_EVALUATIONS.load(std::sync::atomic::Ordering::SeqCst);
+    let err = person.validate().unwrap_err();
+    let after = SHARED_NAME_MAX_EVALUATIONS.load(std::sync::atomic::Ordering::SeqCst);
+
+    // The config is evaluated once, not once per referencing field
+    assert_eq!(after - before, 1);
+
+    assert!(err.first_name().string_length_validation().is_some());
+    assert!(err.last_name().string_length_validation().is_some());
+    assert!(err.nickname().string_length_validation().is_some());
+    assert_eq!(
+        err.score().generic_range_validation().unwrap().actual,
+        11_u8
+    );
+    assert_eq!(
+        err.rating().generic_range_validation().unwrap().actual,
+        -1_i64
+    );
+    assert_eq!(err.count_by_severity(), (4, 1));
+}
+
+// =============================================================================
+// Redaction
+// =============================================================================
+
+#[test]
+fn test_redacted_field_stores_masked_value() {
+    let credentials = RedactedCredentials {
+        password: "hunter2".to_string(),
+        pin: 12345,
+        recovery_codes: vec!["abcd".to_string(), "xyz".to_string()],
+    };
+
+    let err = credentials.validate().unwrap_err();
+
+    // The failure is still reported, but the raw value is masked
+    let password = err.password().string_length_validation().unwrap();
+    assert_eq!(password.input, "*******");
+    assert_eq!(password.input.chars().count(), "hunter2".chars().count());
+
+    let pin = err.pin();
+    assert_eq!(pin.number_range_validation().unwrap().actual, 0);
+    assert_eq!(pin.even_number_validation().unwrap().actual, 12345);
+
+    let element_errors = err.recovery_codes().element_errors();
+    assert_eq!(element_errors.len(), 1);
+    let (index, element_error) = &element_errors[0];
+    assert_eq!(*index, 1);
+    assert_eq!(
+        element_error.string_length_validation().unwrap().input,
+        "***"
+    );
+}
+
+#[test]
+fn test_redacted_field_still_validates_real_value() {
+    let credentials = RedactedCredentials {
+        password: "correct horse battery staple".to_string(),
+        pin: 1234,
+        recovery_codes: vec!["abcd".to_string()],
+    };
+
+    assert!(credentials.validate().is_ok());
+}
+
+#[test]
+fn test_redacted_validator_observes_real_value() {
+    let passphrase = RedactedPassphrase {
+        passphrase: "abcabc".to_string(),
+        hints: vec!["xyz".to_string(), "zzzz".to_string()],
+    };
+
+    let err = passphrase.validate().unwrap_err();
+
+    // Stored copies are masked, but the details describe the real values
+    let validator = err.passphrase().distinct_chars_validation().unwrap();
+    assert_eq!(validator.input, "******");
+    assert_eq!(validator.distinct, 3);
+
+    let element_errors = err.hints().element_errors();
+    assert_eq!(element_errors.len(), 1);
+    let (index, element_error) = &element_errors[0];
+    assert_eq!(*index, 1);
+    let validator = element_error.distinct_chars_validation().unwrap();
+    assert_eq!(validator.input, "****");
+    assert_eq!(validator.distinct, 1);
+}
+
+// =============================================================================
+// Element indices
+// =============================================================================
+
+#[test]
+fn test_element_failure_message_includes_index() {
+    let credentials = RedactedCredentials {
+        password: "correct horse battery staple".to_string(),
+        pin: 1234,
+        recovery_codes: vec!["abcd".to_string(), "efgh".to_string(), "ij".to_string()],
+    };
+
+    let err = credentials.validate().unwrap_err();
+    let (idx, element_error) = &err.recovery_codes().element_errors()[0];
+    let validator = element_error.string_length_validation().unwrap();
+
+    assert_eq!(*idx, 2);
+    assert_eq!(validator.index, Some(2));
+    assert_eq!(
+        validator.to_string(),
+        "item #2: length must be between 4 and 32"
+    );
+}
+
+#[test]
+fn test_field_level_validator_has_no_index() {
+    let item = Item {
+        age: 25,
+        name: String::new(),
+        internal_id: 1,
+    };
+
+    let err = item.validate().unwrap_err();
+    let validator = err.name().string_length_validation().unwrap();
+    assert_eq!(validator.index, None);
+    assert_eq!(validator.to_string(), "length must be between 1 and 67");
+}
+
+// =============================================================================
+// Failure messages
+// =============================================================================
+
+fn invalid_message_signup() -> MessageSignup {
+    MessageSignup {
+        username: "al".to_string(),
+        tags: vec!["rust".to_string(), "a-very-long-tag".to_string()],
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: String::new(),
+            zip_code: "12345".to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_failure_messages_follow_iter_failures() {
+    let err = invalid_message_signup().validate().unwrap_err();
+    let messages = err.failure_messages();
+
+    let failures: Vec<_> = messages
+        .iter()
+        .map(|(failure, _)| failure.clone())
+        .collect();
+    assert_eq!(failures, err.iter_failures().collect::<Vec<_>>());
+
+    let rendered: Vec<String> = messages
+        .iter()
+        .map(|(failure, message)| format!("{}: {}", failure.path(), message))
+        .collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "username: length must be between 3 and 20",
+            "tags[1]: item #1: length must be between 1 and 10",
+            "address.city: length must be between 1 and 50",
+        ]
+    );
+}
+
+#[test]
+fn test_message_overrides_validator_display() {
+    let err = CustomMessageSignup {
+        name: String::new(),
+        age: 200,
+        tags: vec!["rust".to_string(), "a-very-long-tag".to_string()],
+    }
+    .validate()
+    .unwrap_err();
+
+    let messages: Vec<String> = err
+        .failure_messages()
+        .into_iter()
+        .map(|(_, message)| message)
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Name is required",
+            "200 is not a valid age",
+            "tag `a-very-long-tag` is too long",
+        ]
+    );
+
+    // `all()` variants render the override too, while the validator keeps
+    // its own `Display`
+    assert_eq!(err.name().all()[0].to_string(), "Name is required");
+    let name_err = err.name().string_length_validation().unwrap();
+    assert_eq!(name_err.to_string(), "length must be between 1 and 50");
+}
+
+#[test]
+fn test_message_value_falls_back_to_debug() {
+    let err = CustomMessageBasket {
+        items: vec![1, 2, 3],
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "items: [1, 2, 3] has too many items");
+}
+
+#[test]
+fn test_rename_changes_paths_and_serialized_keys() {
+    let signup = ApiSignup {
+        zip_code: "123".to_string(),
+        billing_address: Address {
+            street: "123 Main St".to_string(),
+            city: String::new(),
+            zip_code: "12345".to_string(),
+        },
+    };
+    let err = signup.validate().unwrap_err();
+
+    // Getters keep the Rust field names
+    assert!(err.zip_code().string_length_validation().is_some());
+    assert!(err.billing_address().is_some());
+
+    let paths: Vec<String> = err
+        .all_messages()
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect();
+    assert_eq!(paths, vec!["zipCode", "billingAddress.city"]);
+    assert_eq!(err.field_names(), &["zipCode", "billingAddress"]);
+
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["zipCode"]["string_length_validation"]["input"], "123");
+    assert!(json["billingAddress"]["city"].is_object());
+    assert!(json.get("zip_code").is_none());
+}
+
+#[test]
+fn test_errors_count_and_all_messages() {
+    let err = invalid_message_signup().validate().unwrap_err();
+
+    assert_eq!(err.errors_count(), 3);
+    assert_eq!(
+        err.all_messages(),
+        vec![
+            (
+                "username".to_string(),
+                "length must be between 3 and 20".to_string()
+            ),
+            (
+                "tags[1]".to_string(),
+                "item #1: length must be between 1 and 10".to_string()
+            ),
+            (
+                "address.city".to_string(),
+                "length must be between 1 and 50".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_error_display_lists_every_failure() {
+    let err = invalid_message_signup().validate().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "username: length must be between 3 and 20\n\
+         tags[1]: item #1: length must be between 1 and 10\n\
+         address.city: length must be between 1 and 50"
+    );
+
+    let err = CustomMessageSignup {
+        name: "Ada".to_string(),
+        age: 200,
+        tags: vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string(),
+        ],
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "age: 200 is not a valid age\ntoo_many_tags: at most 3 tags are allowed"
+    );
+}
+
+#[test]
+fn test_error_is_a_std_error_with_nested_source() {
+    use std::error::Error;
+
+    fn validate_signup(signup: &MessageSignup) -> Result<(), Box<dyn Error>> {
+        signup.validate()?;
+        Ok(())
+    }
+
+    let err = validate_signup(&invalid_message_signup()).unwrap_err();
+    assert!(err.to_string().starts_with("username: "));
+    let source = err.source().unwrap();
+    assert_eq!(source.to_string(), "city: length must be between 1 and 50");
+    assert!(source.source().is_none());
+
+    let mut signup = invalid_message_signup();
+    signup.address.city = "Springfield".to_string();
+    let err = validate_signup(&signup).unwrap_err();
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_error_serializes_failures_with_params() {
+    let err = invalid_message_signup().validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({
+            "username": {
+                "string_length_validation": {
+                    "min": 3,
+                    "max": 20,
+                    "input": "al",
+                    "index": null,
+                    "message": "length must be between 3 and 20"
+                }
+            },
+            "tags": {
+                "elements": [
+                    {
+                        "index": 1,
+                        "string_length_validation": {
+                            "min": 1,
+                            "max": 10,
+                            "input": "a-very-long-tag",
+                            "index": 1,
+                            "message": "item #1: length must be between 1 and 10"
+                        }
+                    }
+                ]
+            },
+            "address": {
+                "city": {
+                    "string_length_validation": {
+                        "min": 1,
+                        "max": 50,
+                        "input": "",
+                        "index": null,
+                        "message": "length must be between 1 and 50"
+                    }
+                }
+            }
+        })
+    );
+
+    // Validators without `Serialize` only carry their message
+    let err = CustomMessageSignup {
+        name: "Ada".to_string(),
+        age: 200,
+        tags: vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string(),
+        ],
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({
+            "age": {
+                "number_range_validation": { "message": "200 is not a valid age" }
+            },
+            "cross_field": { "too_many_tags": "at most 3 tags are allowed" }
+        })
+    );
+}
+
+#[test]
+fn test_to_graphql_extensions() {
+    let err = invalid_message_signup().validate().unwrap_err();
+
+    assert_eq!(
+        err.to_graphql_extensions(),
+        serde_json::json!({
+            "username": [
+                { "code": "string_length_validation", "message": "length must be between 3 and 20" }
+            ],
+            "tags[1]": [
+                { "code": "string_length_validation", "message": "item #1: length must be between 1 and 10" }
+            ],
+            "address.city": [
+                { "code": "string_length_validation", "message": "length must be between 1 and 50" }
+            ],
+        })
+    );
+}
+
+#[test]
+fn test_to_pointer_map() {
+    let err = invalid_message_signup().validate().unwrap_err();
+
+    assert_eq!(
+        err.to_pointer_map(),
+        HashMap::from([
+            (
+                "/username".to_string(),
+                vec!["length must be between 3 and 20".to_string()]
+            ),
+            (
+                "/tags/1".to_string(),
+                vec!["item #1: length must be between 1 and 10".to_string()]
+            ),
+            (
+                "/address/city".to_string(),
+                vec!["length must be between 1 and 50".to_string()]
+            ),
+        ])
+    );
+}
+
+#[test]
+fn test_miette_diagnostic_renders_related_failures() {
+    let err = invalid_message_signup().validate().unwrap_err();
+    let diagnostic = err.to_diagnostic();
+
+    let mut rendered = String::new();
+    miette::NarratableReportHandler::new()
+        .render_report(&mut rendered, &diagnostic)
+        .unwrap();
+
+    assert!(rendered.starts_with("validation failed with 3 failures\n"));
+    assert!(rendered.contains("diagnostic help: check username, tags[1], address.city\n"));
+    assert!(rendered.contains("diagnostic code: koruma::validation\n"));
+    for related in [
+        "Error: username: length must be between 3 and 20\n",
+        "Error: tags[1]: item #1: length must be between 1 and 10\n",
+        "Error: address.city: length must be between 1 and 50\n",
+    ] {
+        assert!(
+            rendered.contains(related),
+            "missing {related:?} in {rendered}"
+        );
+    }
+    assert_eq!(
+        rendered
+            .matches("diagnostic code: koruma::string_length_validation")
+            .count(),
+        3
+    );
+}
+
+// ============================================================================
+// Help tests
+// ============================================================================
+
+#[test]
+fn test_failures_carry_help() {
+    let signup = HelpfulSignup {
+        username: "ab".to_string(),
+        age: 7,
+        tags: vec!["rust".to_string(), "a-very-long-tag".to_string()],
+    };
+    let err = signup.validate().unwrap_err();
+
+    let helps: Vec<(String, Option<&'static str>)> = err
+        .iter_failures()
+        .map(|failure| (failure.path().to_string(), failure.help()))
+        .collect();
+    assert_eq!(
+        helps,
+        vec![
+            (
+                "username".to_string(),
+                Some("pick a name between 3 and 20 characters")
+            ),
+            ("age".to_string(), Some("signup-age-help")),
+            ("age".to_string(), None),
+            ("tags[1]".to_string(), Some("keep tags short")),
+        ]
+    );
+
+    let age_help = err
+        .iter_failures()
+        .find(|failure| failure.validator() == "number_range_validation")
+        .and_then(|failure| failure.help_entry());
+    assert_eq!(age_help, Some(Help::Fluent("signup-age-help")));
+}
+
+// ============================================================================
+// Context field tests
+// ============================================================================
+
+#[test]
+fn test_try_new_computes_context_field() {
+    let comment = Comment::try_new(false, "short enough".to_string()).unwrap();
+    assert_eq!(comment.max_len, 20);
+
+    let err = Comment::try_new(false, "x".repeat(21)).unwrap_err();
+    assert!(err.body().string_length_validation().is_some());
+    assert!(err.iter_failures().all(|f| f.path().to_string() == "body"));
+
+    let premium = Comment::try_new(true, "x".repeat(21)).unwrap();
+    assert!(premium.premium);
+    assert_eq!(premium.max_len, 500);
+}
+
+#[test]
+fn test_try_new_takes_context_param() {
+    assert!(BoundedScore::try_new(10, 7).is_ok());
+
+    let err = BoundedScore::try_new(5, 7).unwrap_err();
+    assert!(err.score().generic_range_validation().is_some());
+}
+
+// ============================================================================
+// Map entry tests
+// ============================================================================
+
+#[test]
+fn test_failures_sorted_by_declaration_order() {
+    let signup = SortedSignup {
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: String::new(),
+            zip_code: "12345".to_string(),
+        },
+        labels: HashMap::from([
+            ("tier".to_string(), "tier".to_string()),
+            ("plan".to_string(), String::new()),
+            ("region".to_string(), "eu".to_string()),
+        ]),
+        username: String::new(),
+        tags: vec![String::new(), "a-very-long-tag".to_string()],
+    };
+    let err = signup.validate().unwrap_err();
+
+    let sorted = err.failures_sorted();
+    let rendered: Vec<String> = sorted
+        .iter()
+        .map(|failure| format!("{} {}", failure.path(), failure.validator()))
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "labels[plan] distinct_entry_validation",
+            "labels[tier] distinct_entry_validation",
+            "username string_length_validation",
+            "username named_non_empty_validation",
+            "tags[0] string_length_validation",
+            "tags[1] string_length_validation",
+            "tags[0] named_non_empty_validation",
+            "address.city string_length_validation",
+        ]
+    );
+    for _ in 0..5 {
+        assert_eq!(err.failures_sorted(), sorted);
+    }
+}
+
+#[test]
+fn test_each_validates_map_values_by_key() {
+    let valid = Inventory {
+        stock: HashMap::from([("apples".to_string(), 12)]),
+    };
+    assert!(valid.validate().is_ok());
+
+    let inventory = Inventory {
+        stock: HashMap::from([
+            ("apples".to_string(), 12),
+            ("pears".to_string(), -3),
+            ("plums".to_string(), 250),
+        ]),
+    };
+    let err = inventory.validate().unwrap_err();
+
+    let mut keys: Vec<&str> = err
+        .stock()
+        .element_errors()
+        .iter()
+        .map(|(key, _)| key.as_str())
+        .collect();
+    keys.sort();
+    assert_eq!(keys, vec!["pears", "plums"]);
+
+    let pears = err.stock().by_key("pears").unwrap();
+    assert_eq!(pears.number_range_validation().unwrap().actual, -3);
+    let plums = err.stock().by_key(&"plums".to_string()).unwrap();
+    assert_eq!(plums.number_range_validation().unwrap().actual, 250);
+    assert!(err.stock().by_key("apples").is_none());
+
+    let mut paths: Vec<String> = err
+        .iter_failures()
+        .map(|failure| failure.path().to_string())
+        .collect();
+    paths.sort();
+    assert_eq!(paths, vec!["stock[pears]", "stock[plums]"]);
+}
+
+#[test]
+fn test_each_validates_arrays_and_vec_deques() {
+    let valid = Playlist {
+        ratings: [1, 3, 5],
+        queue: VecDeque::from(["intro".to_string()]),
+    };
+    assert!(valid.validate().is_ok());
+
+    let playlist = Playlist {
+        ratings: [1, 9, 0],
+        queue: VecDeque::from(["intro".to_string(), "an extended mix".to_string()]),
+    };
+    let err = playlist.validate().unwrap_err();
+
+    let rating_indices: Vec<usize> = err
+        .ratings()
+        .element_errors()
+        .iter()
+        .map(|(idx, _)| *idx)
+        .collect();
+    assert_eq!(rating_indices, vec![1, 2]);
+
+    let (idx, queue_error) = &err.queue().element_errors()[0];
+    assert_eq!(*idx, 1);
+    let failed = queue_error.string_length_validation().unwrap();
+    assert_eq!(failed.input, "an extended mix");
+    assert_eq!(failed.index, Some(1));
+}
+
+#[test]
+fn test_entries_validate_key_value_pairs() {
+    let valid = EnvConfig {
+        vars: HashMap::from([("HOME".to_string(), "/home/me".to_string())]),
+    };
+    assert!(valid.validate().is_ok());
+
+    let config = EnvConfig {
+        vars: HashMap::from([
+            ("HOME".to_string(), "/home/me".to_string()),
+            ("SHELL".to_string(), "SHELL".to_string()),
+            ("EDITOR".to_string(), String::new()),
+        ]),
+    };
+    let err = config.validate().unwrap_err();
+
+    let mut keys: Vec<&str> = err
+        .vars()
+        .element_errors()
+        .iter()
+        .map(|(key, _)| key.as_str())
+        .collect();
+    keys.sort();
+    assert_eq!(keys, vec!["EDITOR", "SHELL"]);
+
+    let (_, shell_error) = err
+        .vars()
+        .element_errors()
+        .iter()
+        .find(|(key, _)| key == "SHELL")
+        .unwrap();
+    let failed = shell_error.distinct_entry_validation().unwrap();
+    assert_eq!(failed.entry, ("SHELL".to_string(), "SHELL".to_string()));
+
+    let mut messages: Vec<(String, String)> = err
+        .failure_messages()
+        .into_iter()
+        .map(|(failure, message)| (failure.path().to_string(), message))
+        .collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            (
+                "vars[EDITOR]".to_string(),
+                "`EDITOR` must have a value other than its key".to_string()
+            ),
+            (
+                "vars[SHELL]".to_string(),
+                "`SHELL` must have a value other than its key".to_string()
+            ),
+        ]
+    );
+}
+
+// ============================================================================
+// Field name tests
+// ============================================================================
+
+#[test]
+fn test_validators_receive_field_name() {
+    use koruma::HasFieldName as _;
+
+    let contact = NamedContact {
+        first_name: String::new(),
+        phones: vec!["555-0100".to_string(), String::new()],
+    };
+    let err = contact.validate().unwrap_err();
+
+    let failed = err.first_name().named_non_empty_validation().unwrap();
+    assert_eq!(failed.field_name(), Some("first_name"));
+
+    let messages: Vec<String> = err
+        .failure_messages()
+        .into_iter()
+        .map(|(_, message)| message)
+        .collect();
+    assert_eq!(
+        messages,
+        vec!["first_name must not be empty", "phones must not be empty"]
+    );
+}
+
+// ============================================================================
+// Failure bit tests
+// ============================================================================
+
+#[test]
+fn test_failure_bits_map_back_to_names() {
+    use super::fixtures::MessageSignupKorumaValidationError;
+
+    assert_eq!(
+        MessageSignupKorumaValidationError::FAILURE_BIT_NAMES,
+        &[
+            "username.string_length_validation",
+            "tags[].string_length_validation",
+            "address",
+        ]
+    );
+
+    let err = invalid_message_signup().validate().unwrap_err();
+    assert_eq!(err.failure_bits(), 0b111);
+
+    let mut signup = invalid_message_signup();
+    signup.username = "alice".to_string();
+    let err = signup.validate().unwrap_err();
+    let bits = err.failure_bits();
+    assert_eq!(bits, 0b110);
+
+    let failed: Vec<&str> = MessageSignupKorumaValidationError::FAILURE_BIT_NAMES
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| bits & (1 << i) != 0)
+        .map(|(_, name)| *name)
+        .collect();
+    assert_eq!(failed, vec!["tags[].string_length_validation", "address"]);
+    assert_eq!(err.failure_bit_words(), vec![0b110]);
+}
+
+// ============================================================================
+// Patch tests
+// ============================================================================
+
+#[test]
+fn test_patch_validates_only_set_fields() {
+    let patch = ProfileUpdatePatch {
+        age: Some(30),
+        ..Default::default()
+    };
+    assert!(ProfileUpdate::validate_patch(&patch).is_ok());
+
+    let patch = ProfileUpdatePatch {
+        age: Some(150),
+        ..Default::default()
+    };
+    let err = ProfileUpdate::validate_patch(&patch).unwrap_err();
+    assert!(err.age().number_range_validation().is_some());
+    assert!(err.name().string_length_validation().is_none());
+
+    let patch = ProfileUpdatePatch {
+        age: None,
+        name: Some(String::new()),
+    };
+    let err = patch.validate().unwrap_err();
+    assert!(err.age().number_range_validation().is_none());
+    assert!(err.name().string_length_validation().is_some());
+}
+
+// ============================================================================
+// Builder tests
+// ============================================================================
+
+#[test]
+fn test_builder_validates_on_build() {
+    let item = BuiltItem::builder()
+        .age(25)
+        .name("Alice")
+        .build_validated()
+        .unwrap();
+    assert_eq!(item.age, 25);
+    assert_eq!(item.name, "Alice");
+    assert_eq!(item.nickname, None);
+
+    let err = BuiltItem::builder()
+        .age(150)
+        .name("Bob")
+        .nickname("")
+        .build_validated()
+        .unwrap_err();
+    assert!(err.age().number_range_validation().is_some());
+    assert!(err.name().string_length_validation().is_none());
+    assert!(err.nickname().string_length_validation().is_some());
+}
+
+#[test]
+fn test_crate_visible_validation() {
+    use super::fixtures::InternalItemKorumaValidationError;
+
+    let err: InternalItemKorumaValidationError = InternalItem { age: 150 }.validate().unwrap_err();
+    assert_eq!(err.age().number_range_validation().unwrap().actual, 150);
+}
+
+#[test]
+fn test_typed_errors_return_first_failed_validator() {
+    let item = TypedItem {
+        value: 101,
+        tags: vec![String::new()],
+    };
+    let err = item.validate().unwrap_err();
+
+    match err.value() {
+        Ok(()) => panic!("value should have failed"),
+        Err(TypedItemValueKorumaValidator::NumberRangeValidation(v)) => assert_eq!(v.actual, 101),
+        Err(TypedItemValueKorumaValidator::EvenNumberValidation(_)) => {
+            panic!("range is declared first")
+        },
+    }
+    assert!(err.value_errors().even_number_validation().is_some());
+    assert_eq!(err.tags().element_errors().len(), 1);
+
+    let item = TypedItem {
+        value: 42,
+        tags: vec![],
+    };
+    assert!(item.validation_report().value().is_ok());
+}
+
+// ============================================================================
+// Reused error buffer tests
+// ============================================================================
+
+#[test]
+fn test_validate_into_reuses_error_buffer() {
+    use super::fixtures::OrderWithLenCheckKorumaValidationError;
+
+    let invalid = OrderWithLenCheck {
+        scores: vec![150.0, -1.0, 50.0],
+    };
+    let mut error = OrderWithLenCheckKorumaValidationError::default();
+    assert!(!invalid.validate_into(&mut error));
+    let buffer = error.scores().element_errors().as_ptr();
+
+    for _ in 0..10 {
+        assert!(!invalid.validate_into(&mut error));
+        assert_eq!(error.scores().element_errors().len(), 2);
+        assert_eq!(error.scores().element_errors().as_ptr(), buffer);
+    }
+
+    let valid = OrderWithLenCheck { scores: vec![50.0] };
+    assert!(valid.validate_into(&mut error));
+    assert!(error.is_empty());
+}
+
+#[test]
+fn test_validate_into_clears_nested_slots() {
+    let mut customer = Customer {
+        name: "Alice".to_string(),
+        address: Address {
+            street: "".to_string(),
+            city: "Springfield".to_string(),
+            zip_code: "12345".to_string(),
+        },
+    };
+    let mut error = customer.validation_report();
+    assert!(error.address().is_some());
+
+    customer.address.street = "123 Main St".to_string();
+    assert!(customer.validate_into(&mut error));
+    assert!(error.address().is_none());
+}
+
+// ============================================================================
+// Merge tests
+// ============================================================================
+
+#[test]
+fn test_merge_warnings_with_errors_is_blocking() {
+    let warnings = SeverityProfile {
+        nickname: "Al".to_string(),
+        age: 31,
+    }
+    .validation_report();
+    assert!(!warnings.has_blocking_errors());
+
+    let errors = SeverityProfile {
+        nickname: "Alice".to_string(),
+        age: 130,
+    }
+    .validation_report();
+    assert!(errors.has_blocking_errors());
+
+    let merged = warnings.merge_with(errors);
+    assert!(merged.has_blocking_errors());
+    assert!(merged.nickname().string_length_validation().is_some());
+    assert!(merged.age().number_range_validation().is_some());
+    assert!(merged.age().even_number_validation().is_some());
+    assert_eq!(merged.count_by_severity(), (1, 2));
+}
+
+#[test]
+fn test_merge_combines_element_and_nested_failures() {
+    let mut first = Order {
+        scores: vec![150.0, 50.0],
+    }
+    .validation_report();
+    let second = Order {
+        scores: vec![150.0, 50.0, -1.0],
+    }
+    .validation_report();
+    first.merge(second);
+    let indices: Vec<usize> = first
+        .scores()
+        .element_errors()
+        .iter()
+        .map(|(idx, _)| *idx)
+        .collect();
+    assert_eq!(indices, vec![0, 2]);
+
+    let valid_customer = Customer {
+        name: "Alice".to_string(),
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: "Springfield".to_string(),
+            zip_code: "12345".to_string(),
+        },
+    };
+    let mut bad_street = valid_customer.validation_report();
+    let mut customer = valid_customer;
+    customer.address.street = String::new();
+    bad_street.merge(customer.validation_report());
+    customer.address.street = "123 Main St".to_string();
+    customer.address.city = String::new();
+    bad_street.merge(customer.validation_report());
+
+    let address = bad_street.address().unwrap();
+    assert!(address.street().string_length_validation().is_some());
+    assert!(address.city().string_length_validation().is_some());
+}
+
+// ============================================================================
+// Normalization
+// ============================================================================
+
+#[test]
+fn test_validate_and_normalize_lowercases_on_success() {
+    let mut signup = NormalizedSignup {
+        email: "Alice@Example.COM".to_string(),
+        age: 30,
+    };
+
+    assert!(signup.validate_and_normalize().is_ok());
+    assert_eq!(signup.email, "alice@example.com");
+}
+
+#[test]
+fn test_validate_and_normalize_leaves_invalid_struct_unchanged() {
+    let mut signup = NormalizedSignup {
+        email: "Alice@Example.COM".to_string(),
+        age: 200,
+    };
+
+    let err = signup.validate_and_normalize().unwrap_err();
+    assert!(err.age().number_range_validation().is_some());
+    assert_eq!(signup.email, "Alice@Example.COM");
+}
+
+// ============================================================================
+// Cross-field checks
+// ============================================================================
+
+#[test]
+fn test_cross_field_checks_pass() {
+    let booking = Booking {
+        password: "correct horse".to_string(),
+        confirm_password: "correct horse".to_string(),
+        start_day: 10,
+        end_day: 12,
+    };
+
+    assert!(booking.validate().is_ok());
+}
+
+#[test]
+fn test_cross_field_errors_are_keyed() {
+    let booking = Booking {
+        password: "correct horse".to_string(),
+        confirm_password: "correct hose".to_string(),
+        start_day: 10,
+        end_day: 12,
+    };
+
+    let err = booking.validate().unwrap_err();
+    assert!(err.password().is_empty());
+    let mismatch = err.cross_field("passwords_match").unwrap();
+    assert_eq!(mismatch.to_string(), "passwords don't match");
+    assert!(err.cross_field("end_after_start").is_none());
+    assert_eq!(err.cross_field_errors().len(), 1);
+}
+
+#[test]
+fn test_cross_field_checks_run_after_field_failures() {
+    let booking = Booking {
+        password: "short".to_string(),
+        confirm_password: "short".to_string(),
+        start_day: 400,
+        end_day: 12,
+    };
+
+    let err = booking.validate().unwrap_err();
+    assert!(err.start_day().number_range_validation().is_some());
+    assert!(err.cross_field("end_after_start").is_some());
+
+    let mut merged = Booking {
+        password: "correct horse".to_string(),
+        confirm_password: "other".to_string(),
+        start_day: 1,
+        end_day: 2,
+    }
+    .validate()
+    .unwrap_err();
+    merged.merge(err);
+    assert_eq!(
+        merged
+            .cross_field_errors()
+            .keys()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec!["end_after_start", "passwords_match"]
+    );
+
+    merged.clear();
+    assert!(merged.is_empty());
+}
+
+#[test]
+fn test_cross_field_failures_are_counted() {
+    let err = Booking {
+        password: "correct horse".to_string(),
+        confirm_password: "correct hose".to_string(),
+        start_day: 400,
+        end_day: 12,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(err.iter_failures().count(), 1);
+    assert_eq!(err.iter_cross_field_errors().count(), 2);
+    assert_eq!(err.errors_count(), 3);
+    assert_eq!(err.count_by_severity(), (3, 0));
+}
+
+#[test]
+fn test_cross_field_only_failure_has_messages() {
+    let err = CustomMessageSignup {
+        name: "Ada".to_string(),
+        age: 36,
+        tags: vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string(),
+        ],
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(err.iter_failures().count(), 0);
+    assert_eq!(err.errors_count(), 1);
+    assert_eq!(
+        err.all_messages(),
+        vec![(
+            "too_many_tags".to_string(),
+            "at most 3 tags are allowed".to_string()
+        )]
+    );
+    assert_eq!(
+        err.first_blocking_or_warning(),
+        Some((Severity::Error, "at most 3 tags are allowed".to_string()))
+    );
+}
+
+// ============================================================================
+// Validated wrapper
+// ============================================================================
+
+fn greeting(account: &koruma::Validated<VerifiedAccount>) -> String {
+    format!("hello {}", account.username)
+}
+
+#[test]
+fn test_into_validated_wraps_valid_struct() {
+    let account = VerifiedAccount {
+        username: "alice".to_string(),
+        age: 30,
+    }
+    .into_validated()
+    .unwrap();
+
+    assert_eq!(greeting(&account), "hello alice");
+    assert_eq!(account.into_inner().age, 30);
+}
+
+#[test]
+fn test_into_validated_returns_errors() {
+    let err = VerifiedAccount {
+        username: "al".to_string(),
+        age: 30,
+    }
+    .into_validated()
+    .unwrap_err();
+
+    assert!(err.username().string_length_validation().is_some());
+    assert!(err.age().is_empty());
+}
+
+// ============================================================================
+// Conditional validation
+// ============================================================================
+
+#[test]
+fn test_when_skips_validators_if_predicate_is_false() {
+    let address = TaxedAddress {
+        country: "FR".to_string(),
+        tax_id: Some("123".to_string()),
+    };
+
+    assert!(address.validate().is_ok());
+}
+
+#[test]
+fn test_when_runs_validators_if_predicate_is_true() {
+    let address = TaxedAddress {
+        country: "US".to_string(),
+        tax_id: Some("123".to_string()),
+    };
+
+    let err = address.validate().unwrap_err();
+    assert!(err.tax_id().string_length_validation().is_some());
+    assert!(err.country().is_empty());
+
+    // The inner value is still only validated when present
+    let address = TaxedAddress {
+        country: "US".to_string(),
+        tax_id: None,
+    };
+    assert!(address.validate().is_ok());
+}
+
+// ============================================================================
+// Validation steps
+// ============================================================================
+
+#[test]
+fn test_validation_steps_run_lazily_and_stop_early() {
+    use std::sync::atomic::Ordering;
+
+    let form = SteppedForm {
+        first_name: "Ada".to_string(),
+        last_name: String::new(),
+        age: 37,
+        address: Address {
+            street: "123 Main St".to_string(),
+            city: String::new(),
+            zip_code: "12345".to_string(),
+        },
+    };
+
+    let before = STEPPED_FORM_EVALUATIONS.load(Ordering::SeqCst);
+    let mut steps = form.validation_steps();
+    assert_eq!(STEPPED_FORM_EVALUATIONS.load(Ordering::SeqCst), before);
+
+    let first_failure = steps.find(|step| !step.passed()).unwrap();
+    assert_eq!(first_failure.field(), "last_name");
+    assert_eq!(first_failure.validator(), Some("string_length_validation"));
+    assert!(
+        first_failure
+            .error()
+            .last_name()
+            .string_length_validation()
+            .is_some()
+    );
+    drop(steps);
+    // Only the two name checks ran
+    assert_eq!(STEPPED_FORM_EVALUATIONS.load(Ordering::SeqCst), before + 2);
+
+    let outcomes: Vec<_> = form
+        .validation_steps()
+        .map(|step| (step.field(), step.validator(), step.passed()))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("first_name", Some("string_length_validation"), true),
+            ("last_name", Some("string_length_validation"), false),
+            ("age", Some("number_range_validation"), true),
+            ("age", Some("even_number_validation"), false),
+            ("address", None, false),
+        ]
+    );
+}
+
+// =============================================================================
+// Async validation
+// =============================================================================
+
+#[test]
+fn test_async_validation_runs_sync_and_async_fields() {
+    use futures::executor::block_on;
+
+    let signup = AsyncSignup {
+        username: "ada".to_string(),
+        aliases: vec!["ada_l".to_string(), "admin".to_string()],
+        password: "short".to_string(),
+    };
+
+    // The future can be handed to multi-threaded executors
+    fn assert_send<T: Send>(_: &T) {}
+    let validation = signup.validate();
+    assert_send(&validation);
+
+    let err = block_on(validation).unwrap_err();
+    assert!(err.username().unique_username_validation().is_none());
+    let alias_errors = err.aliases().element_errors();
+    assert_eq!(alias_errors.len(), 1);
+    assert_eq!(alias_errors[0].0, 1);
+    assert_eq!(
+        alias_errors[0]
+            .1
+            .unique_username_validation()
+            .unwrap()
+            .to_string(),
+        "username `admin` is already taken"
+    );
+    assert!(err.password().string_length_validation().is_some());
+
+    let signup = AsyncSignup {
+        username: "root".to_string(),
+        aliases: Vec::new(),
+        password: "correct horse".to_string(),
+    };
+    let err = block_on(signup.validate()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "username: username `root` is already taken"
+    );
+
+    let signup = AsyncSignup {
+        username: "grace".to_string(),
+        aliases: vec!["hopper".to_string()],
+        password: "correct horse".to_string(),
+    };
+    assert!(block_on(signup.validate()).is_ok());
+}
+
+// =============================================================================
+// Combinators
+// =============================================================================
+
+#[test]
+fn test_all_of_and_any_of_combinators() {
+    assert!(
+        ComposedScore {
+            score: 42,
+            bonus: 5
+        }
+        .validate()
+        .is_ok()
+    );
+    assert!(
+        ComposedScore {
+            score: 42,
+            bonus: 95
+        }
+        .validate()
+        .is_ok()
+    );
+
+    let err = ComposedScore {
+        score: 101,
+        bonus: 50,
+    }
+    .validate()
+    .unwrap_err();
+
+    // Out of range and odd: both children of the `AllOf` failed
+    let score_err = err.score().all_of_validation().unwrap();
+    assert_eq!(score_err.failures(), vec![0, 1]);
+    assert_eq!(score_err.to_string(), "failed 2 of 2 required checks");
+
+    let bonus_err = err.bonus().any_of_validation().unwrap();
+    assert_eq!(bonus_err.failures(), vec![0, 1]);
+    assert_eq!(
+        err.to_string(),
+        "score: failed 2 of 2 required checks\n\
+         bonus: failed all 2 alternative checks"
+    );
+
+    // Only the failing child is reported
+    let err = ComposedScore {
+        score: 43,
+        bonus: 0,
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.score().all_of_validation().unwrap().failures(), vec![1]);
+    assert!(err.bonus().any_of_validation().is_none());
+}
+
+#[test]
+fn test_not_validation_inverts_inner_validator() {
+    assert!(
+        LongCode {
+            code: "ABCD-1234".to_string()
+        }
+        .validate()
+        .is_ok()
+    );
+
+    let err = LongCode {
+        code: "AB".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+    let not_err = err.code().not_validation().unwrap();
+    assert_eq!(not_err.inner().input, "");
+    assert_eq!(
+        koruma::StoredValue::stored_value(not_err),
+        &Some("AB".to_string())
+    );
+    assert_eq!(
+        err.to_string(),
+        "code: expected value to fail: length must be between 0 and 3"
+    );
+}
+
+#[test]
+fn test_deferred_value_clones_only_failing_values() {
+    let clones = || COUNTED_TEXT_CLONES.load(std::sync::atomic::Ordering::Relaxed);
+    let text = |s: &str| CountedText(s.to_string());
+
+    let before = clones();
+    let valid = Article {
+        title: text("Koruma"),
+        tags: vec![text("rust"), text("web")],
+    };
+    assert!(valid.validate().is_ok());
+    assert_eq!(clones(), before);
+
+    let invalid = Article {
+        title: text("Validation in Rust"),
+        tags: vec![text("rust"), text("validation")],
+    };
+    let err = invalid.validate().unwrap_err();
+    // One clone per failing value
+    assert_eq!(clones(), before + 2);
+    assert_eq!(
+        err.title().counted_text_len_validation().unwrap().actual,
+        text("Validation in Rust")
+    );
+    let (index, tag_err) = &err.tags().element_errors()[0];
+    assert_eq!(*index, 1);
+    assert_eq!(
+        tag_err.counted_text_len_validation().unwrap().actual,
+        text("validation")
+    );
+}
+
+#[test]
+fn test_is_valid_matches_validate() {
+    // Warnings alone don't make a struct invalid
+    let profile = SeverityProfile {
+        nickname: "Al".to_string(),
+        age: 31,
+    };
+    assert!(profile.is_valid());
+    let profile = SeverityProfile {
+        nickname: "Alice".to_string(),
+        age: 130,
+    };
+    assert!(!profile.is_valid());
+    assert!(profile.validate().is_err());
+
+    // Failures in a nested struct make the outer struct invalid
+    let address = |zip_code: &str| Address {
+        street: "123 Main St".to_string(),
+        city: "Springfield".to_string(),
+        zip_code: zip_code.to_string(),
+    };
+    let customer = Customer {
+        name: "Alice".to_string(),
+        address: address("12345"),
+    };
+    assert!(customer.is_valid());
+    assert!(koruma::ValidateExt::is_valid(&customer));
+    let customer = Customer {
+        name: "Alice".to_string(),
+        address: address("1"),
+    };
+    assert!(!customer.is_valid());
+
+    // Element and cross-field failures count too
+    let article = Article {
+        title: CountedText("Koruma".to_string()),
+        tags: vec![CountedText("validation".to_string())],
+    };
+    assert!(!article.is_valid());
+    let booking = Booking {
+        password: "correct horse".to_string(),
+        confirm_password: "battery staple".to_string(),
+        start_day: 10,
+        end_day: 12,
+    };
+    assert!(!booking.is_valid());
+    assert!(booking.validate().is_err());
+}
@@ -1,6 +1,11 @@
 //! Validator implementations for integration tests.
 
-use koruma::{Validate, validator};
+// The validators spell out both outcomes of their checks
+#![allow(clippy::needless_bool)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use koruma::{AsyncValidate, ObserveValue, Validate, validator};
 
 /// A validation rule that checks if a number is within a specified range.
 #[validator]
@@ -41,13 +46,16 @@ impl<T: PartialOrd + Clone> Validate<T> for GenericRangeValidation<T> {
 }
 
 /// A validation rule that checks string length.
+/// Records the element index when used in `each(...)`.
 #[validator]
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, serde::Serialize)]
 pub struct StringLengthValidation {
     min: usize,
     max: usize,
     #[koruma(value)]
     pub input: String,
+    #[koruma(index)]
+    pub index: Option<usize>,
 }
 
 impl Validate<String> for StringLengthValidation {
@@ -61,6 +69,15 @@ impl Validate<String> for StringLengthValidation {
     }
 }
 
+impl std::fmt::Display for StringLengthValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(index) = self.index {
+            write!(f, "item #{}: ", index)?;
+        }
+        write!(f, "length must be between {} and {}", self.min, self.max)
+    }
+}
+
 /// A validation rule that checks if a number is even.
 #[validator]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -108,3 +125,136 @@ impl<T> VecLenValidation<T> {
         self.actual.len()
     }
 }
+
+/// A validation rule over a `(key, value)` map entry, used with `entries(...)`:
+/// the value must be non-empty and differ from its key.
+#[validator]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DistinctEntryValidation {
+    #[koruma(value)]
+    pub entry: (String, String),
+}
+
+impl Validate<(String, String)> for DistinctEntryValidation {
+    fn validate(&self, (key, value): &(String, String)) -> bool {
+        !value.is_empty() && value != key
+    }
+}
+
+impl std::fmt::Display for DistinctEntryValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` must have a value other than its key", self.entry.0)
+    }
+}
+
+/// A validation rule that checks a string isn't empty, naming the field in its
+/// message. `field_name` is filled in by `#[derive(Koruma)]`.
+#[validator]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NamedNonEmptyValidation {
+    #[koruma(value)]
+    pub actual: String,
+    pub field_name: Option<&'static str>,
+}
+
+impl Validate<String> for NamedNonEmptyValidation {
+    fn validate(&self, value: &String) -> bool {
+        !value.is_empty()
+    }
+}
+
+impl std::fmt::Display for NamedNonEmptyValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} must not be empty",
+            self.field_name.unwrap_or("value")
+        )
+    }
+}
+
+/// A validation rule that checks a string has enough distinct characters.
+/// Recomputes `distinct` from the real value when the stored copy is redacted.
+#[validator]
+#[derive(Clone, Debug)]
+pub struct DistinctCharsValidation {
+    min: usize,
+    #[koruma(value)]
+    pub input: String,
+    #[builder(skip = count_distinct_chars(&input))]
+    pub distinct: usize,
+}
+
+fn count_distinct_chars(value: &str) -> usize {
+    value
+        .chars()
+        .collect::<std::collections::HashSet<_>>()
+        .len()
+}
+
+impl Validate<String> for DistinctCharsValidation {
+    fn validate(&self, value: &String) -> bool {
+        count_distinct_chars(value) >= self.min
+    }
+}
+
+impl ObserveValue<String> for DistinctCharsValidation {
+    fn observe_value(&mut self, value: &String) {
+        self.distinct = count_distinct_chars(value);
+    }
+}
+
+/// Usernames that are already taken, standing in for a database table.
+pub const TAKEN_USERNAMES: &[&str] = &["admin", "root"];
+
+/// An async validation rule that checks a username isn't taken yet.
+#[validator]
+#[derive(Clone, Debug)]
+pub struct UniqueUsernameValidation {
+    #[koruma(value)]
+    pub input: String,
+}
+
+impl AsyncValidate<String> for UniqueUsernameValidation {
+    async fn validate(&self, value: &String) -> bool {
+        // Stands in for a database lookup
+        let taken = futures::future::ready(TAKEN_USERNAMES.contains(&value.as_str())).await;
+        !taken
+    }
+}
+
+impl std::fmt::Display for UniqueUsernameValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "username `{}` is already taken", self.input)
+    }
+}
+
+/// Number of times a [`CountedText`] has been cloned.
+pub static COUNTED_TEXT_CLONES: AtomicUsize = AtomicUsize::new(0);
+
+/// Text that counts its clones, to check when the derive copies a value.
+#[derive(Debug, Default, PartialEq)]
+pub struct CountedText(pub String);
+
+impl Clone for CountedText {
+    fn clone(&self) -> Self {
+        COUNTED_TEXT_CLONES.fetch_add(1, Ordering::Relaxed);
+        Self(self.0.clone())
+    }
+}
+
+/// A validation rule that checks the length of a [`CountedText`]. It only
+/// reads its configuration, so the value is cloned only when it fails.
+#[validator(deferred_value)]
+#[derive(Clone, Debug)]
+pub struct CountedTextLenValidation {
+    max: usize,
+    #[koruma(value)]
+    pub actual: CountedText,
+}
+
+impl Validate<CountedText> for CountedTextLenValidation {
+    fn validate(&self, value: &CountedText) -> bool {
+        value.0.len() <= self.max
+    }
+}
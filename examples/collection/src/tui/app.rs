@@ -1,7 +1,8 @@
+use std::collections::VecDeque;
 use std::io;
 
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
-use koruma::showcase::{DynValidator, InputType, ValidatorShowcase, validators};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use koruma::showcase::{DynValidator, ValidatorShowcase, validators};
 use ratatui::{
     DefaultTerminal, Frame,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -13,16 +14,41 @@ use tui_input::{Input, InputRequest};
 
 use koruma_shared_lib::Languages;
 
+/// Number of checked inputs kept in the history panel.
+const HISTORY_LEN: usize = 20;
+
+/// An input checked with Enter, kept for the current session.
+struct HistoryEntry {
+    /// The input text
+    input: String,
+    /// Name of the validator it was checked against
+    validator: &'static str,
+    /// Whether it passed
+    passed: bool,
+}
+
 /// Application state for the TUI.
 struct App {
     /// Current input text
     input: Input,
-    /// List of all registered validators
+    /// List of all registered validators, grouped by category
     validators: Vec<&'static ValidatorShowcase>,
-    /// Currently selected validator index
+    /// Search text filtering the validators by name or description
+    search: Input,
+    /// Whether keystrokes go to the search box instead of the input
+    searching: bool,
+    /// Indices into `validators` of the validators matching `search`
+    filtered: Vec<usize>,
+    /// Currently selected index into `filtered`
     selected: usize,
     /// Current validator instance (created from input)
     current_validator: Option<Box<dyn DynValidator>>,
+    /// Why the input can't build the current validator, if it can't
+    input_error: Option<String>,
+    /// Checked inputs, newest first
+    history: VecDeque<HistoryEntry>,
+    /// Selected index into `history`, if one was re-selected
+    history_selected: Option<usize>,
     /// Current language for fluent output
     current_language: Languages,
     /// Whether the app should exit
@@ -31,39 +57,85 @@ struct App {
 
 impl App {
     fn new() -> Self {
-        let validators = validators();
+        let mut validators = validators();
+        validators.sort_by_key(|showcase| showcase.category());
+        let filtered = (0..validators.len()).collect();
         Self {
             input: Input::default(),
             validators,
+            search: Input::default(),
+            searching: false,
+            filtered,
             selected: 0,
             current_validator: None,
+            input_error: None,
+            history: VecDeque::new(),
+            history_selected: None,
             current_language: Languages::default(),
             should_exit: false,
         }
     }
 
     fn current_showcase(&self) -> Option<&'static ValidatorShowcase> {
-        self.validators.get(self.selected).copied()
+        self.filtered
+            .get(self.selected)
+            .map(|&index| self.validators[index])
     }
 
     fn validate_input(&mut self) {
-        if let Some(showcase) = self.current_showcase() {
-            let input = self.input.value();
-            self.current_validator = Some((showcase.create_validator)(input));
+        match self.current_showcase() {
+            Some(showcase) => {
+                let input = self.input.value();
+                match showcase.try_create(input) {
+                    Ok(validator) => {
+                        self.current_validator = Some(validator);
+                        self.input_error = None;
+                    },
+                    Err(error) => {
+                        self.current_validator = None;
+                        self.input_error = (!input.is_empty()).then_some(error);
+                    },
+                }
+            },
+            None => {
+                self.current_validator = None;
+                self.input_error = None;
+            },
         }
     }
 
+    /// Recomputes the validators matching the search text, keeping the
+    /// current validator selected if it still matches.
+    fn apply_search(&mut self) {
+        let current = self.filtered.get(self.selected).copied();
+        let query = self.search.value().to_lowercase();
+        self.filtered = self
+            .validators
+            .iter()
+            .enumerate()
+            .filter(|(_, showcase)| {
+                showcase.name.to_lowercase().contains(&query)
+                    || showcase.description.to_lowercase().contains(&query)
+            })
+            .map(|(index, _)| index)
+            .collect();
+        self.selected = current
+            .and_then(|current| self.filtered.iter().position(|&index| index == current))
+            .unwrap_or(0);
+        self.validate_input();
+    }
+
     fn next_validator(&mut self) {
-        if !self.validators.is_empty() {
-            self.selected = (self.selected + 1) % self.validators.len();
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
             self.validate_input();
         }
     }
 
     fn prev_validator(&mut self) {
-        if !self.validators.is_empty() {
+        if !self.filtered.is_empty() {
             self.selected = if self.selected == 0 {
-                self.validators.len() - 1
+                self.filtered.len() - 1
             } else {
                 self.selected - 1
             };
@@ -71,6 +143,88 @@ impl App {
         }
     }
 
+    /// Category of the `position`-th matching validator.
+    fn category_at(&self, position: usize) -> &'static str {
+        self.validators[self.filtered[position]].category()
+    }
+
+    /// Jumps to the first matching validator of the next category.
+    fn next_category(&mut self) {
+        let Some(current) = self.current_showcase().map(|v| v.category()) else {
+            return;
+        };
+        self.selected = (self.selected..self.filtered.len())
+            .find(|&position| self.category_at(position) != current)
+            .unwrap_or(0);
+        self.validate_input();
+    }
+
+    /// Position of the first matching validator in the category of the
+    /// `position`-th one.
+    fn category_start(&self, position: usize) -> usize {
+        let category = self.category_at(position);
+        (0..position)
+            .rev()
+            .take_while(|&earlier| self.category_at(earlier) == category)
+            .last()
+            .unwrap_or(position)
+    }
+
+    /// Jumps to the first matching validator of the previous category.
+    fn prev_category(&mut self) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        let start = self.category_start(self.selected);
+        let previous_end = if start == 0 {
+            self.filtered.len() - 1
+        } else {
+            start - 1
+        };
+        self.selected = self.category_start(previous_end);
+        self.validate_input();
+    }
+
+    /// Records the current input and whether it passed in the history.
+    fn record_history(&mut self) {
+        let (Some(showcase), Some(validator)) = (self.current_showcase(), &self.current_validator)
+        else {
+            return;
+        };
+        let entry = HistoryEntry {
+            input: self.input.value().to_string(),
+            validator: showcase.name,
+            passed: validator.is_valid(),
+        };
+        let repeated = self
+            .history
+            .front()
+            .is_some_and(|last| last.input == entry.input && last.validator == entry.validator);
+        if !repeated {
+            self.history.push_front(entry);
+            self.history.truncate(HISTORY_LEN);
+        }
+        self.history_selected = None;
+    }
+
+    /// Moves the history selection by `offset` (positive is older) and
+    /// re-runs the selected input against the current validator.
+    fn select_history(&mut self, offset: isize) {
+        if self.history.is_empty() {
+            return;
+        }
+        let selected = match self.history_selected {
+            Some(selected) => selected
+                .saturating_add_signed(offset)
+                .min(self.history.len() - 1),
+            None if offset > 0 => 0,
+            None => return,
+        };
+        self.history_selected = Some(selected);
+        self.input = Input::new(self.history[selected].input.clone());
+        self.validate_input();
+    }
+
     fn next_language(&mut self) {
         self.current_language = self.current_language.next();
         super::i18n::change_locale(self.current_language).unwrap();
@@ -81,20 +235,23 @@ impl App {
             return;
         }
 
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         match key.code {
-            KeyCode::Esc => self.should_exit = true,
+            KeyCode::Char('f') if ctrl => self.searching = !self.searching,
+            KeyCode::Left if ctrl => self.prev_category(),
+            KeyCode::Right if ctrl => self.next_category(),
             KeyCode::Up => self.prev_validator(),
             KeyCode::Down => self.next_validator(),
             KeyCode::Tab => self.next_language(),
+            _ if self.searching => self.handle_search_key(key.code),
+            KeyCode::PageUp => self.select_history(1),
+            KeyCode::PageDown => self.select_history(-1),
+            KeyCode::Esc => self.should_exit = true,
+            KeyCode::Enter => self.record_history(),
             KeyCode::Char(c) => {
-                let allow = if let Some(showcase) = self.current_showcase() {
-                    match showcase.input_type {
-                        InputType::Numeric => c.is_ascii_digit() || c == '-',
-                        InputType::Text => true,
-                    }
-                } else {
-                    true
-                };
+                let allow = self
+                    .current_showcase()
+                    .is_none_or(|showcase| showcase.input_kind.allows_char(c));
 
                 if allow {
                     self.input.handle(InputRequest::InsertChar(c));
@@ -125,6 +282,43 @@ impl App {
         }
     }
 
+    /// Handles a key while the search box has focus, filtering live.
+    fn handle_search_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => self.searching = false,
+            KeyCode::Esc => {
+                self.searching = false;
+                self.search.reset();
+                self.apply_search();
+            },
+            KeyCode::Char(c) => {
+                self.search.handle(InputRequest::InsertChar(c));
+                self.apply_search();
+            },
+            KeyCode::Backspace => {
+                self.search.handle(InputRequest::DeletePrevChar);
+                self.apply_search();
+            },
+            KeyCode::Delete => {
+                self.search.handle(InputRequest::DeleteNextChar);
+                self.apply_search();
+            },
+            KeyCode::Home => {
+                self.search.handle(InputRequest::GoToStart);
+            },
+            KeyCode::End => {
+                self.search.handle(InputRequest::GoToEnd);
+            },
+            KeyCode::Left => {
+                self.search.handle(InputRequest::GoToPrevChar);
+            },
+            KeyCode::Right => {
+                self.search.handle(InputRequest::GoToNextChar);
+            },
+            _ => {},
+        }
+    }
+
     fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         // Initial validation
         self.validate_input();
@@ -145,6 +339,8 @@ impl App {
         // Layout constraints
         let constraints = vec![
             Constraint::Min(0),    // Top padding
+            Constraint::Length(3), // Search box
+            Constraint::Length(1), // Spacer
             Constraint::Length(3), // Validator selector
             Constraint::Length(1), // Spacer
             Constraint::Length(3), // Input box
@@ -161,28 +357,71 @@ impl App {
 
         let horizontal = Layout::horizontal([
             Constraint::Min(0),         // Left padding
-            Constraint::Percentage(70), // Content
+            Constraint::Percentage(60), // Content
+            Constraint::Length(2),      // Spacer
+            Constraint::Percentage(25), // History panel
             Constraint::Min(0),         // Right padding
         ]);
 
-        let validator_area = horizontal.split(vertical[1])[1];
-        let input_area = horizontal.split(vertical[3])[1];
-        let display_area = horizontal.split(vertical[5])[1];
-        let fluent_area = horizontal.split(vertical[7])[1];
-        let help_area = horizontal.split(vertical[9])[1];
+        let search_area = horizontal.split(vertical[1])[1];
+        let validator_area = horizontal.split(vertical[3])[1];
+        let input_area = horizontal.split(vertical[5])[1];
+        let display_area = horizontal.split(vertical[7])[1];
+        let fluent_area = horizontal.split(vertical[9])[1];
+        let help_row = horizontal.split(vertical[11]);
+        let help_area = help_row[1].union(help_row[3]);
+        let history_area = horizontal.split(vertical[1])[3].union(horizontal.split(vertical[9])[3]);
 
+        self.render_search(frame, search_area);
         self.render_validator_selector(frame, validator_area);
         self.render_input(frame, input_area);
         self.render_display_output(frame, display_area);
         self.render_fluent_output(frame, fluent_area);
+        self.render_history(frame, history_area);
         self.render_help(frame, help_area);
     }
 
+    fn render_search(&self, frame: &mut Frame, area: Rect) {
+        let border_color = if self.searching {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .title(" Search ")
+            .title_alignment(Alignment::Center);
+
+        let text = if self.search.value().is_empty() && !self.searching {
+            Line::from(Span::styled(
+                "Ctrl+F to filter by name or description",
+                Style::default().fg(Color::DarkGray),
+            ))
+        } else {
+            Line::from(Span::styled(
+                self.search.value(),
+                Style::default().fg(Color::Cyan),
+            ))
+        };
+        let paragraph = Paragraph::new(text).block(block);
+
+        frame.render_widget(paragraph, area);
+
+        if self.searching {
+            let cursor_x = area.x + 1 + self.search.visual_cursor() as u16;
+            frame.set_cursor_position((cursor_x.min(area.x + area.width - 2), area.y + 1));
+        }
+    }
+
     fn render_validator_selector(&self, frame: &mut Frame, area: Rect) {
         let showcase = self.current_showcase();
-        let (name, description) = showcase
-            .map(|v| (v.name, v.description))
-            .unwrap_or(("No validators", "No validators registered"));
+        let (name, description) = match showcase {
+            Some(v) => (v.name, v.description),
+            None if self.validators.is_empty() => ("No validators", "No validators registered"),
+            None => ("No matches", "No validators match the search"),
+        };
 
         let text = vec![
             Line::from(vec![
@@ -202,9 +441,10 @@ impl App {
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Blue))
             .title(format!(
-                " Validator ({}/{}) ",
-                self.selected + 1,
-                self.validators.len()
+                " {} ({}/{}) ",
+                showcase.map_or("Validator", |v| v.category()),
+                (self.selected + 1).min(self.filtered.len()),
+                self.filtered.len()
             ))
             .title_alignment(Alignment::Center);
 
@@ -220,6 +460,7 @@ impl App {
         let (emoji, border_color) = match &self.current_validator {
             Some(v) if v.is_valid() => ("✅ ", Color::Green),
             Some(_) => ("❌ ", Color::Red),
+            None if self.input_error.is_some() => ("⚠️ ", Color::Red),
             None => ("   ", Color::Yellow),
         };
 
@@ -238,6 +479,10 @@ impl App {
 
         frame.render_widget(paragraph, area);
 
+        if self.searching {
+            return;
+        }
+
         // Position cursor (offset by emoji width: 3 chars for emoji + space)
         let emoji_width = 3u16; // emoji takes ~2 chars + space
         let cursor_x = area.x + 1 + emoji_width + self.input.visual_cursor() as u16;
@@ -255,11 +500,7 @@ impl App {
                     (Style::default().fg(Color::Magenta), Color::Magenta, msg)
                 }
             },
-            None => (
-                Style::default().fg(Color::DarkGray),
-                Color::DarkGray,
-                "—".to_string(),
-            ),
+            None => self.empty_output(),
         };
 
         let block = Block::default()
@@ -287,11 +528,7 @@ impl App {
                     (Style::default().fg(Color::LightBlue), Color::LightBlue, msg)
                 }
             },
-            None => (
-                Style::default().fg(Color::DarkGray),
-                Color::DarkGray,
-                "—".to_string(),
-            ),
+            None => self.empty_output(),
         };
 
         let block = Block::default()
@@ -309,17 +546,91 @@ impl App {
         frame.render_widget(paragraph, area);
     }
 
+    fn render_history(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = if self.history.is_empty() {
+            vec![Line::from(Span::styled(
+                "Enter records the input",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            self.history
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    let (mark, color) = if entry.passed {
+                        ("✔ ", Color::Green)
+                    } else {
+                        ("✘ ", Color::Red)
+                    };
+                    let mut style = Style::default().fg(color);
+                    if self.history_selected == Some(index) {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    Line::from(vec![
+                        Span::styled(mark, style),
+                        Span::styled(entry.input.as_str(), style),
+                        Span::styled(
+                            format!("  {}", entry.validator),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ])
+                })
+                .collect()
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+            .title(format!(" History ({}) ", self.history.len()))
+            .title_alignment(Alignment::Center);
+
+        let paragraph = Paragraph::new(lines).block(block);
+
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Style, border color and message shown when there's no validator to render.
+    fn empty_output(&self) -> (Style, Color, String) {
+        if let Some(error) = &self.input_error {
+            (
+                Style::default().fg(Color::Red),
+                Color::Red,
+                format!("invalid input: {error}"),
+            )
+        } else {
+            (
+                Style::default().fg(Color::DarkGray),
+                Color::DarkGray,
+                "—".to_string(),
+            )
+        }
+    }
+
     fn render_help(&self, frame: &mut Frame, area: Rect) {
         let help_text = Line::from(vec![
             Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
             Span::raw(" cycle validator  "),
+            Span::styled("Ctrl+←/→", Style::default().fg(Color::Cyan)),
+            Span::raw(" cycle category  "),
+            Span::styled("Ctrl+F", Style::default().fg(Color::Cyan)),
+            Span::raw(" search  "),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" record  "),
+            Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
+            Span::raw(" history  "),
             Span::styled("Tab", Style::default().fg(Color::Cyan)),
             Span::raw(" cycle language  "),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
-            Span::raw(" quit"),
+            Span::raw(if self.searching {
+                " clear search"
+            } else {
+                " quit"
+            }),
         ]);
 
-        let paragraph = Paragraph::new(help_text).alignment(Alignment::Center);
+        let paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
         frame.render_widget(paragraph, area);
     }
 }
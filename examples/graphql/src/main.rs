@@ -0,0 +1,84 @@
+use async_graphql::{
+    EmptySubscription, ErrorExtensions as _, InputObject, Object, Schema, SimpleObject, Value,
+};
+use koruma::{Koruma, KorumaAllDisplay, ValidationMessages as _};
+use koruma_collection::{
+    collection::LenValidation, format::EmailValidation, numeric::RangeValidation,
+    string::AlphanumericValidation,
+};
+
+/// Input for the `signup` mutation, validated with koruma before use.
+#[derive(InputObject, Koruma, KorumaAllDisplay)]
+pub struct SignupInput {
+    #[koruma(LenValidation::<_>(min = 3, max = 20), AlphanumericValidation::<_>)]
+    pub username: String,
+
+    #[koruma(EmailValidation::<_>)]
+    pub email: String,
+
+    #[koruma(RangeValidation::<_>(min = 13, max = 130))]
+    pub age: i32,
+
+    #[koruma(each(LenValidation::<_>(min = 1, max = 12)))]
+    pub interests: Vec<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct Account {
+    pub username: String,
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    async fn ping(&self) -> &str {
+        "pong"
+    }
+}
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    /// Creates an account, reporting validation failures as a
+    /// `BAD_USER_INPUT` error with per-field details in its extensions.
+    async fn signup(&self, input: SignupInput) -> async_graphql::Result<Account> {
+        if let Err(errors) = input.validate() {
+            let details = Value::from_json(errors.to_graphql_extensions())?;
+            return Err(
+                async_graphql::Error::new("Invalid signup input").extend_with(|_, extensions| {
+                    extensions.set("code", "BAD_USER_INPUT");
+                    extensions.set("validationErrors", details.clone());
+                }),
+            );
+        }
+
+        Ok(Account {
+            username: input.username,
+        })
+    }
+}
+
+pub fn main() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let mutation = r#"
+        mutation {
+            signup(input: {
+                username: "a!",
+                email: "not-an-email",
+                age: 7,
+                interests: ["rust", "validation-libraries"]
+            }) {
+                username
+            }
+        }
+    "#;
+
+    let response = futures::executor::block_on(schema.execute(mutation));
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&response).expect("response is serializable")
+    );
+}
@@ -7,7 +7,7 @@ use crate::{
     },
     validators::normal::{NumberRangeValidation, StringLengthValidation, ZipCodeValidation},
 };
-use koruma::{Koruma, Validate};
+use koruma::Koruma;
 
 // #[derive(Koruma)]
 // struct Order {